@@ -1,4 +1,5 @@
 use data::enums::GamePlatforms;
+use data::migration::Versioned;
 use freya::radio::RadioChannel;
 use serde::{Deserialize, Serialize};
 use crate::data::region::Region;
@@ -29,6 +30,8 @@ impl Default for BattleNetSettings
 
 impl RadioChannel<BattleNetSettings> for GamePlatforms {}
 
+impl Versioned for BattleNetSettings {}
+
 impl BattleNetSettings
 {
 	/// The filename to be used when this struct is read from, or written to, the file system.
@@ -66,6 +66,7 @@ pub fn openBrowserForAuthorization(settings: BattleNetSettings, region: Region)
 
 async fn refreshSc2PlayerAccount(mut user: BattleNetUser, settings: BattleNetSettings) -> BattleNetOperationResult
 {
+	let mut failed = true;
 	let mut requests = vec![];
 	
 	let api = BattleNetApi::new(settings.clone());
@@ -85,6 +86,7 @@ async fn refreshSc2PlayerAccount(mut user: BattleNetUser, settings: BattleNetSet
 			Err(e) => warn!("[BattleNet] Error refreshing StarCraft II account data: {:?}", e),
 			
 			Ok(payload) => {
+				failed = false;
 				let avatarUrl = payload.avatarUrl.clone();
 				if user.starcraft2.is_none()
 				{
@@ -118,6 +120,7 @@ async fn refreshSc2PlayerAccount(mut user: BattleNetUser, settings: BattleNetSet
 	
 	return BattleNetOperationResult
 	{
+		failed,
 		user,
 		requests,
 	};
@@ -125,6 +128,7 @@ async fn refreshSc2PlayerAccount(mut user: BattleNetUser, settings: BattleNetSet
 
 async fn refreshSc2PlayerProfile(mut user: BattleNetUser, settings: BattleNetSettings) -> BattleNetOperationResult
 {
+	let mut failed = true;
 	let api = BattleNetApi::new(settings.clone());
 	if let Ok(session) = getBattleNetSession()
 	{
@@ -136,6 +140,7 @@ async fn refreshSc2PlayerProfile(mut user: BattleNetUser, settings: BattleNetSet
 				Err(e) => warn!("[BattleNet] Error refreshing StarCraft II profile: {:?}", e),
 				
 				Ok(payload) => {
+					failed = false;
 					if let Some(profile) = user.starcraft2.as_mut()
 					{
 						profile.updateProfile(payload);
@@ -146,11 +151,17 @@ async fn refreshSc2PlayerProfile(mut user: BattleNetUser, settings: BattleNetSet
 		}
 	}
 	
-	return user.into();
+	return BattleNetOperationResult
+	{
+		failed,
+		user,
+		requests: vec![],
+	};
 }
 
 async fn refreshSc2StaticProfile(mut user: BattleNetUser, settings: BattleNetSettings) -> BattleNetOperationResult
 {
+	let mut failed = true;
 	let mut requests = vec![];
 	
 	let api = BattleNetApi::new(settings.clone());
@@ -164,6 +175,7 @@ async fn refreshSc2StaticProfile(mut user: BattleNetUser, settings: BattleNetSet
 				Err(e) => warn!("[BattleNet] Error refreshing StarCraft II static data: {:?}", e),
 				
 				Ok(payload) => {
+					failed = false;
 					let group = join!(Path_Games, Starcraft2::GamePrefix);
 					
 					// Cache achievement icons
@@ -196,6 +208,7 @@ async fn refreshSc2StaticProfile(mut user: BattleNetUser, settings: BattleNetSet
 	
 	return BattleNetOperationResult
 	{
+		failed,
 		user,
 		requests,
 	};
@@ -203,6 +216,7 @@ async fn refreshSc2StaticProfile(mut user: BattleNetUser, settings: BattleNetSet
 
 async fn refreshUserInfo(mut user: BattleNetUser, settings: BattleNetSettings) -> BattleNetOperationResult
 {
+	let mut failed = true;
 	let api = BattleNetApi::new(settings.clone());
 	if let Ok(session) = getBattleNetSession()
 	{
@@ -218,11 +232,17 @@ async fn refreshUserInfo(mut user: BattleNetUser, settings: BattleNetSettings) -
 			Err(e) => warn!("[BattleNet] Error refreshing user info: {:?}", e),
 			
 			Ok(payload) => {
+				failed = false;
 				user.updateUserInfo(payload);
 				info!("[BattleNet] User info refreshed");
 			}
 		}
 	}
 	
-	return user.into();
+	return BattleNetOperationResult
+	{
+		failed,
+		user,
+		requests: vec![],
+	};
 }
@@ -1,12 +1,17 @@
+use components::button::icon::IconButton;
+use components::export::promptExport;
 use components::input::filter::AchievementsFilter;
 use data::enums::GamePlatforms;
+use data::export::AchievementExport;
 use data::filter::{FilterCriteria, Filterable};
+use freya::icons::lucide;
 use freya::prelude::{Alignment, ChildrenExt, Code, Component, ContainerExt,
 	ContainerSizeExt, ContainerWithContentExt, Content, Direction, Event,
 	EventHandlersExt, Gaps, IntoElement, KeyboardEventData, ScrollConfig,
-	ScrollPosition, Size, VirtualScrollView, rect, use_scroll_controller,
+	ScrollPosition, Size, VirtualScrollView, rect, spawn, use_scroll_controller,
 	use_state};
 use freya::radio::use_radio;
+use crate::data::games::BattleNetGames;
 use crate::data::user::BattleNetUser;
 use super::achievement::sc2Achievement;
 use super::campaigns::sc2Campaigns;
@@ -40,14 +45,16 @@ impl Component for Sc2Element
 			.clone()
 			.unwrap_or_default();
 		
-		let achievements = profile.filter(FilterCriteria
+		let criteria = FilterCriteria
 		{
 			caseSensitive: caseSensitive(),
 			locked: locked(),
 			nameOnly: nameOnly(),
 			text: search.read().clone(),
 			..Default::default()
-		});
+		};
+		
+		let achievements = profile.filter(criteria.clone());
 		
 		let achievementsLength = achievements.len();
 		
@@ -128,13 +135,34 @@ impl Component for Sc2Element
 							.width(Size::flex(0.5))
 							
 							.child(
-								AchievementsFilter::new(
-									caseSensitive,
-									locked,
-									nameOnly,
-									search
-								)
+								rect()
+									.content(Content::Flex)
+									.cross_align(Alignment::Center)
+									.direction(Direction::Horizontal)
+									.spacing(10.0)
 									.width(Size::percent(100.0))
+									
+									.child(
+										AchievementsFilter::new(
+											caseSensitive,
+											locked,
+											nameOnly,
+											search
+										)
+											.width(Size::flex(1.0))
+									)
+									
+									.child(
+										IconButton::new(lucide::download())
+											.alt("Export")
+											.onPress(move |_| if let Some(profile) = user.read().starcraft2.clone()
+											{
+												let export = AchievementExport::fromFilteredAchievements(&profile, criteria.clone());
+												spawn(async move {
+													promptExport(export, BattleNetGames::StarCraft2.as_ref()).await;
+												});
+											})
+									)
 							)
 							
 							.child(
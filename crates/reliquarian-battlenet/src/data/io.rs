@@ -1,7 +1,7 @@
 use std::io::ErrorKind;
 use anyhow::{Result, anyhow};
-use data::io::{getDataDir, getSettingsDir, readDataFromFile,
-	readRawDataFromFile, writeDataToFile};
+use data::io::{getSettingsDir, readDataFromFile, writeDataToFile};
+use data::storage::{loadUser, saveUser};
 use tracing::warn;
 use crate::api::BattleNetSettings;
 use super::user::BattleNetUser;
@@ -16,17 +16,15 @@ pub fn loadSettings_BattleNet() -> BattleNetSettings
 }
 
 /**
-Read the Battle.Net user data from file.
+Read the Battle.Net user data from storage.
 */
 pub fn loadUserData_BattleNet() -> BattleNetUser
 {
-	return match loadUserData()
+	return match loadUser()
 	{
 		Err(e) => {
 			warn!("Failed loading Battle.Net user data: {:?}", e);
-			warn!("Attempting Battle.Net user data lossy load");
-			loadUserData_lossy()
-				.unwrap_or_default()
+			BattleNetUser::default()
 		},
 		Ok(user) => user,
 	};
@@ -44,33 +42,6 @@ fn loadSettings() -> Result<BattleNetSettings>
 	};
 }
 
-/**
-Read the Battle.Net user data from file.
-*/
-fn loadUserData() -> Result<BattleNetUser>
-{
-	return match getDataDir(false)
-	{
-		None => Err(anyhow!(ErrorKind::NotFound)),
-		Some(dir) => readDataFromFile(dir, BattleNetUser::FileName.into()),
-	};
-}
-
-/**
-Read the Battle.Net user data from file.
-*/
-fn loadUserData_lossy() -> Result<BattleNetUser>
-{
-	return match getDataDir(false)
-	{
-		None => Err(anyhow!(ErrorKind::NotFound)),
-		Some(dir) => {
-			let json = readRawDataFromFile(dir, BattleNetUser::FileName.into())?;
-			BattleNetUser::parseJsonLossy(json)
-		},
-	};
-}
-
 /**
 Write the Battle.Net settings data to file.
 */
@@ -84,13 +55,9 @@ pub fn saveSettings(auth: &BattleNetSettings) -> Result<()>
 }
 
 /**
-Write the Battle.Net user data to file.
+Write the Battle.Net user data to storage.
 */
 pub fn saveUserData(user: &BattleNetUser) -> Result<()>
 {
-	return match getDataDir(true)
-	{
-		None => Err(anyhow!(ErrorKind::NotFound)),
-		Some(dir) => writeDataToFile(dir, BattleNetUser::FileName.into(), user),
-	};
+	return saveUser(user);
 }
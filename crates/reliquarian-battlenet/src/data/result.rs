@@ -4,6 +4,8 @@ use super::user::BattleNetUser;
 #[derive(Clone, Debug)]
 pub struct BattleNetOperationResult
 {
+	/// Whether the operation failed to retrieve the data it was meant to refresh.
+	pub failed: bool,
 	pub user: BattleNetUser,
 	pub requests: Vec<DataRequest>,
 }
@@ -14,8 +16,23 @@ impl From<BattleNetUser> for BattleNetOperationResult
 	{
 		return Self
 		{
+			failed: false,
 			user: value,
 			requests: Default::default(),
 		};
 	}
 }
+
+impl BattleNetOperationResult
+{
+	/// A result which leaves the user unchanged and marks the operation as failed.
+	pub fn failure(user: BattleNetUser) -> Self
+	{
+		return Self
+		{
+			failed: true,
+			user,
+			requests: Default::default(),
+		};
+	}
+}
@@ -1,6 +1,9 @@
 use std::collections::HashMap;
 use chrono::{DateTime, Utc};
 use chrono::serde::ts_seconds_option;
+use data::enums::GamePlatforms;
+use data::unified::{Sc2Extension, Unifiable, UnifiedAchievement,
+	UnifiedExtension};
 use serde::{Deserialize, Serialize};
 use serde_json::{Map, Value};
 use crate::api::{AchievementMetadata, EarnedAchievement};
@@ -48,6 +51,28 @@ impl PartialOrd for Sc2Achievement
 	}
 }
 
+impl Unifiable<UnifiedAchievement> for Sc2Achievement
+{
+	fn unify(&self) -> UnifiedAchievement
+	{
+		return UnifiedAchievement
+		{
+			description: self.description.clone(),
+			extension: UnifiedExtension::StarCraft2(Sc2Extension
+			{
+				points: self.points,
+			}),
+			hidden: false,
+			id: self.id.to_string(),
+			name: self.name.clone(),
+			platform: GamePlatforms::BattleNet,
+			rarity: None,
+			unlocked: self.unlocked,
+			unlockedTimestamp: self.unlockedTimestamp,
+		};
+	}
+}
+
 impl Sc2Achievement
 {
 	pub fn parseJsonMapLossy(map: &Map<String, Value>) -> Option<Self>
@@ -1,7 +1,10 @@
+use data::enums::GamePlatforms;
 use data::filter::{FilterCriteria, Filterable};
+use data::unified::{Unifiable, UnifiedGame};
 use serde::{Deserialize, Serialize};
 use serde_json::{Map, Value};
 use crate::api::{PayloadPlayer, PayloadProfile, PayloadStatic};
+use crate::data::games::BattleNetGames;
 use crate::data::region::Region;
 use crate::data::starcraft2::achievement::Sc2Achievement;
 use crate::data::starcraft2::profile::campaign::CampaignsSummary;
@@ -91,6 +94,21 @@ impl From<PayloadPlayer> for ProfileStarcraft2
 	}
 }
 
+impl Unifiable<UnifiedGame> for ProfileStarcraft2
+{
+	fn unify(&self) -> UnifiedGame
+	{
+		return UnifiedGame::new(
+			GamePlatforms::BattleNet,
+			self.id.to_string(),
+			BattleNetGames::StarCraft2.as_ref(),
+			self.achievements.iter()
+				.map(|a| a.unify())
+				.collect()
+		);
+	}
+}
+
 impl ProfileStarcraft2
 {
 	pub fn getAchievement(&self, id: u64) -> Option<Sc2Achievement>
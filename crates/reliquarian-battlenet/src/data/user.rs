@@ -1,5 +1,8 @@
 use anyhow::Result;
 use data::enums::GamePlatforms;
+use data::migration::Versioned;
+use data::storage::Storable;
+use data::unified::{Unifiable, UnifiedGame};
 use freya::radio::RadioChannel;
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
@@ -21,6 +24,35 @@ pub struct BattleNetUser
 
 impl RadioChannel<BattleNetUser> for GamePlatforms {}
 
+impl Storable for BattleNetUser
+{
+	const DataFileName: &'static str = BattleNetUser::FileName;
+	const GamesKey: Option<&'static str> = None;
+	const Platform: GamePlatforms = GamePlatforms::BattleNet;
+	
+	fn parseLossy(json: String) -> Result<Self>
+	{
+		return BattleNetUser::parseJsonLossy(json);
+	}
+}
+
+impl Unifiable<Vec<UnifiedGame>> for BattleNetUser
+{
+	fn unify(&self) -> Vec<UnifiedGame>
+	{
+		let mut games = vec![];
+		
+		if let Some(starcraft2) = &self.starcraft2
+		{
+			games.push(starcraft2.unify());
+		}
+		
+		return games;
+	}
+}
+
+impl Versioned for BattleNetUser {}
+
 impl BattleNetUser
 {
 	pub const FileName: &str = "battlenet.json";
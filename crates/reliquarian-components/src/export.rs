@@ -0,0 +1,49 @@
+use data::export::{AchievementExport, ExportFormat};
+use rfd::AsyncFileDialog;
+use strum::IntoEnumIterator;
+use tracing::{info, warn};
+
+/**
+Ask the user where to save an export, then write it in the format matching the
+chosen file's extension.
+
+## Parameters
+- export: `AchievementExport` The achievements to write.
+- name: `&str` The suggested file name, without extension.
+*/
+pub async fn promptExport(export: AchievementExport, name: &str)
+{
+	let mut dialog = AsyncFileDialog::new()
+		.set_file_name(format!("{}.{}", sanitizeFileName(name), ExportFormat::default().extension()));
+	
+	for format in ExportFormat::iter()
+	{
+		dialog = dialog.add_filter(format.to_string(), &[format.extension()]);
+	}
+	
+	let destination = match dialog.save_file().await
+	{
+		None => return,
+		Some(handle) => handle.path().to_path_buf(),
+	};
+	
+	let format = ExportFormat::fromPath(&destination);
+	match export.write(&destination, format)
+	{
+		Err(e) => warn!("[Export] Error exporting achievements: {:?}", e),
+		Ok(_) => info!("[Export] Exported {} achievements to {}", export.len(), destination.to_string_lossy()),
+	}
+}
+
+fn sanitizeFileName(name: &str) -> String
+{
+	return name.chars()
+		.map(|c| match c.is_alphanumeric() || c == '-' || c == '_' || c == ' '
+		{
+			false => '_',
+			true => c,
+		})
+		.collect::<String>()
+		.trim()
+		.to_string();
+}
@@ -12,7 +12,9 @@ pub struct AchievementsFilter
 	caseSensitive: Writable<bool>,
 	locked: Writable<bool>,
 	margin: Gaps,
+	missable: Option<Writable<bool>>,
 	nameOnly: Writable<bool>,
+	progression: Option<Writable<bool>>,
 	search: Writable<String>,
 	width: Size,
 }
@@ -25,7 +27,9 @@ impl Component for AchievementsFilter
 		
 		let caseSensitive = self.caseSensitive.clone();
 		let locked = self.locked.clone();
+		let missable = self.missable.clone();
 		let nameOnly = self.nameOnly.clone();
+		let progression = self.progression.clone();
 		let search = self.search.clone();
 		
 		return rect()
@@ -112,6 +116,38 @@ impl Component for AchievementsFilter
 									.selected(*nameOnly.read())
 							)
 					)
+					
+					.maybe_child(missable.is_some().then(||
+						Tile::new()
+							.leading("Missable Only")
+							.on_select({
+								let mut missable = missable.clone().unwrap();
+								move |_| {
+									let value = !*missable.read();
+									missable.set(value);
+								}
+							})
+							.child(
+								Checkbox::new()
+									.selected(*missable.unwrap().read())
+							)
+					))
+					
+					.maybe_child(progression.is_some().then(||
+						Tile::new()
+							.leading("Progression Only")
+							.on_select({
+								let mut progression = progression.clone().unwrap();
+								move |_| {
+									let value = !*progression.read();
+									progression.set(value);
+								}
+							})
+							.child(
+								Checkbox::new()
+									.selected(*progression.unwrap().read())
+							)
+					))
 			));
 	}
 }
@@ -130,7 +166,9 @@ impl AchievementsFilter
 			caseSensitive: caseSensitive.into(),
 			locked: locked.into(),
 			margin: Default::default(),
+			missable: None,
 			nameOnly: nameOnly.into(),
+			progression: None,
 			search: search.into(),
 			width: Default::default(),
 		};
@@ -142,6 +180,18 @@ impl AchievementsFilter
 		return self;
 	}
 	
+	pub fn missable(mut self, state: impl Into<Writable<bool>>) -> Self
+	{
+		self.missable = Some(state.into());
+		return self;
+	}
+	
+	pub fn progression(mut self, state: impl Into<Writable<bool>>) -> Self
+	{
+		self.progression = Some(state.into());
+		return self;
+	}
+	
 	pub fn width(mut self, size: impl Into<Size>) -> Self
 	{
 		self.width = size.into();
@@ -2,18 +2,27 @@ use data::constants::TextColor;
 use freya::icons::lucide;
 use freya::prelude::{Alignment, Checkbox, ChildrenExt, Component, ContainerExt,
 	ContainerSizeExt, ContainerWithContentExt, Content, Direction, Gaps, Input,
-	IntoElement, Size, Tile, WritableUtils, rect, use_state};
+	IntoElement, MenuItem, Select, Size, Tile, WritableUtils, rect, use_state};
 use freya::radio::Writable;
 use crate::button::icon::IconButton;
 
+/// The label of the tag selection item which displays games regardless of their tags.
+const AllTags: &str = "All Tags";
+
 #[derive(Clone, PartialEq)]
 pub struct GamesFilter
 {
 	caseSensitive: Writable<bool>,
+	dlc: Option<Writable<bool>>,
+	hideHidden: Option<Writable<bool>>,
+	linuxNative: Option<Writable<bool>>,
 	margin: Gaps,
 	nameOnly: Option<Writable<bool>>,
 	search: Writable<String>,
 	showAll: Option<Writable<bool>>,
+	tag: Option<Writable<Option<String>>>,
+	tags: Vec<String>,
+	wantToPlay: Option<Writable<bool>>,
 	width: Size,
 }
 
@@ -24,9 +33,20 @@ impl Component for GamesFilter
 		let mut showAdvanced = use_state(bool::default);
 		
 		let caseSensitive = self.caseSensitive.clone();
+		let dlc = self.dlc.clone();
+		let hideHidden = self.hideHidden.clone();
+		let linuxNative = self.linuxNative.clone();
 		let nameOnly = self.nameOnly.clone();
 		let search = self.search.clone();
 		let showAll = self.showAll.clone();
+		let tag = self.tag.clone();
+		let tags = self.tags.clone();
+		let wantToPlay = self.wantToPlay.clone();
+		
+		let libraryFilters = dlc.is_some()
+			|| hideHidden.is_some()
+			|| linuxNative.is_some()
+			|| tag.is_some();
 		
 		return rect()
 			.direction(Direction::Vertical)
@@ -112,6 +132,109 @@ impl Component for GamesFilter
 									.selected(*showAll.unwrap().read())
 							)
 					))
+					
+					.maybe_child(wantToPlay.is_some().then(||
+						Tile::new()
+							.leading("Want to Play Only")
+							.on_select({
+								let mut wantToPlay = wantToPlay.clone().unwrap();
+								move |_| {
+									let value = !*wantToPlay.read();
+									wantToPlay.set(value);
+								}
+							})
+							.child(
+								Checkbox::new()
+									.selected(*wantToPlay.unwrap().read())
+							)
+					))
+			))
+			
+			.maybe_child((showAdvanced() && libraryFilters).then(||
+				rect()
+					.cross_align(Alignment::Center)
+					.direction(Direction::Horizontal)
+					.main_align(Alignment::SpaceEvenly)
+					.width(Size::percent(100.0))
+					
+					.maybe_child(linuxNative.is_some().then(||
+						Tile::new()
+							.leading("Linux Native Only")
+							.on_select({
+								let mut linuxNative = linuxNative.clone().unwrap();
+								move |_| {
+									let value = !*linuxNative.read();
+									linuxNative.set(value);
+								}
+							})
+							.child(
+								Checkbox::new()
+									.selected(*linuxNative.unwrap().read())
+							)
+					))
+					
+					.maybe_child(dlc.is_some().then(||
+						Tile::new()
+							.leading("Has DLC")
+							.on_select({
+								let mut dlc = dlc.clone().unwrap();
+								move |_| {
+									let value = !*dlc.read();
+									dlc.set(value);
+								}
+							})
+							.child(
+								Checkbox::new()
+									.selected(*dlc.unwrap().read())
+							)
+					))
+					
+					.maybe_child(hideHidden.is_some().then(||
+						Tile::new()
+							.leading("Hide Hidden Games")
+							.on_select({
+								let mut hideHidden = hideHidden.clone().unwrap();
+								move |_| {
+									let value = !*hideHidden.read();
+									hideHidden.set(value);
+								}
+							})
+							.child(
+								Checkbox::new()
+									.selected(*hideHidden.unwrap().read())
+							)
+					))
+					
+					.maybe_child(tag.filter(|_| !tags.is_empty()).map(|tag| {
+						let selected = tag.read().clone();
+						
+						Select::new()
+							.selected_item(selected.clone().unwrap_or(AllTags.into()))
+							
+							.child(
+								MenuItem::new()
+									.selected(selected.is_none())
+									.on_press({
+										let mut tag = tag.clone();
+										move |_| tag.set(None)
+									})
+									.child(AllTags)
+							)
+							
+							.children(
+								tags.into_iter().map(|name| {
+									MenuItem::new()
+										.selected(selected.as_ref() == Some(&name))
+										.on_press({
+											let mut tag = tag.clone();
+											let name = name.clone();
+											move |_| tag.set(Some(name.clone()))
+										})
+										.child(name)
+										.into()
+								})
+							)
+					}))
 			));
 	}
 }
@@ -126,14 +249,41 @@ impl GamesFilter
 		return Self
 		{
 			caseSensitive: caseSensitive.into(),
+			dlc: None,
+			hideHidden: None,
+			linuxNative: None,
 			margin: Default::default(),
 			nameOnly: None,
 			search: search.into(),
 			showAll: None,
+			tag: None,
+			tags: vec![],
+			wantToPlay: None,
 			width: Default::default(),
 		};
 	}
 	
+	/// Display a toggle for showing only games which include DLC.
+	pub fn dlc(mut self, state: impl Into<Writable<bool>>) -> Self
+	{
+		self.dlc = Some(state.into());
+		return self;
+	}
+	
+	/// Display a toggle for hiding games the user has hidden in their library.
+	pub fn hideHidden(mut self, state: impl Into<Writable<bool>>) -> Self
+	{
+		self.hideHidden = Some(state.into());
+		return self;
+	}
+	
+	/// Display a toggle for showing only games which run natively on Linux.
+	pub fn linuxNative(mut self, state: impl Into<Writable<bool>>) -> Self
+	{
+		self.linuxNative = Some(state.into());
+		return self;
+	}
+	
 	pub fn margin(mut self, gaps: impl Into<Gaps>) -> Self
 	{
 		self.margin = gaps.into();
@@ -152,6 +302,25 @@ impl GamesFilter
 		return self;
 	}
 	
+	/**
+	Display a selection of user-defined `tags`, of which the selected tag is
+	stored in `state`.
+	
+	The selection is omitted when `tags` is empty.
+	*/
+	pub fn tag(mut self, state: impl Into<Writable<Option<String>>>, tags: Vec<String>) -> Self
+	{
+		self.tag = Some(state.into());
+		self.tags = tags;
+		return self;
+	}
+	
+	pub fn wantToPlay(mut self, state: impl Into<Writable<bool>>) -> Self
+	{
+		self.wantToPlay = Some(state.into());
+		return self;
+	}
+	
 	pub fn width(mut self, size: impl Into<Size>) -> Self
 	{
 		self.width = size.into();
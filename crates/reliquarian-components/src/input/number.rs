@@ -1,23 +1,31 @@
+use std::fmt::Display;
+use std::str::FromStr;
 use freya::prelude::{Component, Input, InputValidator, IntoElement, Size,
 	State, WritableUtils, use_side_effect, use_state};
 
 #[derive(Clone, PartialEq)]
-pub struct NumericInput
+pub struct NumericInput<T: 'static = u64>
 {
-	value: State<u64>,
-	max: u64,
-	min: u64,
+	value: State<T>,
+	max: Option<T>,
+	min: Option<T>,
 	placeholder: String,
 	width: Size,
 }
 
-impl Component for NumericInput
+impl<T> Component for NumericInput<T>
+	where T: Copy + Display + FromStr + PartialOrd + 'static,
 {
 	fn render(&self) -> impl IntoElement
 	{
 		let mut value = self.value.clone();
 		let textValue = use_state(|| value.read().to_string());
-		use_side_effect(move || value.set(textValue.read().parse::<u64>().unwrap()));
+		use_side_effect(move || {
+			if let Ok(number) = textValue.read().parse::<T>()
+			{
+				value.set(number);
+			}
+		});
 		
 		let max = self.max;
 		let min = self.min;
@@ -30,29 +38,29 @@ impl Component for NumericInput
 }
 
 #[allow(unused)]
-impl NumericInput
+impl<T: 'static> NumericInput<T>
 {
-	pub fn new(value: State<u64>) -> Self
+	pub fn new(value: State<T>) -> Self
 	{
 		return Self
 		{
 			value,
-			max: u64::MAX,
-			min: u64::MIN,
+			max: None,
+			min: None,
 			placeholder: String::default(),
 			width: Size::default(),
 		};
 	}
 	
-	pub fn max(mut self, max: impl Into<u64>) -> Self
+	pub fn max(mut self, max: impl Into<T>) -> Self
 	{
-		self.max = max.into();
+		self.max = Some(max.into());
 		return self;
 	}
 	
-	pub fn min(mut self, min: impl Into<u64>) -> Self
+	pub fn min(mut self, min: impl Into<T>) -> Self
 	{
-		self.min = min.into();
+		self.min = Some(min.into());
 		return self;
 	}
 	
@@ -69,11 +77,13 @@ impl NumericInput
 	}
 }
 
-fn validate(validator: InputValidator, max: u64, min: u64)
+fn validate<T>(validator: InputValidator, max: Option<T>, min: Option<T>)
+	where T: FromStr + PartialOrd,
 {
-	validator.set_valid(match validator.text().parse::<u64>()
+	validator.set_valid(match validator.text().parse::<T>()
 	{
 		Err(_) => false,
-		Ok(num) => min <= num && num <= max,
+		Ok(num) => min.is_none_or(|min| min <= num)
+			&& max.is_none_or(|max| num <= max),
 	});
 }
@@ -1,4 +1,5 @@
 pub mod button;
+pub mod export;
 pub mod input;
 pub mod overlay;
 pub mod settings;
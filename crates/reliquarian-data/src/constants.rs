@@ -36,6 +36,11 @@ pub const Path_Avatars: &str = "avatars";
 pub const Path_Logs: &str = "logs";
 pub const Path_Games: &str = "games";
 
+pub const RarityCommon: Color = Color::from_rgb(204, 204, 204);
+pub const RarityUncommon: Color = Color::from_rgb(74, 222, 128);
+pub const RarityRare: Color = Color::from_rgb(96, 165, 250);
+pub const RarityUltraRare: Color = Color::from_rgb(192, 132, 252);
+
 pub const RetroAchievementsGameUnfinished: Color = Color::from_rgb(22, 22, 22);
 pub const RetroAchievementsGameUnfinishedBorder: Color = Color::from_rgb(42, 42, 42);
 pub const RetroAchievementsGameBeaten: Color = Color::from_rgb(212, 212, 216);
@@ -45,6 +50,9 @@ pub const RetroAchievementsGameMastered: Color = Color::from_rgb(255, 215, 0);
 pub const RetroAchievementsProgressColorBackground: Color = Color::from_rgb(9, 9, 11);
 pub const RetroAchievementsProgressColorCasual: Color = Color::from_rgb(115, 115, 115);
 pub const RetroAchievementsProgressColorHardcore: Color = Color::from_rgb(250, 186, 6);
+pub const RetroAchievementsTypeMissable: Color = Color::from_rgb(220, 38, 38);
+pub const RetroAchievementsTypeProgression: Color = Color::from_rgb(37, 99, 235);
+pub const RetroAchievementsTypeWinCondition: Color = Color::from_rgb(22, 163, 74);
 
 pub const SecretsKeyFileName: &str = "secrets.key";
 pub const SecretsVaultFileName: &str = "secrets.json";
@@ -53,3 +61,5 @@ pub const SteamContrast: Color = Color::from_rgb(31, 98, 154);
 
 pub const TextColor: Color = Color::from_rgb(204, 204, 204);
 pub const TheString: &str = "The ";
+
+pub const WarningColor: Color = Color::from_rgb(220, 38, 38);
@@ -1,9 +1,11 @@
 use serde::{Deserialize, Serialize};
 use strum_macros::{Display, EnumIter, EnumString};
+use super::GamePlatforms;
 
 #[derive(Clone, Copy, Default, Debug, Deserialize, Display, EnumIter, EnumString, Eq, Hash, PartialEq, PartialOrd, Serialize)]
 pub enum ActiveContent
 {
+	AllPlatforms,
 	BattleNet,
 	EpicGamesStore,
 	Gog,
@@ -12,4 +14,21 @@ pub enum ActiveContent
 	#[default]
 	Settings,
 	Steam,
+	Timeline,
+}
+
+impl From<GamePlatforms> for ActiveContent
+{
+	fn from(value: GamePlatforms) -> Self
+	{
+		return match value
+		{
+			GamePlatforms::BattleNet => Self::BattleNet,
+			GamePlatforms::EpicGamesStore => Self::EpicGamesStore,
+			GamePlatforms::Gog => Self::Gog,
+			GamePlatforms::RetroAchievements => Self::RetroAchievements,
+			GamePlatforms::Rpcs3 => Self::Rpcs3,
+			GamePlatforms::Steam => Self::Steam,
+		};
+	}
 }
@@ -1,7 +1,9 @@
 mod active;
 mod channel;
 mod platform;
+mod rarity;
 
 pub use active::ActiveContent;
 pub use channel::DataChannel;
 pub use platform::GamePlatforms;
+pub use rarity::RarityTier;
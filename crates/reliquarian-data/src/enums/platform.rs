@@ -1,7 +1,7 @@
 use freya::radio::RadioChannel;
-use strum_macros::AsRefStr;
+use strum_macros::{AsRefStr, EnumIter};
 
-#[derive(AsRefStr, Clone, Copy, Debug, Eq, Hash, PartialEq, PartialOrd, Ord)]
+#[derive(AsRefStr, Clone, Copy, Debug, EnumIter, Eq, Hash, PartialEq, PartialOrd, Ord)]
 pub enum GamePlatforms
 {
 	#[strum(to_string="Battle.Net")]
@@ -19,3 +19,26 @@ pub enum GamePlatforms
 
 impl RadioChannel<Option<String>> for GamePlatforms {}
 impl RadioChannel<Option<u64>> for GamePlatforms {}
+
+impl GamePlatforms
+{
+	/**
+	A stable identifier for the platform, for use by other tools and as the key
+	of stored data.
+	
+	Unlike the display name returned by `as_ref`, the identifier never contains
+	spaces or punctuation and will not change if the display name does.
+	*/
+	pub fn identifier(&self) -> &'static str
+	{
+		return match self
+		{
+			Self::BattleNet => "BattleNet",
+			Self::EpicGamesStore => "EpicGamesStore",
+			Self::Gog => "Gog",
+			Self::RetroAchievements => "RetroAchievements",
+			Self::Rpcs3 => "Rpcs3",
+			Self::Steam => "Steam",
+		};
+	}
+}
@@ -0,0 +1,36 @@
+use freya::prelude::Color;
+use serde::{Deserialize, Serialize};
+use strum_macros::{Display, EnumIter};
+use crate::constants::{RarityCommon, RarityRare, RarityUltraRare,
+	RarityUncommon};
+
+/**
+How rare an achievement is, based on the percentage of players who have
+unlocked it.
+
+The boundaries between tiers are configured in `AppSettings::rarity`.
+*/
+#[derive(Clone, Copy, Debug, Default, Deserialize, Display, EnumIter, Eq, Hash, PartialEq, PartialOrd, Ord, Serialize)]
+pub enum RarityTier
+{
+	#[default]
+	Common,
+	Uncommon,
+	Rare,
+	#[strum(to_string="Ultra Rare")]
+	UltraRare,
+}
+
+impl RarityTier
+{
+	pub fn color(&self) -> Color
+	{
+		return match self
+		{
+			Self::Common => RarityCommon,
+			Self::Uncommon => RarityUncommon,
+			Self::Rare => RarityRare,
+			Self::UltraRare => RarityUltraRare,
+		};
+	}
+}
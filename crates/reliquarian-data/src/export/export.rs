@@ -0,0 +1,236 @@
+use std::io::Write;
+use std::path::Path;
+use anyhow::{Context, Result};
+use crate::filter::{FilterCriteria, Filterable};
+use crate::io::writeFileAtomically;
+use crate::unified::{Unifiable, UnifiedAchievement, UnifiedGame};
+use super::format::ExportFormat;
+use super::row::ExportRow;
+
+/**
+A flattened list of achievements, ready to be written to a CSV or JSON file.
+
+Exports are built from the same `FilterCriteria` used to display the games or
+achievements, so that the file contains exactly what is on screen.
+*/
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct AchievementExport
+{
+	pub rows: Vec<ExportRow>,
+}
+
+impl AchievementExport
+{
+	/**
+	Export the achievements of a single game, limited to those which match the
+	`filter`.
+	*/
+	pub fn fromFilteredAchievements<G, A>(game: &G, filter: impl Into<FilterCriteria>) -> Self
+		where G: Filterable<A> + Unifiable<UnifiedGame>,
+			A: Unifiable<UnifiedAchievement>
+	{
+		let mut unified = game.unify();
+		unified.achievements = game.filter(filter)
+			.iter()
+			.map(|a| a.unify())
+			.collect();
+		
+		return Self::fromGames(&[unified]);
+	}
+	
+	/**
+	Export every achievement of the games which match the `filter`.
+	*/
+	pub fn fromFilteredGames<G>(source: &impl Filterable<G>, filter: impl Into<FilterCriteria>) -> Self
+		where G: Unifiable<UnifiedGame>
+	{
+		let games = source.filter(filter)
+			.iter()
+			.map(|g| g.unify())
+			.collect::<Vec<_>>();
+		
+		return Self::fromGames(&games);
+	}
+	
+	pub fn fromGames(games: &[UnifiedGame]) -> Self
+	{
+		let rows = games.iter()
+			.flat_map(|game| game.achievements.iter()
+				.map(|achievement| ExportRow::new(game, achievement)))
+			.collect();
+		
+		return Self { rows };
+	}
+	
+	pub fn is_empty(&self) -> bool
+	{
+		return self.rows.is_empty();
+	}
+	
+	pub fn len(&self) -> usize
+	{
+		return self.rows.len();
+	}
+	
+	/**
+	Render the export as comma-separated values, with a header line.
+	*/
+	pub fn toCsv(&self) -> String
+	{
+		let mut lines = vec![csvLine(ExportRow::Headers.iter())];
+		for row in &self.rows
+		{
+			lines.push(csvLine(row.csvFields().iter()));
+		}
+		
+		return lines.join("\r\n") + "\r\n";
+	}
+	
+	pub fn toJson(&self) -> Result<String>
+	{
+		return Ok(serde_json::to_string_pretty(&self.rows)?);
+	}
+	
+	/**
+	Write the export to the file at `path`, replacing it if it already exists.
+	*/
+	pub fn write(&self, path: &Path, format: ExportFormat) -> Result<()>
+	{
+		let contents = match format
+		{
+			ExportFormat::Csv => self.toCsv(),
+			ExportFormat::Json => self.toJson()?,
+		};
+		
+		return writeFileAtomically(path, |writer| Ok(writer.write_all(contents.as_bytes())?))
+			.context(format!("Failed writing export to: '{}'", path.to_string_lossy()));
+	}
+}
+
+fn csvLine<'a>(fields: impl Iterator<Item = &'a (impl AsRef<str> + 'a)>) -> String
+{
+	return fields.map(|field| csvField(field.as_ref()))
+		.collect::<Vec<_>>()
+		.join(",");
+}
+
+/**
+Quote a field if it contains a delimiter, quote, or line break, doubling any
+quotes it contains.
+*/
+fn csvField(field: &str) -> String
+{
+	return match field.contains([',', '"', '\n', '\r'])
+	{
+		false => field.to_string(),
+		true => format!("\"{}\"", field.replace('"', "\"\"")),
+	};
+}
+
+#[cfg(test)]
+mod tests
+{
+	use chrono::DateTime;
+	use crate::enums::GamePlatforms;
+	use crate::unified::{RetroAchievementsExtension, UnifiedExtension};
+	use super::*;
+	
+	struct TestAchievement(UnifiedAchievement);
+	
+	impl Unifiable<UnifiedAchievement> for TestAchievement
+	{
+		fn unify(&self) -> UnifiedAchievement
+		{
+			return self.0.clone();
+		}
+	}
+	
+	struct TestGame(Vec<UnifiedAchievement>);
+	
+	impl Filterable<TestAchievement> for TestGame
+	{
+		fn filter(&self, filter: impl Into<FilterCriteria>) -> Vec<TestAchievement>
+		{
+			let filter = filter.into();
+			return self.0.iter()
+				.filter(|a| !filter.locked || !a.unlocked)
+				.filter(|a| a.name.contains(&filter.text))
+				.cloned()
+				.map(TestAchievement)
+				.collect();
+		}
+	}
+	
+	impl Unifiable<UnifiedGame> for TestGame
+	{
+		fn unify(&self) -> UnifiedGame
+		{
+			return UnifiedGame::new(GamePlatforms::RetroAchievements, "1", "The Game", self.0.clone());
+		}
+	}
+	
+	fn buildGame() -> TestGame
+	{
+		let mut first = UnifiedAchievement::new(GamePlatforms::RetroAchievements, "1");
+		first.name = "First".into();
+		first.description = "Say \"hello\", then leave".into();
+		first.unlocked = true;
+		first.unlockedTimestamp = DateTime::from_timestamp(1765083932, 0);
+		first.extension = UnifiedExtension::RetroAchievements(RetroAchievementsExtension
+		{
+			hardcore: true,
+			points: 10,
+		});
+		
+		let mut second = UnifiedAchievement::new(GamePlatforms::RetroAchievements, "2");
+		second.name = "Second".into();
+		second.rarity = Some(12.5);
+		
+		return TestGame(vec![first, second]);
+	}
+	
+	#[test]
+	fn csv()
+	{
+		let export = AchievementExport::fromFilteredAchievements(&buildGame(), String::default());
+		let csv = export.toCsv();
+		let lines = csv.lines().collect::<Vec<_>>();
+		
+		assert_eq!(lines.len(), 3);
+		assert_eq!(lines[0], ExportRow::Headers.join(","));
+		assert_eq!(
+			lines[1],
+			"RetroAchievements,1,The Game,1,First,\"Say \"\"hello\"\", then leave\",false,true,2025-12-07T05:05:32+00:00,,10,true,,,"
+		);
+		assert_eq!(lines[2], "RetroAchievements,1,The Game,2,Second,,false,false,,12.5,,,,,");
+	}
+	
+	#[test]
+	fn filtered()
+	{
+		let game = buildGame();
+		
+		let locked = FilterCriteria { locked: true, ..Default::default() };
+		let export = AchievementExport::fromFilteredAchievements(&game, locked);
+		assert_eq!(export.len(), 1);
+		assert_eq!(export.rows[0].name, "Second");
+		assert_eq!(export.rows[0].rarity, Some(12.5));
+		assert_eq!(export.rows[0].points, None);
+		
+		let export = AchievementExport::fromFilteredAchievements(&game, "Nothing".to_string());
+		assert!(export.is_empty());
+		assert_eq!(export.toCsv(), ExportRow::Headers.join(",") + "\r\n");
+	}
+	
+	#[test]
+	fn json()
+	{
+		let export = AchievementExport::fromFilteredAchievements(&buildGame(), String::default());
+		let value: serde_json::Value = serde_json::from_str(&export.toJson().unwrap()).unwrap();
+		
+		assert_eq!(value[0]["gameName"], "The Game");
+		assert_eq!(value[0]["points"], 10);
+		assert_eq!(value[0]["hardcore"], true);
+		assert_eq!(value[0]["grade"], serde_json::Value::Null);
+	}
+}
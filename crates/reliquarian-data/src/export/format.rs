@@ -0,0 +1,43 @@
+use std::path::Path;
+use strum_macros::{Display, EnumIter};
+
+/**
+The file formats into which achievements can be exported.
+*/
+#[derive(Clone, Copy, Debug, Default, Display, EnumIter, Eq, Hash, PartialEq)]
+pub enum ExportFormat
+{
+	#[default]
+	#[strum(to_string="CSV")]
+	Csv,
+	#[strum(to_string="JSON")]
+	Json,
+}
+
+impl ExportFormat
+{
+	pub fn extension(&self) -> &'static str
+	{
+		return match self
+		{
+			Self::Csv => "csv",
+			Self::Json => "json",
+		};
+	}
+	
+	/**
+	The format matching the extension of `path`, falling back to the default
+	format when the extension is missing or unrecognized.
+	*/
+	pub fn fromPath(path: &Path) -> Self
+	{
+		return match path.extension()
+			.and_then(|e| e.to_str())
+			.map(|e| e.to_lowercase())
+			.as_deref()
+		{
+			Some("json") => Self::Json,
+			_ => Self::Csv,
+		};
+	}
+}
@@ -0,0 +1,7 @@
+mod export;
+mod format;
+mod row;
+
+pub use export::AchievementExport;
+pub use format::ExportFormat;
+pub use row::ExportRow;
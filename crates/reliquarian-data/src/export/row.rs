@@ -0,0 +1,132 @@
+use serde::Serialize;
+use crate::unified::{UnifiedAchievement, UnifiedExtension, UnifiedGame};
+
+/**
+A single achievement, flattened together with its game so that it can be
+written as one line of a spreadsheet.
+
+Platform-specific columns are empty for platforms which do not provide them.
+*/
+#[derive(Clone, Debug, Default, PartialEq, Serialize)]
+pub struct ExportRow
+{
+	/// The stable identifier of the platform, rather than its display name.
+	pub platform: String,
+	pub gameId: String,
+	pub gameName: String,
+	pub achievementId: String,
+	pub name: String,
+	pub description: String,
+	pub hidden: bool,
+	pub unlocked: bool,
+	
+	/// The moment the achievement was unlocked, formatted as RFC 3339.
+	pub unlockedTimestamp: Option<String>,
+	
+	/// The percentage of players who have unlocked the achievement.
+	pub rarity: Option<f64>,
+	
+	/// RetroAchievements or StarCraft II points.
+	pub points: Option<u64>,
+	
+	/// RetroAchievements Hardcore unlock.
+	pub hardcore: Option<bool>,
+	
+	/// PlayStation trophy grade.
+	pub grade: Option<String>,
+	
+	/// Epic Games Store achievement tier.
+	pub tier: Option<String>,
+	
+	/// Epic Games Store XP.
+	pub xp: Option<u64>,
+}
+
+impl ExportRow
+{
+	/// The CSV column headers, in the same order as `csvFields`.
+	pub const Headers: [&str; 15] = [
+		"Platform",
+		"Game ID",
+		"Game",
+		"Achievement ID",
+		"Name",
+		"Description",
+		"Hidden",
+		"Unlocked",
+		"Unlocked At",
+		"Rarity",
+		"Points",
+		"Hardcore",
+		"Grade",
+		"Tier",
+		"XP",
+	];
+	
+	pub fn new(game: &UnifiedGame, achievement: &UnifiedAchievement) -> Self
+	{
+		let mut row = Self
+		{
+			platform: game.platform.identifier().to_string(),
+			gameId: game.id.clone(),
+			gameName: game.name.clone(),
+			achievementId: achievement.id.clone(),
+			name: achievement.name.clone(),
+			description: achievement.description.clone(),
+			hidden: achievement.hidden,
+			unlocked: achievement.unlocked,
+			unlockedTimestamp: achievement.unlockedTimestamp
+				.map(|dt| dt.to_rfc3339()),
+			rarity: achievement.rarity,
+			..Default::default()
+		};
+		
+		match &achievement.extension
+		{
+			UnifiedExtension::EpicGamesStore(egs) => {
+				row.tier = Some(egs.tierName.clone());
+				row.xp = Some(egs.xp);
+			},
+			
+			UnifiedExtension::None => {},
+			UnifiedExtension::Psn(psn) => row.grade = Some(psn.grade.to_string()),
+			
+			UnifiedExtension::RetroAchievements(ra) => {
+				row.hardcore = Some(ra.hardcore);
+				row.points = Some(ra.points);
+			},
+			
+			UnifiedExtension::StarCraft2(sc2) => row.points = Some(sc2.points),
+		}
+		
+		return row;
+	}
+	
+	/// The values of this row, in the same order as `Headers`.
+	pub fn csvFields(&self) -> [String; 15]
+	{
+		return [
+			self.platform.clone(),
+			self.gameId.clone(),
+			self.gameName.clone(),
+			self.achievementId.clone(),
+			self.name.clone(),
+			self.description.clone(),
+			self.hidden.to_string(),
+			self.unlocked.to_string(),
+			self.unlockedTimestamp.clone().unwrap_or_default(),
+			optionalField(self.rarity),
+			optionalField(self.points),
+			optionalField(self.hardcore),
+			self.grade.clone().unwrap_or_default(),
+			self.tier.clone().unwrap_or_default(),
+			optionalField(self.xp),
+		];
+	}
+}
+
+fn optionalField(value: Option<impl ToString>) -> String
+{
+	return value.map(|v| v.to_string())
+		.unwrap_or_default();
+}
@@ -10,17 +10,38 @@ pub struct FilterCriteria
 	/// Should the text search be case sensitive
 	pub caseSensitive: bool,
 	
+	/// Display only games which include DLC (only on platforms which report it)
+	pub dlc: bool,
+	
+	/// Hide games the user has hidden in their library (only on platforms which support hiding)
+	pub hideHidden: bool,
+	
+	/// Display only games which run natively on Linux (only on platforms which report it)
+	pub linuxNative: bool,
+	
 	/// Display only locked or unlocked
 	pub locked: bool,
 	
+	/// Display only achievements which can be permanently missed (only on platforms which flag them)
+	pub missable: bool,
+	
 	/// Should the text be searched for in the name only
 	pub nameOnly: bool,
 	
+	/// Display only achievements required to beat the game (only on platforms which flag them)
+	pub progression: bool,
+	
 	/// Show all items when there is a boolean test that could hide some (i.e. games with no achievements metadata)
 	pub showAll: bool,
 	
+	/// Display only games with this user-defined tag (only on platforms which support tags)
+	pub tag: Option<String>,
+	
 	/// Search text to filter by name and description
 	pub text: String,
+	
+	/// Display only games the user wants to play (only on platforms which track them)
+	pub wantToPlay: bool,
 }
 
 impl From<String> for FilterCriteria
@@ -0,0 +1,404 @@
+use std::collections::HashSet;
+use std::fs::{File, create_dir_all, read_dir};
+use std::io::{self, ErrorKind, Write};
+use std::path::{Component, Path, PathBuf};
+use anyhow::{Context, Result, anyhow};
+use chrono::{DateTime, Local, Utc};
+use serde::{Deserialize, Serialize};
+use zip::write::SimpleFileOptions;
+use zip::{CompressionMethod, ZipArchive, ZipWriter};
+use crate::constants::{SecretsKeyFileName, SecretsVaultFileName};
+use super::backup::BackupsFragment;
+use super::io::{TemporaryExtension, getCacheDir, getConfigDir, getDataDir,
+	writeFileAtomically};
+
+/// The version of the archive layout written by the current build.
+pub const ArchiveVersion: u32 = 1;
+pub const ArchiveExtension: &str = "zip";
+
+const CacheFragment: &str = "cache";
+const ConfigFragment: &str = "config";
+const DataFragment: &str = "data";
+const ManifestFileName: &str = "manifest.json";
+
+/**
+The local directories whose contents are bundled into an archive.
+*/
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ArchiveDirectories
+{
+	pub cache: PathBuf,
+	pub config: PathBuf,
+	pub data: PathBuf,
+}
+
+impl ArchiveDirectories
+{
+	/// The application's own cache, configuration and data directories.
+	pub fn local() -> Result<Self>
+	{
+		return Ok(Self
+		{
+			cache: getCacheDir(true).ok_or(anyhow!(ErrorKind::NotFound))?.into(),
+			config: getConfigDir(true).ok_or(anyhow!(ErrorKind::NotFound))?.into(),
+			data: getDataDir(true).ok_or(anyhow!(ErrorKind::NotFound))?.into(),
+		});
+	}
+	
+	fn sections(&self) -> [(&'static str, &Path); 3]
+	{
+		return [
+			(CacheFragment, self.cache.as_path()),
+			(ConfigFragment, self.config.as_path()),
+			(DataFragment, self.data.as_path()),
+		];
+	}
+}
+
+/**
+Describes the contents of an archive. Stored as `manifest.json` at the root of
+the archive.
+*/
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct ArchiveManifest
+{
+	pub created: DateTime<Utc>,
+	/// The number of files in the archive, excluding the manifest.
+	pub files: usize,
+	pub version: u32,
+}
+
+/**
+A file name for a new archive, based on the current local time.
+*/
+pub fn defaultArchiveName() -> String
+{
+	return format!(
+		"reliquarian-{}.{}",
+		Local::now().format("%Y%m%d-%H%M%S"),
+		ArchiveExtension
+	);
+}
+
+/**
+Bundle the contents of the cache, configuration and data directories into a
+single archive at `destination`.
+
+The secrets key and vault are never included, nor are rolling backups or
+partially written temporary files. Any credentials will need to be entered
+again after the archive is imported.
+
+Some platforms share a single directory between configuration and data, or
+nest one directory inside another. Each file is only archived under the first
+section which contains it.
+*/
+pub fn exportArchive(destination: &Path, directories: &ArchiveDirectories) -> Result<ArchiveManifest>
+{
+	let mut archived = HashSet::new();
+	let mut entries = vec![];
+	for (section, root) in directories.sections()
+	{
+		let mut files = vec![];
+		collectFiles(root, &mut files)?;
+		
+		for file in files
+		{
+			let relative = file.strip_prefix(root)?;
+			if !isExcluded(relative) && archived.insert(file.clone())
+			{
+				entries.push((archivePath(section, relative)?, file));
+			}
+		}
+	}
+	
+	let manifest = ArchiveManifest
+	{
+		created: Utc::now(),
+		files: entries.len(),
+		version: ArchiveVersion,
+	};
+	
+	writeFileAtomically(destination, |writer| {
+		let options = SimpleFileOptions::default()
+			.compression_method(CompressionMethod::Deflated);
+		
+		let mut zip = ZipWriter::new(writer);
+		
+		zip.start_file(ManifestFileName, options)?;
+		zip.write_all(serde_json::to_string_pretty(&manifest)?.as_bytes())?;
+		
+		for (name, path) in &entries
+		{
+			zip.start_file(name.as_str(), options)?;
+			
+			let mut file = File::open(path)
+				.context(format!("Failed opening file at: '{}'", path.to_string_lossy()))?;
+			
+			io::copy(&mut file, &mut zip)
+				.context(format!("Failed archiving file at: '{}'", path.to_string_lossy()))?;
+		}
+		
+		zip.finish()?;
+		return Ok(());
+	})?;
+	
+	return Ok(manifest);
+}
+
+/**
+Restore the contents of an archive created by `exportArchive` into the cache,
+configuration and data directories, replacing any existing files of the same
+name.
+
+Fails without writing anything if the archive was created by a newer version
+of the application.
+*/
+pub fn importArchive(source: &Path, directories: &ArchiveDirectories) -> Result<ArchiveManifest>
+{
+	let file = File::open(source)
+		.context(format!("Failed opening archive at: '{}'", source.to_string_lossy()))?;
+	
+	let mut archive = ZipArchive::new(file)
+		.context(format!("Failed reading archive at: '{}'", source.to_string_lossy()))?;
+	
+	let manifest: ArchiveManifest = serde_json::from_reader(
+		archive.by_name(ManifestFileName)
+			.context("The archive does not contain a manifest")?
+	)?;
+	
+	if manifest.version > ArchiveVersion
+	{
+		return Err(anyhow!(
+			"Archive version {} is newer than the latest supported version {}",
+			manifest.version,
+			ArchiveVersion
+		));
+	}
+	
+	for index in 0..archive.len()
+	{
+		let mut entry = archive.by_index(index)?;
+		if entry.is_dir()
+		{
+			continue;
+		}
+		
+		let name = entry.enclosed_name()
+			.ok_or(anyhow!("The archive contains an unsafe path: '{}'", entry.name()))?;
+		
+		let mut components = name.components();
+		let root = match components.next().and_then(|c| c.as_os_str().to_str())
+		{
+			Some(CacheFragment) => &directories.cache,
+			Some(ConfigFragment) => &directories.config,
+			Some(DataFragment) => &directories.data,
+			_ => continue,
+		};
+		
+		let relative = components.as_path();
+		if isExcluded(relative)
+		{
+			continue;
+		}
+		
+		let destination = root.join(relative);
+		if let Some(parent) = destination.parent()
+		{
+			create_dir_all(parent)?;
+		}
+		
+		writeFileAtomically(&destination, |writer| {
+			io::copy(&mut entry, writer)?;
+			return Ok(());
+		})?;
+	}
+	
+	return Ok(manifest);
+}
+
+fn archivePath(section: &str, relative: &Path) -> Result<String>
+{
+	let mut parts = vec![section.to_string()];
+	for component in relative.components()
+	{
+		match component
+		{
+			Component::Normal(part) => parts.push(
+				part.to_str()
+					.ok_or(anyhow!("Unsupported file name: '{}'", relative.to_string_lossy()))?
+					.to_string()
+			),
+			
+			_ => return Err(anyhow!("Unsupported path: '{}'", relative.to_string_lossy())),
+		}
+	}
+	
+	return Ok(parts.join("/"));
+}
+
+fn collectFiles(directory: &Path, files: &mut Vec<PathBuf>) -> Result<()>
+{
+	if !directory.is_dir()
+	{
+		return Ok(());
+	}
+	
+	for entry in read_dir(directory)?
+	{
+		let path = entry?.path();
+		match path.is_dir()
+		{
+			false => files.push(path),
+			true => collectFiles(&path, files)?,
+		}
+	}
+	
+	return Ok(());
+}
+
+/**
+Files which must never be moved between machines, either because they are
+secret or because they are only meaningful locally.
+*/
+fn isExcluded(relative: &Path) -> bool
+{
+	let isSecret = relative.file_name()
+		.and_then(|name| name.to_str())
+		.is_some_and(|name| name == SecretsKeyFileName || name == SecretsVaultFileName);
+	
+	let isTemporary = relative.to_string_lossy()
+		.ends_with(TemporaryExtension);
+	
+	let isBackup = relative.components()
+		.next()
+		.and_then(|component| component.as_os_str().to_str())
+		.is_some_and(|component| component == BackupsFragment);
+	
+	return isSecret || isTemporary || isBackup;
+}
+
+#[cfg(test)]
+mod tests
+{
+	use std::env::temp_dir;
+	use std::fs::{self, remove_dir_all};
+	use super::*;
+	
+	fn testDirectories(name: &str) -> ArchiveDirectories
+	{
+		let root = temp_dir()
+			.join(format!("reliquarian-archive-{}-{}", name, std::process::id()));
+		
+		_ = remove_dir_all(&root);
+		
+		return ArchiveDirectories
+		{
+			cache: root.join("cache"),
+			config: root.join("config"),
+			data: root.join("data"),
+		};
+	}
+	
+	fn writeFile(path: PathBuf, contents: &str)
+	{
+		create_dir_all(path.parent().unwrap()).unwrap();
+		fs::write(path, contents).unwrap();
+	}
+	
+	#[test]
+	fn exportAndImport()
+	{
+		let source = testDirectories("source");
+		writeFile(source.cache.join("steam").join("games_73").join("header.jpg"), "image");
+		writeFile(source.config.join("settings").join("config.json"), "settings");
+		writeFile(source.config.join("settings").join(SecretsKeyFileName), "key");
+		writeFile(source.config.join("settings").join(SecretsVaultFileName), "vault");
+		writeFile(source.data.join("steam.json"), "user");
+		writeFile(source.data.join("steam.json.tmp"), "partial");
+		writeFile(source.data.join(BackupsFragment).join("steam-20251207052532000.json"), "backup");
+		
+		let archive = source.data.parent().unwrap().join(defaultArchiveName());
+		let exported = exportArchive(&archive, &source).unwrap();
+		assert_eq!(exported.files, 3);
+		assert_eq!(exported.version, ArchiveVersion);
+		
+		let destination = testDirectories("destination");
+		writeFile(destination.data.join("steam.json"), "old user");
+		
+		let imported = importArchive(&archive, &destination).unwrap();
+		assert_eq!(imported, exported);
+		
+		let read = |path: PathBuf| fs::read_to_string(path).ok();
+		assert_eq!(read(destination.cache.join("steam").join("games_73").join("header.jpg")), Some("image".into()));
+		assert_eq!(read(destination.config.join("settings").join("config.json")), Some("settings".into()));
+		assert_eq!(read(destination.data.join("steam.json")), Some("user".into()));
+		assert_eq!(read(destination.config.join("settings").join(SecretsKeyFileName)), None);
+		assert_eq!(read(destination.config.join("settings").join(SecretsVaultFileName)), None);
+		assert_eq!(read(destination.data.join("steam.json.tmp")), None);
+		assert!(!destination.data.join(BackupsFragment).exists());
+		
+		_ = remove_dir_all(source.data.parent().unwrap());
+		_ = remove_dir_all(destination.data.parent().unwrap());
+	}
+	
+	#[test]
+	fn sharedDirectories()
+	{
+		let mut source = testDirectories("shared");
+		source.data = source.config.clone();
+		source.cache = source.config.join("cache");
+		
+		writeFile(source.cache.join("header.jpg"), "image");
+		writeFile(source.config.join("settings").join("config.json"), "settings");
+		writeFile(source.data.join("steam.json"), "user");
+		
+		let archive = source.config.parent().unwrap().join(defaultArchiveName());
+		let exported = exportArchive(&archive, &source).unwrap();
+		assert_eq!(exported.files, 3);
+		
+		let file = File::open(&archive).unwrap();
+		let mut names = ZipArchive::new(file).unwrap()
+			.file_names()
+			.map(|name| name.to_string())
+			.collect::<Vec<_>>();
+		
+		names.sort();
+		assert_eq!(names, vec![
+			"cache/header.jpg".to_string(),
+			"config/settings/config.json".to_string(),
+			"config/steam.json".to_string(),
+			ManifestFileName.to_string(),
+		]);
+		
+		_ = remove_dir_all(source.config.parent().unwrap());
+	}
+	
+	#[test]
+	fn newerVersion()
+	{
+		let directories = testDirectories("version");
+		create_dir_all(&directories.data).unwrap();
+		
+		let archive = directories.data.join(defaultArchiveName());
+		let file = File::create(&archive).unwrap();
+		let mut zip = ZipWriter::new(file);
+		
+		let manifest = ArchiveManifest
+		{
+			created: Utc::now(),
+			files: 1,
+			version: ArchiveVersion + 1,
+		};
+		
+		zip.start_file(ManifestFileName, SimpleFileOptions::default()).unwrap();
+		zip.write_all(serde_json::to_string(&manifest).unwrap().as_bytes()).unwrap();
+		zip.start_file("data/steam.json", SimpleFileOptions::default()).unwrap();
+		zip.write_all(b"user").unwrap();
+		zip.finish().unwrap();
+		
+		assert!(importArchive(&archive, &directories).is_err());
+		assert!(!directories.data.join("steam.json").exists());
+		
+		_ = remove_dir_all(directories.data.parent().unwrap());
+	}
+}
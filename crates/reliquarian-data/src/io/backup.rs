@@ -0,0 +1,315 @@
+use std::fs::{self, create_dir_all, read_dir};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use anyhow::{Context, Result, anyhow};
+use chrono::{DateTime, NaiveDateTime, TimeDelta, Utc};
+use serde_json::Value;
+use super::io::writeFileAtomically;
+
+pub(super) const BackupsFragment: &str = "backups";
+const TimestampFormat: &str = "%Y%m%d%H%M%S%3f";
+
+/// The minimum number of hours between the backups taken when saving.
+const BackupIntervalHours: i64 = 6;
+
+/**
+A timestamped copy of a data file, stored in the `backups` subdirectory of the
+directory which contains the original file.
+
+The backup's file name is the original file name with the timestamp inserted
+before the extension, e.g. `steam-20251207052532123.json`.
+*/
+#[derive(Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+pub struct DataBackup
+{
+	/// The file name, with extension, of the original file.
+	pub fileName: String,
+	pub path: PathBuf,
+	pub timestamp: DateTime<Utc>,
+}
+
+impl DataBackup
+{
+	fn new(directory: &Path, fileName: String, timestamp: DateTime<Utc>) -> Self
+	{
+		let stamp = timestamp.format(TimestampFormat);
+		let backupName = match fileName.rsplit_once('.')
+		{
+			None => format!("{}-{}", fileName, stamp),
+			Some((stem, extension)) => format!("{}-{}.{}", stem, stamp, extension),
+		};
+		
+		return Self
+		{
+			path: directory.join(backupName),
+			fileName,
+			timestamp,
+		};
+	}
+	
+	fn fromPath(path: PathBuf) -> Option<Self>
+	{
+		let name = path.file_name()?.to_str()?;
+		
+		let (stem, extension) = match name.rsplit_once('.')
+		{
+			None => (name, None),
+			Some((stem, extension)) => (stem, Some(extension)),
+		};
+		
+		let (original, stamp) = stem.rsplit_once('-')?;
+		let timestamp = NaiveDateTime::parse_from_str(stamp, TimestampFormat)
+			.ok()?
+			.and_utc();
+		
+		let fileName = match extension
+		{
+			None => original.to_string(),
+			Some(extension) => format!("{}.{}", original, extension),
+		};
+		
+		return Some(Self
+		{
+			fileName,
+			path,
+			timestamp,
+		});
+	}
+}
+
+/**
+Copy a data file into the backups directory, then delete the oldest backups of
+that file so that no more than `count` remain.
+
+Nothing is copied if `count` is zero or the file does not exist yet.
+
+## Parameters
+- directory: `String` Absolute path to the directory which contains the file.
+- fileName: `String` File name with extension of the file to back up.
+- count: `usize` The maximum number of backups of the file to keep.
+*/
+pub fn backupDataFile(directory: String, fileName: String, count: usize) -> Result<Option<DataBackup>>
+{
+	let source = Path::new(directory.as_str())
+		.join(&fileName);
+	
+	if count == 0 || !source.exists()
+	{
+		return Ok(None);
+	}
+	
+	let backupsDir = Path::new(directory.as_str())
+		.join(BackupsFragment);
+	
+	create_dir_all(&backupsDir)
+		.context(format!(
+			"Failed creating the backups directory at: '{}'",
+			backupsDir.to_string_lossy()
+		))?;
+	
+	let backup = DataBackup::new(&backupsDir, fileName, Utc::now());
+	
+	fs::copy(&source, &backup.path)
+		.context(format!(
+			"Failed copying '{}' to '{}'",
+			source.to_string_lossy(),
+			backup.path.to_string_lossy()
+		))?;
+	
+	for expired in listBackups(directory)
+		.into_iter()
+		.filter(|b| b.fileName == backup.fileName)
+		.skip(count)
+	{
+		fs::remove_file(&expired.path)
+			.context(format!(
+				"Failed deleting expired backup at: '{}'",
+				expired.path.to_string_lossy()
+			))?;
+	}
+	
+	return Ok(Some(backup));
+}
+
+/**
+Copy a data file into the backups directory, as `backupDataFile` does, unless
+the newest backup of that file was taken within the last few hours.
+
+A single refresh saves the same file many times, so backing up on every save
+would quickly replace every backup with near-identical copies.
+
+## Parameters
+- directory: `String` Absolute path to the directory which contains the file.
+- fileName: `String` File name with extension of the file to back up.
+- count: `usize` The maximum number of backups of the file to keep.
+*/
+pub fn backupDataFilePeriodically(directory: String, fileName: String, count: usize) -> Result<Option<DataBackup>>
+{
+	return backupDataFileOlderThan(directory, fileName, count, TimeDelta::hours(BackupIntervalHours));
+}
+
+fn backupDataFileOlderThan(directory: String, fileName: String, count: usize, interval: TimeDelta) -> Result<Option<DataBackup>>
+{
+	let recent = listBackups(directory.clone())
+		.into_iter()
+		.find(|b| b.fileName == fileName)
+		.is_some_and(|b| Utc::now() - b.timestamp < interval);
+	
+	return match recent
+	{
+		false => backupDataFile(directory, fileName, count),
+		true => Ok(None),
+	};
+}
+
+/**
+List every backup in the backups directory, newest first.
+
+## Parameters
+- directory: `String` Absolute path to the directory which contains the original files.
+*/
+pub fn listBackups(directory: String) -> Vec<DataBackup>
+{
+	let backupsDir = Path::new(directory.as_str())
+		.join(BackupsFragment);
+	
+	let mut backups = match read_dir(backupsDir)
+	{
+		Err(_) => vec![],
+		Ok(entries) => entries.filter_map(|entry| entry.ok())
+			.filter(|entry| entry.path().is_file())
+			.filter_map(|entry| DataBackup::fromPath(entry.path()))
+			.collect::<Vec<_>>(),
+	};
+	
+	backups.sort_by(|a, b| b.timestamp.cmp(&a.timestamp)
+		.then_with(|| a.fileName.cmp(&b.fileName)));
+	
+	return backups;
+}
+
+/**
+Replace a data file with the contents of one of its backups.
+
+The current file is backed up first, so a restore can itself be undone.
+Backups of JSON files are validated before anything is replaced.
+
+## Parameters
+- directory: `String` Absolute path to the directory which contains the original file.
+- backup: `&DataBackup` The backup to restore.
+- count: `usize` The maximum number of backups of the file to keep.
+*/
+pub fn restoreBackup(directory: String, backup: &DataBackup, count: usize) -> Result<()>
+{
+	let contents = fs::read(&backup.path)
+		.context(format!(
+			"Failed reading backup at: '{}'",
+			backup.path.to_string_lossy()
+		))?;
+	
+	if backup.fileName.ends_with(".json")
+	{
+		serde_json::from_slice::<Value>(&contents)
+			.map_err(|e| anyhow!(
+				"The backup at '{}' is not valid JSON: {}",
+				backup.path.to_string_lossy(),
+				e
+			))?;
+	}
+	
+	backupDataFile(directory.clone(), backup.fileName.clone(), count)?;
+	
+	let destination = Path::new(directory.as_str())
+		.join(&backup.fileName);
+	
+	return writeFileAtomically(&destination, |writer| Ok(writer.write_all(&contents)?));
+}
+
+#[cfg(test)]
+mod tests
+{
+	use std::env::temp_dir;
+	use std::fs::remove_dir_all;
+	use chrono::TimeZone;
+	use super::*;
+	
+	fn testDirectory(name: &str) -> String
+	{
+		let path = temp_dir()
+			.join(format!("reliquarian-backup-{}-{}", name, std::process::id()));
+		
+		_ = remove_dir_all(&path);
+		create_dir_all(&path).unwrap();
+		return path.to_string_lossy().to_string();
+	}
+	
+	#[test]
+	fn backupName()
+	{
+		let timestamp = Utc.with_ymd_and_hms(2025, 12, 7, 5, 25, 32).unwrap();
+		let backup = DataBackup::new(Path::new("backups"), "steam.json".into(), timestamp);
+		
+		assert_eq!(backup.path, Path::new("backups").join("steam-20251207052532000.json"));
+		assert_eq!(DataBackup::fromPath(backup.path.clone()), Some(backup));
+		
+		let backup = DataBackup::new(Path::new("backups"), "my-data".into(), timestamp);
+		assert_eq!(DataBackup::fromPath(backup.path.clone()), Some(backup));
+		
+		assert_eq!(DataBackup::fromPath("backups/steam.json".into()), None);
+	}
+	
+	#[test]
+	fn backupAndRestore()
+	{
+		let directory = testDirectory("restore");
+		let file = Path::new(&directory).join("test.json");
+		
+		assert_eq!(backupDataFile(directory.clone(), "test.json".into(), 2).unwrap(), None);
+		
+		for version in 1..=4
+		{
+			fs::write(&file, format!("{{\"version\":{}}}", version)).unwrap();
+			assert!(backupDataFile(directory.clone(), "test.json".into(), 0).unwrap().is_none());
+			assert!(backupDataFile(directory.clone(), "test.json".into(), 2).unwrap().is_some());
+			std::thread::sleep(std::time::Duration::from_millis(2));
+		}
+		
+		let backups = listBackups(directory.clone());
+		assert_eq!(backups.len(), 2);
+		assert_eq!(fs::read_to_string(&backups[0].path).unwrap(), "{\"version\":4}");
+		assert_eq!(fs::read_to_string(&backups[1].path).unwrap(), "{\"version\":3}");
+		
+		fs::write(&file, "{\"version\":5}").unwrap();
+		restoreBackup(directory.clone(), &backups[1], 2).unwrap();
+		assert_eq!(fs::read_to_string(&file).unwrap(), "{\"version\":3}");
+		
+		// The replaced file was backed up and the oldest backup was pruned
+		let backups = listBackups(directory.clone());
+		assert_eq!(backups.len(), 2);
+		assert_eq!(fs::read_to_string(&backups[0].path).unwrap(), "{\"version\":5}");
+		assert_eq!(fs::read_to_string(&backups[1].path).unwrap(), "{\"version\":4}");
+		
+		fs::write(&backups[0].path, "{\"version\":").unwrap();
+		assert!(restoreBackup(directory.clone(), &backups[0], 2).is_err());
+		assert_eq!(fs::read_to_string(&file).unwrap(), "{\"version\":3}");
+		
+		_ = remove_dir_all(&directory);
+	}
+	
+	#[test]
+	fn backupPeriodically()
+	{
+		let directory = testDirectory("periodic");
+		fs::write(Path::new(&directory).join("test.json"), "{}").unwrap();
+		
+		assert!(backupDataFilePeriodically(directory.clone(), "test.json".into(), 2).unwrap().is_some());
+		assert!(backupDataFilePeriodically(directory.clone(), "test.json".into(), 2).unwrap().is_none());
+		assert_eq!(listBackups(directory.clone()).len(), 1);
+		
+		std::thread::sleep(std::time::Duration::from_millis(2));
+		assert!(backupDataFileOlderThan(directory.clone(), "test.json".into(), 2, TimeDelta::zero()).unwrap().is_some());
+		assert_eq!(listBackups(directory.clone()).len(), 2);
+		
+		_ = remove_dir_all(&directory);
+	}
+}
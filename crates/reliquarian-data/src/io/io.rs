@@ -7,8 +7,10 @@ use image::ImageReader;
 use reqwest::Client;
 use serde::Serialize;
 use serde::de::DeserializeOwned;
+use serde_json::Value;
 use crate::constants::{SecretsKeyFileName, SecretsVaultFileName};
 use crate::io::FileLocation;
+use crate::migration::{Envelope, Versioned};
 use crate::settings::AppSettings;
 
 const Application: &str = "reliquarian";
@@ -16,6 +18,7 @@ const Organization: &str = "";
 const Qualifier: &str = "";
 
 const SettingsFragment: &str = "settings";
+pub(super) const TemporaryExtension: &str = ".tmp";
 
 /**
 Retrieve the image from a `url` and store it in the cache directory.
@@ -196,12 +199,15 @@ pub fn loadAppSettings() -> Result<AppSettings>
 Generic method to read data from file and deserialize it into a given type which
 implements `DeserializeOwned`.
 
+Data written by an older version of the application is upgraded to the current
+version of `T` before it is deserialized.
+
 ## Parameters
 - directory: `String` Absolute path to the directory which contains the desired file.
 - fileName: `String` File name with extension of the desired file.
 */
 pub fn readDataFromFile<T>(directory: String, fileName: String) -> Result<T>
-	where T: DeserializeOwned
+	where T: DeserializeOwned + Versioned
 {
 	let path = Path::new(directory.as_str())
 		.join(fileName);
@@ -214,10 +220,23 @@ pub fn readDataFromFile<T>(directory: String, fileName: String) -> Result<T>
 	
 	let reader = BufReader::new(file);
 	
-	return Ok(serde_json::from_reader(reader)
+	let value: Value = serde_json::from_reader(reader)
 		.context(format!(
 			"Failed parsing as JSON file at: '{}'",
 			path.as_path().to_str().unwrap()
+		))?;
+	
+	let data = Envelope::fromValue(value)
+		.upgrade::<T>()
+		.context(format!(
+			"Failed migrating the data in file at: '{}'",
+			path.as_path().to_str().unwrap()
+		))?;
+	
+	return Ok(serde_json::from_value(data)
+		.context(format!(
+			"Failed deserializing the data in file at: '{}'",
+			path.as_path().to_str().unwrap()
 		))?);
 }
 
@@ -302,29 +321,87 @@ pub fn saveImageToCache(
 /**
 Generic method to write a given type which implements `Serialize` to file.
 
+The data is wrapped in an `Envelope` recording the current version of `T`. It
+is written to a temporary file which then replaces the destination, so the
+existing file is never left partially written.
+
 ## Parameters
 - directory: `String` Absolute path to the directory which contains the desired file.
 - fileName: `String` File name with extension of the desired file.
 - data: `&T` The data to be written.
 */
 pub fn writeDataToFile<T>(directory: String, fileName: String, data: &T) -> Result<()>
-	where T: Serialize
+	where T: Serialize + Versioned
 {
 	let path = Path::new(directory.as_str())
 		.join(fileName);
 	
-	let file = File::create(&path)
+	return writeFileAtomically(&path, |buffer| {
+		serde_json::to_writer_pretty(buffer, &Envelope::wrap(data))
+			.context(format!(
+				"serde_json failed writing data to BufWriter at: '{}'",
+				path.as_path().to_str().unwrap()
+			))?;
+		
+		return Ok(());
+	});
+}
+
+/**
+Write to a temporary file alongside `path` and, only once everything has been
+written and flushed to disk, rename it over `path`.
+
+The temporary file is removed if anything fails.
+*/
+pub fn writeFileAtomically<F>(path: &Path, write: F) -> Result<()>
+	where F: FnOnce(&mut BufWriter<File>) -> Result<()>
+{
+	let mut temporaryName = path.file_name()
+		.ok_or(anyhow!(ErrorKind::InvalidInput))?
+		.to_owned();
+	temporaryName.push(TemporaryExtension);
+	
+	let temporary = path.with_file_name(temporaryName);
+	
+	let result = writeAndSync(&temporary, write)
+		.and_then(|_| fs::rename(&temporary, path)
+			.context(format!(
+				"Failed replacing the file at: '{}'",
+				path.to_string_lossy()
+			))
+		);
+	
+	if result.is_err()
+	{
+		_ = fs::remove_file(&temporary);
+	}
+	
+	return result;
+}
+
+fn writeAndSync<F>(path: &Path, write: F) -> Result<()>
+	where F: FnOnce(&mut BufWriter<File>) -> Result<()>
+{
+	let file = File::create(path)
 		.context(format!(
 			"Failed creating or truncating the file at: '{}'",
-			path.as_path().to_str().unwrap()
+			path.to_string_lossy()
 		))?;
 	
-	let buffer = BufWriter::new(file);
+	let mut buffer = BufWriter::new(file);
+	write(&mut buffer)?;
 	
-	_ = serde_json::to_writer_pretty(buffer, data)
+	let file = buffer.into_inner()
+		.map_err(|e| e.into_error())
 		.context(format!(
-			"serde_json failed writing data to BufWriter at: '{}'",
-			path.as_path().to_str().unwrap()
+			"Failed flushing the file at: '{}'",
+			path.to_string_lossy()
+		))?;
+	
+	file.sync_all()
+		.context(format!(
+			"Failed syncing the file at: '{}'",
+			path.to_string_lossy()
 		))?;
 	
 	return Ok(());
@@ -1,5 +1,11 @@
+mod archive;
+mod backup;
 mod io;
 mod location;
 
+pub use archive::{ArchiveDirectories, ArchiveExtension, ArchiveManifest,
+	ArchiveVersion, defaultArchiveName, exportArchive, importArchive};
+pub use backup::{DataBackup, backupDataFile, backupDataFilePeriodically,
+	listBackups, restoreBackup};
 pub use io::*;
 pub use location::FileLocation;
@@ -1,9 +1,13 @@
 pub mod constants;
 pub mod enums;
+pub mod export;
 pub mod filter;
 pub mod format;
 pub mod io;
+pub mod migration;
 pub mod settings;
+pub mod storage;
+pub mod unified;
 
 use std::sync::LazyLock;
 use freya::prelude::{ButtonLayoutThemePreference, ColorsSheet, Gaps, Preference,
@@ -0,0 +1,109 @@
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use super::versioned::{LegacyVersion, Versioned};
+
+/**
+The wrapper written around persisted data to record the version of the format
+in which it was written.
+*/
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct Envelope<T>
+{
+	pub data: T,
+	pub version: u32,
+}
+
+impl<'a, T> Envelope<&'a T>
+	where T: Versioned
+{
+	/// Wrap `data` for writing at the current version of `T`.
+	pub fn wrap(data: &'a T) -> Self
+	{
+		return Self
+		{
+			data,
+			version: T::Version,
+		};
+	}
+}
+
+impl Envelope<Value>
+{
+	/**
+	Separate serialized data from its version.
+	
+	Data which is not wrapped in an envelope is assumed to have been written
+	before versioning was introduced and is treated as `LegacyVersion`.
+	*/
+	pub fn fromValue(value: Value) -> Self
+	{
+		if let Value::Object(map) = &value
+		{
+			if map.len() == 2 && map.contains_key("data")
+			{
+				if let Some(version) = map.get("version")
+					.and_then(|v| v.as_u64())
+					.and_then(|v| u32::try_from(v).ok())
+				{
+					let mut map = map.clone();
+					
+					return Self
+					{
+						data: map.remove("data").unwrap_or_default(),
+						version,
+					};
+				}
+			}
+		}
+		
+		return Self
+		{
+			data: value,
+			version: LegacyVersion,
+		};
+	}
+	
+	/// Upgrade the data to the current version of `T`.
+	pub fn upgrade<T: Versioned>(self) -> Result<Value>
+	{
+		return T::migrations().migrate(self.data, self.version, T::Version);
+	}
+}
+
+#[cfg(test)]
+mod tests
+{
+	use serde_json::json;
+	use super::*;
+	
+	#[derive(Serialize)]
+	struct Legacy;
+	
+	impl Versioned for Legacy {}
+	
+	#[test]
+	fn fromValue()
+	{
+		let data = json!({ "games": [], "name": "The name" });
+		
+		let legacy = Envelope::fromValue(data.clone());
+		assert_eq!(legacy.data, data);
+		assert_eq!(legacy.version, LegacyVersion);
+		
+		let enveloped = Envelope::fromValue(json!({ "data": data.clone(), "version": 3 }));
+		assert_eq!(enveloped.data, data);
+		assert_eq!(enveloped.version, 3);
+		
+		// Objects which merely contain a data property are not envelopes
+		let partial = json!({ "data": "The data", "name": "The name" });
+		assert_eq!(Envelope::fromValue(partial.clone()).data, partial);
+	}
+	
+	#[test]
+	fn wrap()
+	{
+		let value = serde_json::to_value(Envelope::wrap(&Legacy)).unwrap();
+		assert_eq!(value, json!({ "data": null, "version": LegacyVersion }));
+	}
+}
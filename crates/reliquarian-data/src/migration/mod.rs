@@ -0,0 +1,7 @@
+mod envelope;
+mod registry;
+mod versioned;
+
+pub use envelope::Envelope;
+pub use registry::{Migration, MigrationRegistry};
+pub use versioned::{LegacyVersion, Versioned};
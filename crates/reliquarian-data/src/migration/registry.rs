@@ -0,0 +1,112 @@
+use std::collections::BTreeMap;
+use anyhow::{Result, anyhow};
+use serde_json::Value;
+
+/// A single step which upgrades serialized data by exactly one version.
+pub type Migration = fn(Value) -> Result<Value>;
+
+/**
+The ordered collection of steps used to upgrade serialized data from any older
+version to the current version.
+*/
+#[derive(Clone, Debug, Default)]
+pub struct MigrationRegistry
+{
+	steps: BTreeMap<u32, Migration>,
+}
+
+impl MigrationRegistry
+{
+	pub fn new() -> Self
+	{
+		return Self::default();
+	}
+	
+	/**
+	Upgrade `value` from version `from` to version `to` by applying each
+	registered step in turn.
+	
+	Fails if `from` is newer than `to` or if any of the required steps has not
+	been registered.
+	*/
+	pub fn migrate(&self, value: Value, from: u32, to: u32) -> Result<Value>
+	{
+		if from > to
+		{
+			return Err(anyhow!(
+				"Data version {} is newer than the latest supported version {}",
+				from,
+				to
+			));
+		}
+		
+		let mut value = value;
+		for version in from..to
+		{
+			let step = self.steps.get(&version)
+				.ok_or(anyhow!("No migration registered from version {} to {}", version, version + 1))?;
+			
+			value = step(value)?;
+		}
+		
+		return Ok(value);
+	}
+	
+	/// Register the step which upgrades data from version `from` to `from + 1`.
+	pub fn register(mut self, from: u32, step: Migration) -> Self
+	{
+		self.steps.insert(from, step);
+		return self;
+	}
+}
+
+#[cfg(test)]
+mod tests
+{
+	use serde_json::json;
+	use super::*;
+	
+	fn renameTitle(mut value: Value) -> Result<Value>
+	{
+		if let Value::Object(map) = &mut value
+		{
+			if let Some(title) = map.remove("title")
+			{
+				map.insert("name".into(), title);
+			}
+		}
+		
+		return Ok(value);
+	}
+	
+	fn addTags(mut value: Value) -> Result<Value>
+	{
+		if let Value::Object(map) = &mut value
+		{
+			map.insert("tags".into(), json!([]));
+		}
+		
+		return Ok(value);
+	}
+	
+	#[test]
+	fn migrate()
+	{
+		let registry = MigrationRegistry::new()
+			.register(2, addTags)
+			.register(1, renameTitle);
+		
+		let v1 = json!({ "title": "The name" });
+		let v2 = json!({ "name": "The name" });
+		let v3 = json!({ "name": "The name", "tags": [] });
+		
+		assert_eq!(registry.migrate(v1.clone(), 1, 1).unwrap(), v1);
+		assert_eq!(registry.migrate(v1.clone(), 1, 2).unwrap(), v2);
+		assert_eq!(registry.migrate(v1.clone(), 1, 3).unwrap(), v3);
+		assert_eq!(registry.migrate(v2.clone(), 2, 3).unwrap(), v3);
+		assert_eq!(registry.migrate(v3.clone(), 3, 3).unwrap(), v3);
+		
+		assert!(registry.migrate(v3.clone(), 4, 3).is_err());
+		assert!(registry.migrate(v3.clone(), 3, 4).is_err());
+	}
+}
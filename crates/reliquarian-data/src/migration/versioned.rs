@@ -0,0 +1,29 @@
+use super::registry::MigrationRegistry;
+
+/**
+The version assumed for any persisted data which was written before versioning
+was introduced, and therefore has no `Envelope`.
+*/
+pub const LegacyVersion: u32 = 1;
+
+/**
+Persisted data whose format is versioned, allowing data written by older builds
+to be upgraded to the current format when it is read.
+*/
+pub trait Versioned
+{
+	/**
+	The version of the format written by the current build.
+	
+	Increment this whenever the serialized format changes in a way which
+	requires existing data to be transformed, and register the corresponding
+	step in `migrations`.
+	*/
+	const Version: u32 = LegacyVersion;
+	
+	/// The steps which upgrade older versions of the data to `Version`.
+	fn migrations() -> MigrationRegistry
+	{
+		return MigrationRegistry::new();
+	}
+}
@@ -1,9 +1,49 @@
 use serde::{Deserialize, Serialize};
 use strum_macros::{Display, EnumIter, EnumString};
 
+/**
+The language in which achievement names and descriptions are requested, for
+those platforms which support localization.
+
+Every language supported by Steam is available.
+*/
 #[derive(Clone, Copy, Default, Debug, Deserialize, Display, EnumIter, EnumString, Eq, Hash, PartialEq, PartialOrd, Serialize)]
 pub enum Language
 {
+	Arabic,
+	Bulgarian,
+	Czech,
+	Danish,
+	Dutch,
 	#[default]
-	English
+	English,
+	Finnish,
+	French,
+	German,
+	Greek,
+	Hungarian,
+	Indonesian,
+	Italian,
+	Japanese,
+	Korean,
+	Norwegian,
+	Polish,
+	Portuguese,
+	#[strum(to_string="Portuguese (Brazil)")]
+	PortugueseBrazil,
+	Romanian,
+	Russian,
+	#[strum(to_string="Simplified Chinese")]
+	SimplifiedChinese,
+	#[strum(to_string="Spanish (Latin America)")]
+	SpanishLatinAmerica,
+	#[strum(to_string="Spanish (Spain)")]
+	SpanishSpain,
+	Swedish,
+	Thai,
+	#[strum(to_string="Traditional Chinese")]
+	TraditionalChinese,
+	Turkish,
+	Ukrainian,
+	Vietnamese,
 }
@@ -1,7 +1,11 @@
 mod language;
 mod platforms;
+mod rarity;
 mod settings;
+mod sync;
 
 pub use language::Language;
 pub use platforms::EnabledPlatforms;
+pub use rarity::RarityThresholds;
 pub use settings::AppSettings;
+pub use sync::{PlatformSync, SyncSchedule};
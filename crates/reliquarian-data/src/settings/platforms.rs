@@ -29,7 +29,9 @@ impl EnabledPlatforms
 	{
 		return match active
 		{
-			ActiveContent::Settings => true,
+			ActiveContent::AllPlatforms
+				| ActiveContent::Settings
+				| ActiveContent::Timeline => true,
 			
 			ActiveContent::BattleNet => self.battleNet,
 			ActiveContent::EpicGamesStore => self.epicGamesStores,
@@ -0,0 +1,125 @@
+use serde::{Deserialize, Serialize};
+use crate::enums::RarityTier;
+
+/**
+The percentages of players below which an achievement is considered to belong
+to each rarity tier. Achievements unlocked by at least `uncommon` percent of
+players are common.
+
+Each threshold must be lower than the one above it, so that every tier covers
+at least some percentages.
+*/
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Serialize)]
+pub struct RarityThresholds
+{
+	#[serde(default = "defaultRare")]
+	pub rare: f32,
+	
+	#[serde(default = "defaultUltraRare")]
+	pub ultraRare: f32,
+	
+	#[serde(default = "defaultUncommon")]
+	pub uncommon: f32,
+}
+
+impl Default for RarityThresholds
+{
+	fn default() -> Self
+	{
+		return Self
+		{
+			rare: defaultRare(),
+			ultraRare: defaultUltraRare(),
+			uncommon: defaultUncommon(),
+		};
+	}
+}
+
+impl RarityThresholds
+{
+	/// Whether every threshold is a percentage lower than the one above it.
+	pub fn isValid(&self) -> bool
+	{
+		return 0.0 <= self.ultraRare
+			&& self.ultraRare < self.rare
+			&& self.rare < self.uncommon
+			&& self.uncommon <= 100.0;
+	}
+	
+	pub fn tier(&self, percent: f32) -> RarityTier
+	{
+		return match percent
+		{
+			p if p < self.ultraRare => RarityTier::UltraRare,
+			p if p < self.rare => RarityTier::Rare,
+			p if p < self.uncommon => RarityTier::Uncommon,
+			_ => RarityTier::Common,
+		};
+	}
+}
+
+fn defaultRare() -> f32
+{
+	return 20.0;
+}
+
+fn defaultUltraRare() -> f32
+{
+	return 5.0;
+}
+
+fn defaultUncommon() -> f32
+{
+	return 50.0;
+}
+
+#[cfg(test)]
+mod tests
+{
+	use super::*;
+	
+	#[test]
+	fn tier()
+	{
+		let thresholds = RarityThresholds::default();
+		assert_eq!(thresholds.tier(75.3), RarityTier::Common);
+		assert_eq!(thresholds.tier(50.0), RarityTier::Common);
+		assert_eq!(thresholds.tier(49.9), RarityTier::Uncommon);
+		assert_eq!(thresholds.tier(12.0), RarityTier::Rare);
+		assert_eq!(thresholds.tier(0.4), RarityTier::UltraRare);
+		
+		let custom = RarityThresholds
+		{
+			rare: 10.0,
+			ultraRare: 0.5,
+			uncommon: 30.0,
+		};
+		assert_eq!(custom.tier(12.0), RarityTier::Uncommon);
+		assert_eq!(custom.tier(4.0), RarityTier::Rare);
+		assert_eq!(custom.tier(0.4), RarityTier::UltraRare);
+	}
+	
+	#[test]
+	fn isValid()
+	{
+		assert!(RarityThresholds::default().isValid());
+		
+		let mut thresholds = RarityThresholds
+		{
+			rare: 20.0,
+			ultraRare: 25.0,
+			uncommon: 50.0,
+		};
+		assert!(!thresholds.isValid());
+		
+		thresholds.ultraRare = 20.0;
+		assert!(!thresholds.isValid());
+		
+		thresholds.ultraRare = 2.5;
+		thresholds.uncommon = 150.0;
+		assert!(!thresholds.isValid());
+		
+		thresholds.uncommon = 100.0;
+		assert!(thresholds.isValid());
+	}
+}
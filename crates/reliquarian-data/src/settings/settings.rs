@@ -1,13 +1,22 @@
 use serde::{Deserialize, Serialize};
 use crate::enums::ActiveContent;
+use crate::migration::Versioned;
+use crate::storage::StorageBackend;
 use super::platforms::EnabledPlatforms;
 use super::Language;
+use super::rarity::RarityThresholds;
+use super::sync::SyncSchedule;
 
+pub const DefaultBackupCount: u64 = 5;
 pub const DefaultNotificationDuration: u64 = 1000;
 
 #[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct AppSettings
 {
+	/// The number of rolling backups to keep of each user data file.
+	#[serde(default = "defaultBackupCount")]
+	pub backupCount: u64,
+	
 	#[serde(default)]
 	pub defaultActivePlatform: ActiveContent,
 	
@@ -23,6 +32,18 @@ pub struct AppSettings
 	/// The duration in milliseconds for which a notification should remain on screen.
 	#[serde(default)]
 	pub notificationDuration: u64,
+	
+	/// The boundaries between the rarity tiers of achievements.
+	#[serde(default)]
+	pub rarity: RarityThresholds,
+	
+	/// The mechanism used to persist platform user data.
+	#[serde(default)]
+	pub storageBackend: StorageBackend,
+	
+	/// When each platform's data is refreshed automatically.
+	#[serde(default)]
+	pub sync: SyncSchedule,
 }
 
 impl Default for AppSettings
@@ -31,16 +52,27 @@ impl Default for AppSettings
 	{
 		return Self
 		{
+			backupCount: DefaultBackupCount,
 			defaultActivePlatform: Default::default(),
 			displayGamesWithoutAchievements: bool::default(),
 			enabledPlatforms: Default::default(),
 			language: Default::default(),
 			notificationDuration: DefaultNotificationDuration,
+			rarity: Default::default(),
+			storageBackend: Default::default(),
+			sync: Default::default(),
 		};
 	}
 }
 
+impl Versioned for AppSettings {}
+
 impl AppSettings
 {
 	pub const FileName: &str = "config.json";
 }
+
+fn defaultBackupCount() -> u64
+{
+	return DefaultBackupCount;
+}
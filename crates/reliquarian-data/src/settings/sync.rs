@@ -0,0 +1,183 @@
+use chrono::{DateTime, TimeDelta, Utc};
+use serde::{Deserialize, Serialize};
+use strum::IntoEnumIterator;
+use crate::enums::GamePlatforms;
+use super::platforms::EnabledPlatforms;
+
+/**
+When a platform's data should be refreshed automatically, along with when it
+was last refreshed successfully.
+*/
+#[derive(Clone, Copy, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct PlatformSync
+{
+	/// The number of hours between automatic refreshes. Zero disables the interval.
+	#[serde(default)]
+	pub intervalHours: u64,
+	
+	#[serde(default)]
+	pub lastSync: Option<DateTime<Utc>>,
+	
+	/// Refresh once each time the application starts, regardless of the interval.
+	#[serde(default)]
+	pub onStartup: bool,
+}
+
+impl PlatformSync
+{
+	/**
+	Whether an automatic refresh should be started at `now`.
+	
+	A platform with an interval which has never been synced is always due.
+	*/
+	pub fn isDue(&self, now: DateTime<Utc>, startup: bool) -> bool
+	{
+		if startup && self.onStartup
+		{
+			return true;
+		}
+		
+		return match (self.intervalHours, self.lastSync)
+		{
+			(0, _) => false,
+			(_, None) => true,
+			(hours, Some(last)) => i64::try_from(hours).ok()
+				.and_then(TimeDelta::try_hours)
+				.is_some_and(|interval| now - last >= interval),
+		};
+	}
+}
+
+/**
+The automatic refresh schedule of every platform.
+*/
+#[derive(Clone, Copy, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct SyncSchedule
+{
+	#[serde(default)]
+	pub battleNet: PlatformSync,
+	
+	#[serde(default)]
+	pub epicGamesStore: PlatformSync,
+	
+	#[serde(default)]
+	pub gog: PlatformSync,
+	
+	#[serde(default)]
+	pub retroAchievements: PlatformSync,
+	
+	#[serde(default)]
+	pub rpcs3: PlatformSync,
+	
+	#[serde(default)]
+	pub steam: PlatformSync,
+}
+
+impl SyncSchedule
+{
+	/**
+	The enabled platforms whose automatic refresh should be started at `now`.
+	
+	## Parameters
+	- now: `DateTime<Utc>` The current time.
+	- startup: `bool` Whether this is the first check since the application started.
+	- enabled: `&EnabledPlatforms` The platforms which are currently enabled.
+	*/
+	pub fn due(&self, now: DateTime<Utc>, startup: bool, enabled: &EnabledPlatforms) -> Vec<GamePlatforms>
+	{
+		return GamePlatforms::iter()
+			.filter(|platform| enabled.isEnabled((*platform).into()))
+			.filter(|platform| self.get(*platform).isDue(now, startup))
+			.collect();
+	}
+	
+	pub fn get(&self, platform: GamePlatforms) -> &PlatformSync
+	{
+		return match platform
+		{
+			GamePlatforms::BattleNet => &self.battleNet,
+			GamePlatforms::EpicGamesStore => &self.epicGamesStore,
+			GamePlatforms::Gog => &self.gog,
+			GamePlatforms::RetroAchievements => &self.retroAchievements,
+			GamePlatforms::Rpcs3 => &self.rpcs3,
+			GamePlatforms::Steam => &self.steam,
+		};
+	}
+	
+	pub fn getMut(&mut self, platform: GamePlatforms) -> &mut PlatformSync
+	{
+		return match platform
+		{
+			GamePlatforms::BattleNet => &mut self.battleNet,
+			GamePlatforms::EpicGamesStore => &mut self.epicGamesStore,
+			GamePlatforms::Gog => &mut self.gog,
+			GamePlatforms::RetroAchievements => &mut self.retroAchievements,
+			GamePlatforms::Rpcs3 => &mut self.rpcs3,
+			GamePlatforms::Steam => &mut self.steam,
+		};
+	}
+	
+	pub fn markSynced(&mut self, platform: GamePlatforms, timestamp: DateTime<Utc>)
+	{
+		self.getMut(platform).lastSync = Some(timestamp);
+	}
+}
+
+#[cfg(test)]
+mod tests
+{
+	use chrono::TimeZone;
+	use super::*;
+	
+	#[test]
+	fn isDue()
+	{
+		let now = Utc.with_ymd_and_hms(2025, 12, 7, 5, 25, 32).unwrap();
+		
+		let mut sync = PlatformSync::default();
+		assert!(!sync.isDue(now, true));
+		assert!(!sync.isDue(now, false));
+		
+		sync.onStartup = true;
+		assert!(sync.isDue(now, true));
+		assert!(!sync.isDue(now, false));
+		
+		sync.intervalHours = 6;
+		assert!(sync.isDue(now, false));
+		
+		sync.lastSync = Some(now - TimeDelta::hours(5));
+		assert!(!sync.isDue(now, false));
+		
+		sync.lastSync = Some(now - TimeDelta::hours(6));
+		assert!(sync.isDue(now, false));
+		
+		sync.intervalHours = u64::MAX;
+		assert!(!sync.isDue(now, false));
+	}
+	
+	#[test]
+	fn due()
+	{
+		let now = Utc.with_ymd_and_hms(2025, 12, 7, 5, 25, 32).unwrap();
+		
+		let mut schedule = SyncSchedule::default();
+		schedule.gog.onStartup = true;
+		schedule.steam.intervalHours = 1;
+		schedule.retroAchievements.intervalHours = 1;
+		
+		let enabled = EnabledPlatforms
+		{
+			gog: true,
+			steam: true,
+			..Default::default()
+		};
+		
+		assert_eq!(schedule.due(now, true, &enabled), vec![GamePlatforms::Gog, GamePlatforms::Steam]);
+		assert_eq!(schedule.due(now, false, &enabled), vec![GamePlatforms::Steam]);
+		
+		schedule.markSynced(GamePlatforms::Steam, now);
+		assert_eq!(schedule.steam.lastSync, Some(now));
+		assert!(schedule.due(now, false, &enabled).is_empty());
+		assert_eq!(schedule.due(now + TimeDelta::hours(1), false, &enabled), vec![GamePlatforms::Steam]);
+	}
+}
@@ -0,0 +1,33 @@
+use serde::{Deserialize, Serialize};
+use strum_macros::{Display, EnumIter, EnumString};
+
+/**
+The mechanism used to persist platform user data.
+*/
+#[derive(Clone, Copy, Default, Debug, Deserialize, Display, EnumIter, EnumString, Eq, Hash, PartialEq, PartialOrd, Serialize)]
+pub enum StorageBackend
+{
+	/// One JSON file per platform in the data directory.
+	#[default]
+	Json,
+	
+	/// A single SQLite database in the data directory.
+	///
+	/// Only available when built with the `sqlite` feature. Otherwise this
+	/// behaves identically to `Json`.
+	#[strum(to_string="SQLite")]
+	Sqlite,
+}
+
+impl StorageBackend
+{
+	/// Is this backend usable in the current build?
+	pub fn isAvailable(&self) -> bool
+	{
+		return match self
+		{
+			Self::Json => true,
+			Self::Sqlite => cfg!(feature = "sqlite"),
+		};
+	}
+}
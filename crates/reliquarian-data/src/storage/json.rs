@@ -0,0 +1,77 @@
+use std::path::Path;
+use anyhow::Result;
+use serde_json::Value;
+use tracing::warn;
+use crate::io::{backupDataFilePeriodically, readDataFromFile,
+	readRawDataFromFile, writeDataToFile};
+use crate::migration::Envelope;
+use super::storable::Storable;
+use super::storage::UserStorage;
+
+/**
+Stores each platform's user data as a single JSON file.
+
+Before a file is overwritten, a copy of it is added to the rolling backups in
+the same directory, at most once every few hours.
+*/
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct JsonStorage
+{
+	backups: usize,
+	directory: String,
+}
+
+impl UserStorage for JsonStorage
+{
+	fn contains<T: Storable>(&self) -> bool
+	{
+		return Path::new(&self.directory)
+			.join(T::DataFileName)
+			.exists();
+	}
+	
+	fn load<T: Storable>(&self) -> Result<T>
+	{
+		return match readDataFromFile(self.directory.clone(), T::DataFileName.into())
+		{
+			Ok(user) => Ok(user),
+			Err(e) => {
+				warn!("[Storage] Failed loading {} user data: {:?}", T::Platform.as_ref(), e);
+				warn!("[Storage] Attempting {} user data lossy load", T::Platform.as_ref());
+				let json = readRawDataFromFile(self.directory.clone(), T::DataFileName.into())?;
+				let data = Envelope::fromValue(serde_json::from_str::<Value>(&json)?)
+					.upgrade::<T>()?;
+				T::parseLossy(data.to_string())
+			},
+		};
+	}
+	
+	fn save<T: Storable>(&self, user: &T) -> Result<()>
+	{
+		if let Err(e) = backupDataFilePeriodically(self.directory.clone(), T::DataFileName.into(), self.backups)
+		{
+			warn!("[Storage] Failed backing up {} user data: {:?}", T::Platform.as_ref(), e);
+		}
+		
+		return writeDataToFile(self.directory.clone(), T::DataFileName.into(), user);
+	}
+}
+
+impl JsonStorage
+{
+	pub fn new(directory: impl Into<String>) -> Self
+	{
+		return Self
+		{
+			backups: 0,
+			directory: directory.into(),
+		};
+	}
+	
+	/// Set the number of rolling backups to keep of each file.
+	pub fn backups(mut self, count: usize) -> Self
+	{
+		self.backups = count;
+		return self;
+	}
+}
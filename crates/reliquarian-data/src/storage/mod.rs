@@ -0,0 +1,14 @@
+mod backend;
+mod json;
+#[cfg(feature = "sqlite")]
+mod sqlite;
+mod storable;
+mod storage;
+
+pub use backend::StorageBackend;
+pub use json::JsonStorage;
+#[cfg(feature = "sqlite")]
+pub use sqlite::SqliteStorage;
+pub use storable::Storable;
+pub use storage::{UserStorage, loadUser, migrateUser, reimportUser,
+	replaceUser, saveUser, selectedBackend};
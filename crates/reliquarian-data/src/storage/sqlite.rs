@@ -0,0 +1,551 @@
+use std::path::Path;
+use std::sync::Mutex;
+use anyhow::{Result, anyhow};
+use rusqlite::{Connection, OptionalExtension, params};
+use serde_json::Value;
+use strum::IntoEnumIterator;
+use crate::enums::GamePlatforms;
+use crate::migration::Envelope;
+use crate::unified::UnifiedGame;
+use super::storable::Storable;
+use super::storage::UserStorage;
+
+const Schema: &str = "
+CREATE TABLE IF NOT EXISTS profiles (
+	platform TEXT NOT NULL PRIMARY KEY,
+	version INTEGER NOT NULL,
+	data TEXT NOT NULL
+);
+
+CREATE TABLE IF NOT EXISTS games (
+	platform TEXT NOT NULL,
+	id TEXT NOT NULL,
+	position INTEGER NOT NULL,
+	name TEXT NOT NULL,
+	data TEXT NOT NULL,
+	PRIMARY KEY (platform, id)
+);
+
+CREATE TABLE IF NOT EXISTS achievements (
+	platform TEXT NOT NULL,
+	gameId TEXT NOT NULL,
+	id TEXT NOT NULL,
+	name TEXT NOT NULL,
+	description TEXT NOT NULL,
+	unlocked INTEGER NOT NULL,
+	PRIMARY KEY (platform, gameId, id)
+);
+
+CREATE TABLE IF NOT EXISTS unlocks (
+	platform TEXT NOT NULL,
+	gameId TEXT NOT NULL,
+	achievementId TEXT NOT NULL,
+	timestamp INTEGER NOT NULL,
+	PRIMARY KEY (platform, gameId, achievementId)
+);
+
+CREATE INDEX IF NOT EXISTS unlocks_timestamp ON unlocks (timestamp);
+
+CREATE TEMP TABLE IF NOT EXISTS savedAchievements (
+	gameId TEXT NOT NULL,
+	id TEXT NOT NULL,
+	PRIMARY KEY (gameId, id)
+);
+";
+
+/**
+The `user_version` of a database whose rows are keyed by the stable platform
+identifier rather than the display name.
+*/
+const SchemaVersion: i64 = 1;
+
+/**
+Stores every platform's user data in a single SQLite database.
+
+The platform-specific data is split into a `profiles` row, holding the format
+version and everything except the list of games, and one `games` row per game.
+Only the games which have changed are rewritten when saving.
+
+The `achievements` and `unlocks` tables are derived from the unified model. As
+with the games, only the rows which have changed are rewritten when saving.
+They exist for querying purposes only and are never read back when loading.
+*/
+pub struct SqliteStorage
+{
+	connection: Mutex<Connection>,
+}
+
+impl UserStorage for SqliteStorage
+{
+	fn contains<T: Storable>(&self) -> bool
+	{
+		return match self.connection.lock()
+		{
+			Err(_) => false,
+			Ok(connection) => connection.query_row(
+				"SELECT 1 FROM profiles WHERE platform = ?1",
+				params![T::Platform.identifier()],
+				|_| Ok(())
+			)
+				.optional()
+				.is_ok_and(|row| row.is_some()),
+		};
+	}
+	
+	fn load<T: Storable>(&self) -> Result<T>
+	{
+		let connection = self.connection.lock()
+			.map_err(|e| anyhow!("{}", e))?;
+		
+		let (profile, version): (String, u32) = connection.query_row(
+			"SELECT data, version FROM profiles WHERE platform = ?1",
+			params![T::Platform.identifier()],
+			|row| Ok((row.get(0)?, row.get(1)?))
+		)?;
+		
+		let mut value: Value = serde_json::from_str(&profile)?;
+		
+		if let Some(key) = T::GamesKey
+		{
+			let mut statement = connection.prepare(
+				"SELECT data FROM games WHERE platform = ?1 ORDER BY position"
+			)?;
+			
+			let games = statement.query_map(
+				params![T::Platform.identifier()],
+				|row| row.get::<_, String>(0)
+			)?
+				.filter_map(|data| data.ok())
+				.filter_map(|data| serde_json::from_str::<Value>(&data).ok())
+				.collect::<Vec<_>>();
+			
+			if let Value::Object(map) = &mut value
+			{
+				map.insert(key.into(), Value::Array(games));
+			}
+		}
+		
+		let value = Envelope { data: value, version }
+			.upgrade::<T>()?;
+		
+		return match serde_json::from_value(value.clone())
+		{
+			Ok(user) => Ok(user),
+			Err(_) => T::parseLossy(value.to_string()),
+		};
+	}
+	
+	fn save<T: Storable>(&self, user: &T) -> Result<()>
+	{
+		let platform = T::Platform.identifier();
+		let mut value = serde_json::to_value(user)?;
+		
+		let games = match (T::GamesKey, &mut value)
+		{
+			(Some(key), Value::Object(map)) => match map.insert(key.into(), Value::Array(vec![]))
+			{
+				Some(Value::Array(games)) => games,
+				_ => vec![],
+			},
+			
+			_ => vec![],
+		};
+		
+		let mut connection = self.connection.lock()
+			.map_err(|e| anyhow!("{}", e))?;
+		
+		let transaction = connection.transaction()?;
+		
+		transaction.execute(
+			"INSERT INTO profiles (platform, version, data) VALUES (?1, ?2, ?3)
+				ON CONFLICT (platform) DO UPDATE SET version = excluded.version, data = excluded.data",
+			params![platform, T::Version, value.to_string()]
+		)?;
+		
+		let mut ids = vec![];
+		for (position, game) in games.iter().enumerate()
+		{
+			let id = match game.get(T::GameIdKey)
+			{
+				Some(Value::String(id)) => id.clone(),
+				Some(Value::Number(id)) => id.to_string(),
+				_ => continue,
+			};
+			
+			let name = match game.get("name")
+			{
+				Some(Value::String(name)) => name.clone(),
+				_ => String::default(),
+			};
+			
+			transaction.execute(
+				"INSERT INTO games (platform, id, position, name, data) VALUES (?1, ?2, ?3, ?4, ?5)
+					ON CONFLICT (platform, id) DO UPDATE SET
+						position = excluded.position,
+						name = excluded.name,
+						data = excluded.data
+					WHERE games.data != excluded.data OR games.position != excluded.position",
+				params![platform, id, position as i64, name, game.to_string()]
+			)?;
+			
+			ids.push(Value::String(id));
+		}
+		
+		transaction.execute(
+			"DELETE FROM games WHERE platform = ?1 AND id NOT IN (SELECT value FROM json_each(?2))",
+			params![platform, Value::Array(ids).to_string()]
+		)?;
+		
+		Self::updateAchievements(&transaction, platform, &user.unify())?;
+		
+		transaction.commit()?;
+		
+		return Ok(());
+	}
+}
+
+impl SqliteStorage
+{
+	pub const FileName: &str = "reliquarian.db";
+	
+	/**
+	Open, or create, the database at `path` and ensure the schema exists.
+	*/
+	pub fn open(path: impl AsRef<Path>) -> Result<Self>
+	{
+		return Self::initialize(Connection::open(path)?);
+	}
+	
+	/**
+	Open a new, empty database which exists only in memory.
+	*/
+	pub fn openInMemory() -> Result<Self>
+	{
+		return Self::initialize(Connection::open_in_memory()?);
+	}
+	
+	fn initialize(connection: Connection) -> Result<Self>
+	{
+		connection.execute_batch(Schema)?;
+		Self::migrateSchema(&connection)?;
+		
+		return Ok(Self
+		{
+			connection: Mutex::new(connection),
+		});
+	}
+	
+	/**
+	Databases written before the schema was versioned keyed every row by the
+	platform's display name. Re-key those rows by the platform's identifier, so
+	that renaming a platform for display does not orphan its data.
+	*/
+	fn migrateSchema(connection: &Connection) -> Result<()>
+	{
+		let version: i64 = connection.query_row(
+			"PRAGMA user_version",
+			params![],
+			|row| row.get(0)
+		)?;
+		
+		if version < SchemaVersion
+		{
+			for platform in GamePlatforms::iter()
+			{
+				for table in ["profiles", "games", "achievements", "unlocks"]
+				{
+					connection.execute(
+						&format!("UPDATE {} SET platform = ?1 WHERE platform = ?2", table),
+						params![platform.identifier(), platform.as_ref()]
+					)?;
+				}
+			}
+			
+			connection.execute_batch(&format!("PRAGMA user_version = {}", SchemaVersion))?;
+		}
+		
+		return Ok(());
+	}
+	
+	/**
+	Bring the `achievements` and `unlocks` tables in line with `games`, writing
+	only the rows which have been added, changed or removed.
+	*/
+	fn updateAchievements(connection: &Connection, platform: &str, games: &[UnifiedGame]) -> Result<()>
+	{
+		connection.execute("DELETE FROM savedAchievements", params![])?;
+		
+		let mut insertSaved = connection.prepare(
+			"INSERT OR IGNORE INTO savedAchievements (gameId, id) VALUES (?1, ?2)"
+		)?;
+		
+		let mut upsertAchievement = connection.prepare(
+			"INSERT INTO achievements (platform, gameId, id, name, description, unlocked)
+				VALUES (?1, ?2, ?3, ?4, ?5, ?6)
+				ON CONFLICT (platform, gameId, id) DO UPDATE SET
+					name = excluded.name,
+					description = excluded.description,
+					unlocked = excluded.unlocked
+				WHERE achievements.name != excluded.name
+					OR achievements.description != excluded.description
+					OR achievements.unlocked != excluded.unlocked"
+		)?;
+		
+		let mut upsertUnlock = connection.prepare(
+			"INSERT INTO unlocks (platform, gameId, achievementId, timestamp)
+				VALUES (?1, ?2, ?3, ?4)
+				ON CONFLICT (platform, gameId, achievementId) DO UPDATE SET
+					timestamp = excluded.timestamp
+				WHERE unlocks.timestamp != excluded.timestamp"
+		)?;
+		
+		let mut deleteUnlock = connection.prepare(
+			"DELETE FROM unlocks WHERE platform = ?1 AND gameId = ?2 AND achievementId = ?3"
+		)?;
+		
+		for game in games
+		{
+			for achievement in &game.achievements
+			{
+				insertSaved.execute(params![game.id, achievement.id])?;
+				
+				upsertAchievement.execute(params![
+					platform,
+					game.id,
+					achievement.id,
+					achievement.name,
+					achievement.description,
+					achievement.unlocked
+				])?;
+				
+				match achievement.unlockedTimestamp
+				{
+					None => deleteUnlock.execute(params![platform, game.id, achievement.id])?,
+					
+					Some(timestamp) => upsertUnlock.execute(params![
+						platform,
+						game.id,
+						achievement.id,
+						timestamp.timestamp()
+					])?,
+				};
+			}
+		}
+		
+		// Remove the rows of achievements which no longer exist
+		connection.execute(
+			"DELETE FROM achievements WHERE platform = ?1 AND NOT EXISTS (
+				SELECT 1 FROM savedAchievements s
+					WHERE s.gameId = achievements.gameId AND s.id = achievements.id
+			)",
+			params![platform]
+		)?;
+		
+		connection.execute(
+			"DELETE FROM unlocks WHERE platform = ?1 AND NOT EXISTS (
+				SELECT 1 FROM savedAchievements s
+					WHERE s.gameId = unlocks.gameId AND s.id = unlocks.achievementId
+			)",
+			params![platform]
+		)?;
+		
+		return Ok(());
+	}
+	
+	/**
+	Count the unlocks, across every platform, which occurred at or after the
+	given unix `timestamp` in seconds.
+	*/
+	pub fn countUnlocksSince(&self, timestamp: i64) -> Result<u64>
+	{
+		let connection = self.connection.lock()
+			.map_err(|e| anyhow!("{}", e))?;
+		
+		let count: i64 = connection.query_row(
+			"SELECT COUNT(*) FROM unlocks WHERE timestamp >= ?1",
+			params![timestamp],
+			|row| row.get(0)
+		)?;
+		
+		return Ok(count as u64);
+	}
+}
+
+#[cfg(test)]
+mod tests
+{
+	use std::env::temp_dir;
+	use std::fs::{create_dir_all, remove_dir_all};
+	use chrono::DateTime;
+	use serde::{Deserialize, Serialize};
+	use crate::io::{backupDataFile, restoreBackup};
+	use crate::migration::Versioned;
+	use crate::storage::JsonStorage;
+	use crate::unified::{Unifiable, UnifiedAchievement};
+	use super::*;
+	
+	#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+	struct TestGame
+	{
+		id: u64,
+		name: String,
+		unlocked: Option<i64>,
+	}
+	
+	#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+	struct TestUser
+	{
+		games: Vec<TestGame>,
+		name: String,
+	}
+	
+	impl Storable for TestUser
+	{
+		const DataFileName: &'static str = "test.json";
+		const Platform: GamePlatforms = GamePlatforms::RetroAchievements;
+		
+		fn parseLossy(json: String) -> Result<Self>
+		{
+			return Ok(serde_json::from_str(&json)?);
+		}
+	}
+	
+	impl Unifiable<Vec<UnifiedGame>> for TestUser
+	{
+		fn unify(&self) -> Vec<UnifiedGame>
+		{
+			return self.games.iter()
+				.map(|g| {
+					let mut achievement = UnifiedAchievement::new(GamePlatforms::Steam, "a");
+					achievement.unlocked = g.unlocked.is_some();
+					achievement.unlockedTimestamp = g.unlocked.and_then(|ts| DateTime::from_timestamp(ts, 0));
+					UnifiedGame::new(GamePlatforms::Steam, g.id.to_string(), g.name.clone(), vec![achievement])
+				})
+				.collect();
+		}
+	}
+	
+	impl Versioned for TestUser {}
+	
+	fn buildUser() -> TestUser
+	{
+		return TestUser
+		{
+			games: vec![
+				TestGame { id: 2, name: "Second".into(), unlocked: Some(1728611918) },
+				TestGame { id: 1, name: "First".into(), unlocked: None },
+			],
+			name: "The name".into(),
+		};
+	}
+	
+	#[test]
+	fn saveAndLoad()
+	{
+		let storage = SqliteStorage::openInMemory().unwrap();
+		assert!(!storage.contains::<TestUser>());
+		assert!(storage.load::<TestUser>().is_err());
+		
+		let mut user = buildUser();
+		storage.save(&user).unwrap();
+		assert!(storage.contains::<TestUser>());
+		assert_eq!(storage.load::<TestUser>().unwrap(), user);
+		assert_eq!(storage.countUnlocksSince(0).unwrap(), 1);
+		
+		user.games.remove(0);
+		user.games[0].unlocked = Some(1765083932);
+		user.games.push(TestGame { id: 3, name: "Third".into(), unlocked: None });
+		storage.save(&user).unwrap();
+		assert_eq!(storage.load::<TestUser>().unwrap(), user);
+		assert_eq!(storage.countUnlocksSince(0).unwrap(), 1);
+		assert_eq!(storage.countUnlocksSince(1765083933).unwrap(), 0);
+		
+		user.games[0].unlocked = None;
+		storage.save(&user).unwrap();
+		assert_eq!(storage.countUnlocksSince(0).unwrap(), 0);
+	}
+	
+	#[test]
+	fn migrate()
+	{
+		let source = SqliteStorage::openInMemory().unwrap();
+		let destination = SqliteStorage::openInMemory().unwrap();
+		
+		assert!(!crate::storage::migrateUser::<TestUser>(&source, &destination).unwrap());
+		
+		let user = buildUser();
+		source.save(&user).unwrap();
+		assert!(crate::storage::migrateUser::<TestUser>(&source, &destination).unwrap());
+		assert_eq!(destination.load::<TestUser>().unwrap(), user);
+		
+		// Existing data is never overwritten
+		source.save(&TestUser::default()).unwrap();
+		assert!(!crate::storage::migrateUser::<TestUser>(&source, &destination).unwrap());
+		assert_eq!(destination.load::<TestUser>().unwrap(), user);
+	}
+	
+	#[test]
+	fn restoreThenLoad()
+	{
+		let directory = temp_dir()
+			.join(format!("reliquarian-sqlite-restore-{}", std::process::id()));
+		
+		_ = remove_dir_all(&directory);
+		create_dir_all(&directory).unwrap();
+		let directory = directory.to_string_lossy().to_string();
+		
+		let json = JsonStorage::new(directory.clone());
+		let sqlite = SqliteStorage::openInMemory().unwrap();
+		
+		let backedUp = buildUser();
+		json.save(&backedUp).unwrap();
+		let backup = backupDataFile(directory.clone(), TestUser::DataFileName.into(), 2)
+			.unwrap()
+			.unwrap();
+		
+		assert!(crate::storage::migrateUser::<TestUser>(&json, &sqlite).unwrap());
+		
+		let latest = TestUser { name: "Latest".into(), ..Default::default() };
+		sqlite.save(&latest).unwrap();
+		
+		restoreBackup(directory.clone(), &backup, 2).unwrap();
+		
+		// Migration never overwrites the database, so the restored file is ignored
+		assert!(!crate::storage::migrateUser::<TestUser>(&json, &sqlite).unwrap());
+		assert_eq!(sqlite.load::<TestUser>().unwrap(), latest);
+		
+		assert!(crate::storage::replaceUser::<TestUser>(&json, &sqlite).unwrap());
+		assert_eq!(sqlite.load::<TestUser>().unwrap(), backedUp);
+		
+		_ = remove_dir_all(&directory);
+	}
+	
+	#[test]
+	fn migrateSchema()
+	{
+		let storage = SqliteStorage::openInMemory().unwrap();
+		let user = buildUser();
+		storage.save(&user).unwrap();
+		
+		// Restore the display name keys written before the schema was versioned
+		{
+			let connection = storage.connection.lock().unwrap();
+			for table in ["profiles", "games", "achievements", "unlocks"]
+			{
+				connection.execute(
+					&format!("UPDATE {} SET platform = ?1", table),
+					params![GamePlatforms::RetroAchievements.as_ref()]
+				).unwrap();
+			}
+			
+			connection.execute_batch("PRAGMA user_version = 0").unwrap();
+		}
+		
+		assert!(!storage.contains::<TestUser>());
+		
+		SqliteStorage::migrateSchema(&storage.connection.lock().unwrap()).unwrap();
+		assert!(storage.contains::<TestUser>());
+		assert_eq!(storage.load::<TestUser>().unwrap(), user);
+		assert_eq!(storage.countUnlocksSince(0).unwrap(), 1);
+	}
+}
@@ -0,0 +1,40 @@
+use anyhow::Result;
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+use crate::enums::GamePlatforms;
+use crate::migration::Versioned;
+use crate::unified::{Unifiable, UnifiedGame};
+
+/**
+A platform's user data which can be persisted by any `UserStorage`.
+*/
+pub trait Storable: Default + DeserializeOwned + Serialize + Unifiable<Vec<UnifiedGame>> + Versioned
+{
+	/// The file name, with extension, used when storing the data as JSON.
+	const DataFileName: &'static str;
+	
+	/**
+	The key of the property, within the serialized game data, which uniquely
+	identifies each game.
+	*/
+	const GameIdKey: &'static str = "id";
+	
+	/**
+	The key of the property, within the serialized user data, which contains
+	the list of games.
+	
+	Storage backends which support it will store each game individually, so
+	that only the games which have changed need to be rewritten.
+	*/
+	const GamesKey: Option<&'static str> = Some("games");
+	
+	const Platform: GamePlatforms;
+	
+	/**
+	Parse the serialized user data while discarding any parts which fail to
+	parse, rather than failing entirely.
+	
+	The data has already been upgraded to the current version of `Self`.
+	*/
+	fn parseLossy(json: String) -> Result<Self>;
+}
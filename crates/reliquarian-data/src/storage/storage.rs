@@ -0,0 +1,199 @@
+use std::io::ErrorKind;
+use anyhow::{Result, anyhow};
+use tracing::warn;
+use crate::io::{getDataDir, loadAppSettings};
+use super::backend::StorageBackend;
+use super::json::JsonStorage;
+use super::storable::Storable;
+
+#[cfg(feature = "sqlite")]
+use std::path::Path;
+#[cfg(feature = "sqlite")]
+use tracing::info;
+#[cfg(feature = "sqlite")]
+use crate::io::backupDataFilePeriodically;
+#[cfg(feature = "sqlite")]
+use super::sqlite::SqliteStorage;
+
+/**
+A mechanism for persisting and retrieving platform user data.
+*/
+pub trait UserStorage
+{
+	/// Does this storage currently hold data for the platform of `T`?
+	fn contains<T: Storable>(&self) -> bool;
+	
+	fn load<T: Storable>(&self) -> Result<T>;
+	
+	fn save<T: Storable>(&self, user: &T) -> Result<()>;
+}
+
+/**
+Read the user data for the platform of `T` using the storage backend selected
+in the application's settings.
+
+When the SQLite backend is selected but has no data for the platform yet, the
+existing JSON file is migrated into the database first. If the database cannot
+be used for any reason, the JSON file is read instead.
+*/
+pub fn loadUser<T: Storable>() -> Result<T>
+{
+	let directory = getDataDir(false)
+		.ok_or(anyhow!(ErrorKind::NotFound))?;
+	
+	let json = JsonStorage::new(directory.clone());
+	
+	#[cfg(feature = "sqlite")]
+	if selectedBackend() == StorageBackend::Sqlite
+	{
+		match openSqlite(&directory)
+		{
+			Err(e) => warn!("[Storage] Error opening the SQLite database, falling back to JSON: {:?}", e),
+			
+			Ok(sqlite) => {
+				match migrateUser::<T>(&json, &sqlite)
+				{
+					Err(e) => warn!("[Storage] Error migrating {} user data to SQLite: {:?}", T::Platform.as_ref(), e),
+					Ok(true) => info!("[Storage] Migrated {} user data to SQLite", T::Platform.as_ref()),
+					Ok(false) => {},
+				}
+				
+				match sqlite.load()
+				{
+					Err(e) => warn!("[Storage] Error loading {} user data from SQLite, falling back to JSON: {:?}", T::Platform.as_ref(), e),
+					Ok(user) => return Ok(user),
+				}
+			},
+		}
+	}
+	
+	return json.load();
+}
+
+/**
+Copy the user data for the platform of `T` from `source` into `destination`,
+if `destination` does not already contain it.
+
+Returns `true` if any data was copied.
+*/
+pub fn migrateUser<T: Storable>(source: &impl UserStorage, destination: &impl UserStorage) -> Result<bool>
+{
+	if destination.contains::<T>()
+	{
+		return Ok(false);
+	}
+	
+	return replaceUser::<T>(source, destination);
+}
+
+/**
+Copy the user data for the platform of `T` from `source` into `destination`,
+overwriting any data `destination` already contains.
+
+Returns `true` if any data was copied.
+*/
+pub fn replaceUser<T: Storable>(source: &impl UserStorage, destination: &impl UserStorage) -> Result<bool>
+{
+	if !source.contains::<T>()
+	{
+		return Ok(false);
+	}
+	
+	let user = source.load::<T>()?;
+	destination.save(&user)?;
+	return Ok(true);
+}
+
+/**
+Import the JSON file of the platform of `T` into the storage backend selected
+in the application's settings, if `fileName` is that file.
+
+`loadUser` only migrates the JSON file into the SQLite database while the
+database has no data for the platform. When a JSON backup is restored, the
+restored file must be imported explicitly or it will never be read.
+
+Returns `true` if any data was imported.
+*/
+pub fn reimportUser<T: Storable>(fileName: &str) -> Result<bool>
+{
+	if fileName != T::DataFileName
+	{
+		return Ok(false);
+	}
+	
+	#[cfg(feature = "sqlite")]
+	if selectedBackend() == StorageBackend::Sqlite
+	{
+		let directory = getDataDir(false)
+			.ok_or(anyhow!(ErrorKind::NotFound))?;
+		
+		let json = JsonStorage::new(directory.clone());
+		return replaceUser::<T>(&json, &openSqlite(&directory)?);
+	}
+	
+	return Ok(false);
+}
+
+/**
+Write the user data for the platform of `T` using the storage backend selected
+in the application's settings.
+
+The existing file is added to the rolling backups before it is replaced, unless
+it was already backed up within the last few hours.
+
+Errors saving to the SQLite database are returned rather than writing the JSON
+file instead, as `loadUser` prefers the database and would never read the JSON
+copy back.
+*/
+pub fn saveUser<T: Storable>(user: &T) -> Result<()>
+{
+	let directory = getDataDir(true)
+		.ok_or(anyhow!(ErrorKind::NotFound))?;
+	
+	let backups = loadAppSettings()
+		.unwrap_or_default()
+		.backupCount as usize;
+	
+	#[cfg(feature = "sqlite")]
+	if selectedBackend() == StorageBackend::Sqlite
+	{
+		if let Err(e) = backupDataFilePeriodically(directory.clone(), SqliteStorage::FileName.into(), backups)
+		{
+			warn!("[Storage] Failed backing up the SQLite database: {:?}", e);
+		}
+		
+		return openSqlite(&directory)
+			.and_then(|sqlite| sqlite.save(user));
+	}
+	
+	return JsonStorage::new(directory)
+		.backups(backups)
+		.save(user);
+}
+
+/**
+The storage backend selected in the application's settings, if it is available
+in the current build.
+*/
+pub fn selectedBackend() -> StorageBackend
+{
+	let backend = loadAppSettings()
+		.map(|settings| settings.storageBackend)
+		.unwrap_or_default();
+	
+	return match backend.isAvailable()
+	{
+		false => {
+			warn!("[Storage] The {} storage backend is not available in this build", backend);
+			StorageBackend::Json
+		},
+		
+		true => backend,
+	};
+}
+
+#[cfg(feature = "sqlite")]
+fn openSqlite(directory: &String) -> Result<SqliteStorage>
+{
+	return SqliteStorage::open(Path::new(directory).join(SqliteStorage::FileName));
+}
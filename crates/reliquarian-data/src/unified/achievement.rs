@@ -0,0 +1,65 @@
+use std::io::ErrorKind;
+use anyhow::{Result, anyhow};
+use chrono::{DateTime, Utc};
+use crate::constants::Format_ChronoDateTime;
+use crate::enums::GamePlatforms;
+use super::extension::UnifiedExtension;
+
+/**
+A platform-neutral representation of a single achievement, trophy, or similar
+unlockable.
+*/
+#[derive(Clone, Debug, PartialEq)]
+pub struct UnifiedAchievement
+{
+	pub description: String,
+	
+	/// Platform-specific data which has no platform-neutral equivalent.
+	pub extension: UnifiedExtension,
+	
+	pub hidden: bool,
+	
+	/// The platform-specific id, stringified.
+	pub id: String,
+	
+	pub name: String,
+	
+	/// The platform from which this achievement originated.
+	pub platform: GamePlatforms,
+	
+	/// The percentage of players who have unlocked the achievement, if known.
+	pub rarity: Option<f64>,
+	
+	pub unlocked: bool,
+	
+	/// The moment when the user unlocked the achievement, if known.
+	pub unlockedTimestamp: Option<DateTime<Utc>>,
+}
+
+impl UnifiedAchievement
+{
+	pub fn formatUnlockedTimestamp(&self) -> Result<String>
+	{
+		return match self.unlockedTimestamp
+		{
+			None => Err(anyhow!(ErrorKind::NotFound)),
+			Some(dt) => Ok(dt.format(Format_ChronoDateTime).to_string()),
+		};
+	}
+	
+	pub fn new(platform: GamePlatforms, id: impl Into<String>) -> Self
+	{
+		return Self
+		{
+			description: Default::default(),
+			extension: Default::default(),
+			hidden: false,
+			id: id.into(),
+			name: Default::default(),
+			platform,
+			rarity: None,
+			unlocked: false,
+			unlockedTimestamp: None,
+		};
+	}
+}
@@ -0,0 +1,66 @@
+use strum_macros::Display;
+
+/**
+Platform-specific achievement data which has no platform-neutral equivalent.
+*/
+#[derive(Clone, Debug, Default, PartialEq)]
+pub enum UnifiedExtension
+{
+	EpicGamesStore(EgsExtension),
+	#[default]
+	None,
+	Psn(PsnExtension),
+	RetroAchievements(RetroAchievementsExtension),
+	StarCraft2(Sc2Extension),
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct EgsExtension
+{
+	/// The hex color code associated with the achievement's tier.
+	pub tierColor: String,
+	
+	/// The display name of the achievement's tier.
+	pub tierName: String,
+	
+	/// The amount of XP awarded when unlocking the achievement.
+	pub xp: u64,
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct PsnExtension
+{
+	/// The grade of the trophy.
+	pub grade: PsnGrade,
+	
+	/// Whether or not the trophy counts towards unlocking the Platinum trophy.
+	pub platinumRelevance: bool,
+}
+
+#[derive(Clone, Copy, Debug, Default, Display, Eq, Hash, PartialEq, PartialOrd, Ord)]
+pub enum PsnGrade
+{
+	#[default]
+	Unknown,
+	Platinum,
+	Gold,
+	Silver,
+	Bronze,
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct RetroAchievementsExtension
+{
+	/// Whether or not the achievement has been unlocked in Hardcore mode.
+	pub hardcore: bool,
+	
+	/// The amount of points gained when unlocking the achievement.
+	pub points: u64,
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Sc2Extension
+{
+	/// The amount of achievement points gained when unlocking the achievement.
+	pub points: u64,
+}
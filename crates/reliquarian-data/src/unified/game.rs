@@ -0,0 +1,184 @@
+use std::cmp::Ordering;
+use std::io::ErrorKind;
+use anyhow::{Result, anyhow};
+use chrono::{DateTime, Utc};
+use crate::constants::{Format_ChronoDateTime, TheString};
+use crate::enums::GamePlatforms;
+use crate::format::truncateF32;
+use crate::io::FileLocation;
+use super::achievement::UnifiedAchievement;
+
+/**
+A platform-neutral representation of a game and its achievements.
+
+The achievement counts are stored separately from the list of achievements
+because some platforms report them before the achievements themselves have
+been retrieved.
+*/
+#[derive(Clone, Debug, PartialEq)]
+pub struct UnifiedGame
+{
+	pub achievements: Vec<UnifiedAchievement>,
+	
+	/// The total number of achievements available for the game.
+	pub achievementsTotal: u64,
+	
+	/// The number of achievements the user has unlocked.
+	pub achievementsUnlocked: u64,
+	
+	/// The location of the game's cached icon image, if the platform provides one.
+	pub icon: Option<FileLocation>,
+	
+	/// The platform-specific id, stringified.
+	pub id: String,
+	
+	/// The moment when the user most recently unlocked an achievement, if known.
+	pub lastUnlocked: Option<DateTime<Utc>>,
+	
+	pub name: String,
+	
+	/// The platform from which this game originated.
+	pub platform: GamePlatforms,
+	
+	/// The system on which the game is played, for platforms which span multiple systems.
+	pub system: Option<String>,
+}
+
+impl PartialOrd for UnifiedGame
+{
+	fn partial_cmp(&self, other: &Self) -> Option<Ordering>
+	{
+		return match self.sortName().to_lowercase().partial_cmp(&other.sortName().to_lowercase())
+		{
+			None => self.platform.partial_cmp(&other.platform),
+			
+			Some(o) => match o
+			{
+				Ordering::Equal => self.platform.partial_cmp(&other.platform),
+				_ => Some(o),
+			},
+		};
+	}
+}
+
+impl UnifiedGame
+{
+	pub fn formatLastUnlocked(&self) -> Result<String>
+	{
+		return match self.lastUnlocked
+		{
+			None => Err(anyhow!(ErrorKind::NotFound)),
+			Some(dt) => Ok(dt.format(Format_ChronoDateTime).to_string()),
+		};
+	}
+	
+	/**
+	Create a new instance, deriving the achievement counts and the most recent
+	unlock from the given list of `achievements`.
+	*/
+	pub fn new(
+		platform: GamePlatforms,
+		id: impl Into<String>,
+		name: impl Into<String>,
+		achievements: Vec<UnifiedAchievement>
+	) -> Self
+	{
+		let achievementsUnlocked = achievements.iter()
+			.filter(|a| a.unlocked)
+			.count() as u64;
+		
+		let lastUnlocked = achievements.iter()
+			.filter_map(|a| a.unlockedTimestamp)
+			.max();
+		
+		return Self
+		{
+			achievementsTotal: achievements.len() as u64,
+			achievementsUnlocked,
+			achievements,
+			icon: None,
+			id: id.into(),
+			lastUnlocked,
+			name: name.into(),
+			platform,
+			system: None,
+		};
+	}
+	
+	pub fn percentUnlocked(&self) -> f32
+	{
+		return truncateF32(
+			match self.achievementsTotal > 0
+			{
+				false => 0.0,
+				true => (self.achievementsUnlocked as f32
+						/ self.achievementsTotal as f32)
+					* 100.0
+			},
+			2
+		);
+	}
+	
+	pub fn sortName(&self) -> String
+	{
+		return match self.name.starts_with(TheString)
+		{
+			true => {
+				let mut the = self.name.clone();
+				let name = the.split_off(TheString.len());
+				format!("{}, {}", name, the.trim())
+			},
+			
+			false => self.name.clone(),
+		};
+	}
+}
+
+#[cfg(test)]
+mod tests
+{
+	use super::*;
+	
+	fn buildAchievement(id: u64, timestamp: Option<i64>) -> UnifiedAchievement
+	{
+		let mut achievement = UnifiedAchievement::new(GamePlatforms::Steam, id.to_string());
+		achievement.unlocked = timestamp.is_some();
+		achievement.unlockedTimestamp = timestamp.and_then(|ts| DateTime::from_timestamp(ts, 0));
+		return achievement;
+	}
+	
+	#[test]
+	fn new()
+	{
+		let game = UnifiedGame::new(
+			GamePlatforms::Steam,
+			"12",
+			"The name",
+			vec![
+				buildAchievement(1, Some(1728611918)),
+				buildAchievement(2, None),
+				buildAchievement(3, Some(1765083932)),
+				buildAchievement(4, None),
+			]
+		);
+		
+		assert_eq!(game.achievements.len(), 4);
+		assert_eq!(game.achievementsTotal, 4);
+		assert_eq!(game.achievementsUnlocked, 2);
+		assert_eq!(&game.id, "12");
+		assert_eq!(game.lastUnlocked, DateTime::from_timestamp(1765083932, 0));
+		assert_eq!(&game.name, "The name");
+		assert_eq!(game.percentUnlocked(), 50.0);
+	}
+	
+	#[test]
+	fn percentUnlocked()
+	{
+		let mut game = UnifiedGame::new(GamePlatforms::Gog, "1", "The name", vec![]);
+		assert_eq!(game.percentUnlocked(), 0.0);
+		
+		game.achievementsTotal = 3;
+		game.achievementsUnlocked = 1;
+		assert_eq!(game.percentUnlocked(), 33.33);
+	}
+}
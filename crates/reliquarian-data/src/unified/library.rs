@@ -0,0 +1,195 @@
+use std::cmp::Ordering;
+use strum_macros::{Display, EnumIter};
+use crate::enums::GamePlatforms;
+use crate::filter::{FilterCriteria, Filterable};
+use super::game::UnifiedGame;
+
+/**
+The games from every platform, collected into a single list.
+*/
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct UnifiedLibrary
+{
+	pub games: Vec<UnifiedGame>,
+}
+
+/**
+The orderings available when displaying a `UnifiedLibrary`.
+*/
+#[derive(Clone, Copy, Debug, Default, Display, EnumIter, Eq, Hash, PartialEq)]
+pub enum UnifiedSort
+{
+	#[strum(to_string="Completion")]
+	Completion,
+	#[strum(to_string="Last Unlocked")]
+	LastUnlocked,
+	#[default]
+	#[strum(to_string="Name")]
+	Name,
+	#[strum(to_string="Platform")]
+	Platform,
+}
+
+impl Filterable<UnifiedGame> for UnifiedLibrary
+{
+	fn filter(&self, filter: impl Into<FilterCriteria>) -> Vec<UnifiedGame>
+	{
+		let filter = filter.into();
+		
+		let allGames = filter.showAll;
+		let caseSensitive = filter.caseSensitive;
+		let search = match caseSensitive
+		{
+			false => filter.text.to_lowercase(),
+			true => filter.text.clone(),
+		};
+		
+		let mut games = self.games.iter()
+			.filter(|g| allGames || g.achievementsTotal > 0)
+			.filter(|g| match caseSensitive
+			{
+				false => g.name.to_lowercase().contains(&search),
+				true => g.name.contains(&search),
+			})
+			.cloned()
+			.collect::<Vec<_>>();
+		
+		games.sort_by(|a, b| a.partial_cmp(b).unwrap_or(Ordering::Equal));
+		
+		return games;
+	}
+}
+
+impl UnifiedLibrary
+{
+	pub fn extend(&mut self, games: impl IntoIterator<Item = UnifiedGame>)
+	{
+		self.games.extend(games);
+	}
+	
+	/**
+	Filter the library and order the result by `sort`.
+	
+	Games are always ordered by name first so that ties in the chosen ordering
+	remain stable. Completion and last unlocked are ordered descending, with
+	games which have never been unlocked placed last.
+	*/
+	pub fn filterAndSort(
+		&self,
+		filter: impl Into<FilterCriteria>,
+		platform: Option<GamePlatforms>,
+		sort: UnifiedSort
+	) -> Vec<UnifiedGame>
+	{
+		let mut games = self.filter(filter);
+		
+		if let Some(platform) = platform
+		{
+			games.retain(|g| g.platform == platform);
+		}
+		
+		match sort
+		{
+			UnifiedSort::Completion => games.sort_by(|a, b| b.percentUnlocked()
+				.partial_cmp(&a.percentUnlocked())
+				.unwrap_or(Ordering::Equal)),
+			
+			UnifiedSort::LastUnlocked => games.sort_by(|a, b| b.lastUnlocked.cmp(&a.lastUnlocked)),
+			UnifiedSort::Name => {},
+			UnifiedSort::Platform => games.sort_by(|a, b| a.platform.cmp(&b.platform)),
+		}
+		
+		return games;
+	}
+	
+	pub fn new() -> Self
+	{
+		return Self::default();
+	}
+}
+
+#[cfg(test)]
+mod tests
+{
+	use chrono::DateTime;
+	use super::*;
+	
+	fn buildLibrary() -> UnifiedLibrary
+	{
+		let mut gog = UnifiedGame::new(GamePlatforms::Gog, "1", "Zeta", vec![]);
+		gog.achievementsTotal = 10;
+		gog.achievementsUnlocked = 5;
+		gog.lastUnlocked = DateTime::from_timestamp(1728611918, 0);
+		
+		let mut steam = UnifiedGame::new(GamePlatforms::Steam, "2", "The Alpha", vec![]);
+		steam.achievementsTotal = 4;
+		steam.achievementsUnlocked = 4;
+		steam.lastUnlocked = DateTime::from_timestamp(1765083932, 0);
+		
+		let mut ra = UnifiedGame::new(GamePlatforms::RetroAchievements, "3", "Beta", vec![]);
+		ra.achievementsTotal = 20;
+		
+		let empty = UnifiedGame::new(GamePlatforms::EpicGamesStore, "4", "Alpha Empty", vec![]);
+		
+		let mut library = UnifiedLibrary::new();
+		library.extend(vec![gog, steam, ra, empty]);
+		return library;
+	}
+	
+	fn ids(games: &Vec<UnifiedGame>) -> Vec<&str>
+	{
+		return games.iter()
+			.map(|g| g.id.as_str())
+			.collect();
+	}
+	
+	#[test]
+	fn filter()
+	{
+		let library = buildLibrary();
+		
+		let games = library.filter(FilterCriteria::default());
+		assert_eq!(ids(&games), vec!["2", "3", "1"]);
+		
+		let games = library.filter(FilterCriteria
+		{
+			showAll: true,
+			..Default::default()
+		});
+		assert_eq!(ids(&games), vec!["4", "2", "3", "1"]);
+		
+		let games = library.filter(FilterCriteria
+		{
+			caseSensitive: true,
+			text: "alpha".into(),
+			showAll: true,
+			..Default::default()
+		});
+		assert!(games.is_empty());
+		
+		let games = library.filter(FilterCriteria::from("alpha".to_string()));
+		assert_eq!(ids(&games), vec!["2"]);
+	}
+	
+	#[test]
+	fn filterAndSort()
+	{
+		let library = buildLibrary();
+		
+		let games = library.filterAndSort(FilterCriteria::default(), None, UnifiedSort::Completion);
+		assert_eq!(ids(&games), vec!["2", "1", "3"]);
+		
+		let games = library.filterAndSort(FilterCriteria::default(), None, UnifiedSort::LastUnlocked);
+		assert_eq!(ids(&games), vec!["2", "1", "3"]);
+		
+		let games = library.filterAndSort(FilterCriteria::default(), None, UnifiedSort::Platform);
+		assert_eq!(ids(&games), vec!["1", "3", "2"]);
+		
+		let games = library.filterAndSort(
+			FilterCriteria::default(),
+			Some(GamePlatforms::RetroAchievements),
+			UnifiedSort::Name
+		);
+		assert_eq!(ids(&games), vec!["3"]);
+	}
+}
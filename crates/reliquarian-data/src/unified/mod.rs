@@ -0,0 +1,14 @@
+mod achievement;
+mod extension;
+mod game;
+mod library;
+mod timeline;
+mod unifiable;
+
+pub use achievement::UnifiedAchievement;
+pub use extension::{EgsExtension, PsnExtension, PsnGrade,
+	RetroAchievementsExtension, Sc2Extension, UnifiedExtension};
+pub use game::UnifiedGame;
+pub use library::{UnifiedLibrary, UnifiedSort};
+pub use timeline::{TimelineDay, TimelineEntry, UnlockTimeline};
+pub use unifiable::Unifiable;
@@ -0,0 +1,259 @@
+use chrono::{DateTime, NaiveDate, TimeZone, Utc};
+use crate::enums::GamePlatforms;
+use crate::io::FileLocation;
+use super::achievement::UnifiedAchievement;
+use super::game::UnifiedGame;
+
+/**
+A single unlocked achievement along with enough information about its game to
+display it outside of the game's own view.
+*/
+#[derive(Clone, Debug, PartialEq)]
+pub struct TimelineEntry
+{
+	pub achievement: UnifiedAchievement,
+	
+	/// The location of the game's cached icon image, if the platform provides one.
+	pub gameIcon: Option<FileLocation>,
+	
+	/// The platform-specific id of the game, stringified.
+	pub gameId: String,
+	
+	pub gameName: String,
+	
+	/// The moment when the achievement was unlocked.
+	pub timestamp: DateTime<Utc>,
+}
+
+impl TimelineEntry
+{
+	pub fn platform(&self) -> GamePlatforms
+	{
+		return self.achievement.platform;
+	}
+}
+
+/**
+The entries of an `UnlockTimeline` which were unlocked on the same calendar day.
+*/
+#[derive(Clone, Debug, PartialEq)]
+pub struct TimelineDay
+{
+	pub date: NaiveDate,
+	pub entries: Vec<TimelineEntry>,
+}
+
+/**
+Every unlocked achievement with a known unlock timestamp, across all of the
+given games, ordered from the most recent unlock to the oldest.
+*/
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct UnlockTimeline
+{
+	pub entries: Vec<TimelineEntry>,
+}
+
+impl UnlockTimeline
+{
+	/**
+	Split `entries` into groups of unlocks which occurred on the same day in the
+	given `timezone`.
+	
+	The order of `entries` is preserved, both between and within days.
+	*/
+	pub fn groupByDay<Tz: TimeZone>(entries: &[TimelineEntry], timezone: &Tz) -> Vec<TimelineDay>
+	{
+		let mut days: Vec<TimelineDay> = vec![];
+		
+		for entry in entries
+		{
+			let date = entry.timestamp.with_timezone(timezone).date_naive();
+			match days.last_mut()
+			{
+				Some(day) if day.date == date => day.entries.push(entry.clone()),
+				
+				_ => days.push(TimelineDay
+				{
+					date,
+					entries: vec![entry.clone()],
+				}),
+			}
+		}
+		
+		return days;
+	}
+	
+	pub fn is_empty(&self) -> bool
+	{
+		return self.entries.is_empty();
+	}
+	
+	pub fn len(&self) -> usize
+	{
+		return self.entries.len();
+	}
+	
+	pub fn new(games: &[UnifiedGame]) -> Self
+	{
+		let mut entries = games.iter()
+			.flat_map(|game| game.achievements.iter()
+				.filter(|a| a.unlocked)
+				.filter_map(|a| a.unlockedTimestamp.map(|timestamp| TimelineEntry
+				{
+					achievement: a.clone(),
+					gameIcon: game.icon.clone(),
+					gameId: game.id.clone(),
+					gameName: game.name.clone(),
+					timestamp,
+				}))
+			)
+			.collect::<Vec<_>>();
+		
+		entries.sort_by(|a, b| b.timestamp.cmp(&a.timestamp));
+		
+		return Self { entries };
+	}
+	
+	/**
+	Retrieve the entries on the given zero-based `page`, where each page holds
+	at most `pageSize` entries.
+	*/
+	pub fn page(&self, page: usize, pageSize: usize) -> &[TimelineEntry]
+	{
+		let start = page.saturating_mul(pageSize)
+			.min(self.entries.len());
+		let end = start.saturating_add(pageSize)
+			.min(self.entries.len());
+		
+		return &self.entries[start..end];
+	}
+	
+	pub fn pageCount(&self, pageSize: usize) -> usize
+	{
+		return match pageSize
+		{
+			0 => 0,
+			_ => self.entries.len().div_ceil(pageSize),
+		};
+	}
+	
+	/// Create a new timeline containing only the entries which were unlocked at or after `moment`.
+	pub fn since(&self, moment: DateTime<Utc>) -> Self
+	{
+		let end = self.entries.iter()
+			.position(|e| e.timestamp < moment)
+			.unwrap_or(self.entries.len());
+		
+		return Self
+		{
+			entries: self.entries[..end].to_vec(),
+		};
+	}
+}
+
+#[cfg(test)]
+mod tests
+{
+	use chrono::FixedOffset;
+	use super::*;
+	
+	fn buildAchievement(platform: GamePlatforms, id: &str, timestamp: Option<i64>) -> UnifiedAchievement
+	{
+		let mut achievement = UnifiedAchievement::new(platform, id);
+		achievement.unlocked = timestamp.is_some();
+		achievement.unlockedTimestamp = timestamp.and_then(|ts| DateTime::from_timestamp(ts, 0));
+		return achievement;
+	}
+	
+	fn buildTimeline() -> UnlockTimeline
+	{
+		let steam = UnifiedGame::new(
+			GamePlatforms::Steam,
+			"1",
+			"Steam Game",
+			vec![
+				// 2024-10-11 01:58:38 UTC
+				buildAchievement(GamePlatforms::Steam, "a", Some(1728611918)),
+				buildAchievement(GamePlatforms::Steam, "b", None),
+				// 2024-10-12 12:00:00 UTC
+				buildAchievement(GamePlatforms::Steam, "c", Some(1728734400)),
+			]
+		);
+		
+		let mut unlockedWithoutTimestamp = buildAchievement(GamePlatforms::Gog, "e", None);
+		unlockedWithoutTimestamp.unlocked = true;
+		
+		let gog = UnifiedGame::new(
+			GamePlatforms::Gog,
+			"2",
+			"GOG Game",
+			vec![
+				// 2024-10-11 23:30:00 UTC
+				buildAchievement(GamePlatforms::Gog, "d", Some(1728689400)),
+				unlockedWithoutTimestamp,
+			]
+		);
+		
+		return UnlockTimeline::new(&[steam, gog]);
+	}
+	
+	fn ids(entries: &[TimelineEntry]) -> Vec<&str>
+	{
+		return entries.iter()
+			.map(|e| e.achievement.id.as_str())
+			.collect();
+	}
+	
+	#[test]
+	fn new()
+	{
+		let timeline = buildTimeline();
+		
+		assert_eq!(timeline.len(), 3);
+		assert_eq!(ids(&timeline.entries), vec!["c", "d", "a"]);
+		assert_eq!(&timeline.entries[1].gameName, "GOG Game");
+		assert_eq!(timeline.entries[1].platform(), GamePlatforms::Gog);
+	}
+	
+	#[test]
+	fn page()
+	{
+		let timeline = buildTimeline();
+		
+		assert_eq!(timeline.pageCount(2), 2);
+		assert_eq!(ids(timeline.page(0, 2)), vec!["c", "d"]);
+		assert_eq!(ids(timeline.page(1, 2)), vec!["a"]);
+		assert!(timeline.page(2, 2).is_empty());
+		assert_eq!(timeline.pageCount(0), 0);
+		assert!(timeline.page(0, 0).is_empty());
+	}
+	
+	#[test]
+	fn since()
+	{
+		let timeline = buildTimeline();
+		
+		let moment = DateTime::from_timestamp(1728689400, 0).unwrap();
+		assert_eq!(ids(&timeline.since(moment).entries), vec!["c", "d"]);
+	}
+	
+	#[test]
+	fn groupByDay()
+	{
+		let timeline = buildTimeline();
+		
+		let days = UnlockTimeline::groupByDay(&timeline.entries, &Utc);
+		assert_eq!(days.len(), 2);
+		assert_eq!(days[0].date, NaiveDate::from_ymd_opt(2024, 10, 12).unwrap());
+		assert_eq!(ids(&days[0].entries), vec!["c"]);
+		assert_eq!(days[1].date, NaiveDate::from_ymd_opt(2024, 10, 11).unwrap());
+		assert_eq!(ids(&days[1].entries), vec!["d", "a"]);
+		
+		// UTC-05:00 moves "a" back onto the 10th
+		let offset = FixedOffset::west_opt(5 * 3600).unwrap();
+		let days = UnlockTimeline::groupByDay(&timeline.entries, &offset);
+		assert_eq!(days.len(), 3);
+		assert_eq!(days[2].date, NaiveDate::from_ymd_opt(2024, 10, 10).unwrap());
+		assert_eq!(ids(&days[2].entries), vec!["a"]);
+	}
+}
@@ -0,0 +1,11 @@
+/**
+Conversion from a platform-specific data structure into its platform-neutral
+counterpart.
+
+Each platform crate implements this for its own achievement, game, and user
+types so that aggregate views can be written once against the unified model.
+*/
+pub trait Unifiable<T>
+{
+	fn unify(&self) -> T;
+}
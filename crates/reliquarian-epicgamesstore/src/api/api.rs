@@ -1,7 +1,13 @@
 use anyhow::{anyhow, Context, Result};
+use net::AuthorizationManager;
+use oauth2::{ResponseType, Scope};
 use serde::de::DeserializeOwned;
+use tracing::{info, warn};
 use ureq::config::RedirectAuthHeaders;
 use urlencoding::encode;
+use crate::secure::{getEpicGamesStoreClientAuth,
+	invalidateEpicGamesStoreSession, setEpicGamesStoreAccountId,
+	setEpicGamesStoreSession};
 use super::endpoint::{Extensions, Variables};
 use super::endpoint::achievement::{AchievementVariables, Payload_Achievement};
 use super::endpoint::player::{Payload_PlayerProfile, PlayerProfileVariables};
@@ -9,6 +15,8 @@ use super::endpoint::private::{Payload_PlayerProfilePrivate,
 	PlayerProfilePrivateVariables};
 use super::endpoint::progress::{AchievementProgressVariables,
 	Payload_AchievementProgress};
+use super::endpoint::session::EgsSession;
+use super::endpoint::userinfo::Payload_UserInfo;
 
 #[derive(Clone, Debug, Default)]
 pub struct EgsApi;
@@ -18,6 +26,15 @@ impl EgsApi
 	pub const Platform: &str = "EpicGamesStore";
 	
 	const Endpoint: &str = "https://store.epicgames.com/graphql";
+	const UserInfoEndpoint: &str = "https://api.epicgames.dev/epic/oauth/v2/userInfo";
+	
+	const AuthResponseType: &str = "code";
+	const AuthScope: &str = "basic_profile";
+	const AuthUrl: &str = "https://www.epicgames.com/id/authorize";
+	const TokenUrl: &str = "https://api.epicgames.dev/epic/oauth/v2/token";
+	
+	/// The port of the redirect URL which must be registered with the user's Epic Account Services application.
+	pub const RedirectPort: u64 = 6510;
 	
 	const Error_AccountIdMissing: &str = "Account ID must not be empty";
 	
@@ -31,6 +48,42 @@ impl EgsApi
 	const QueryHash_PlayerProfilePrivate: &str = "47d0391fa5ec42d829e4a03f399cb586a29cf3cebd940cc4747aed0192c61114";
 	const QueryHash_PlayerProfileAchievementsByProductId: &str = "70ff714976f88a85aafa3cb5abb9909d52e12a3ff585d7b49550d2493a528fb0";
 	
+	/**
+	Sign into an Epic account using the user's Epic Account Services
+	application.
+	
+	The account ID of the signed in user is saved alongside the session, so the
+	public profile operations work without entering it manually.
+	*/
+	pub async fn authorize() -> Result<()>
+	{
+		let auth = getEpicGamesStoreClientAuth()?;
+		
+		let mut authManager = AuthorizationManager::new(
+			auth.clientId().clone(),
+			auth.clientSecret().clone(),
+			Self::AuthUrl.into(),
+			Self::TokenUrl.into(),
+			Some(Self::RedirectPort)
+		)?;
+		
+		let session = match authManager.authorizationCodeFlow::<EgsSession>(
+			ResponseType::new(Self::AuthResponseType.into()),
+			vec![Scope::new(Self::AuthScope.into())]
+		).await
+		{
+			Err(e) => return Err(anyhow!("[EpicGamesStore] Authorization flow failed: {:?}", e)),
+			Ok(session) => session,
+		};
+		
+		let userInfo = Self::getUserInfo(&session)?;
+		setEpicGamesStoreAccountId(userInfo.sub)?;
+		setEpicGamesStoreSession(session)?;
+		
+		info!("[EpicGamesStore] Authorization flow succeeded.");
+		return Ok(());
+	}
+	
 	/**
 	Query the `Achievement` operation.
 	
@@ -133,6 +186,81 @@ impl EgsApi
 		};
 	}
 	
+	/**
+	Retrieves the account ID and display name of the signed in user.
+	
+	Uses the `userInfo` endpoint which Epic Account Services documents for
+	tokens issued with the `basic_profile` scope.
+	*/
+	pub fn getUserInfo(session: &EgsSession) -> Result<Payload_UserInfo>
+	{
+		return Self::getAuthenticated(Self::UserInfoEndpoint.into(), session);
+	}
+	
+	/**
+	Refresh the session if its access token has expired.
+	
+	Epic issues a new refresh token along with each access token, so the
+	refreshed session replaces the stored session. If the session cannot be
+	refreshed, it is flagged so the UI can ask the user to sign in again.
+	
+	Returns `Err` if the session requires the user to sign in again.
+	*/
+	pub async fn refreshSession(session: EgsSession) -> Result<EgsSession>
+	{
+		if session.requiresReauthentication()
+		{
+			return Err(anyhow!("[EpicGamesStore] Session requires re-authentication"));
+		}
+		
+		if !session.hasExpired()
+		{
+			return Ok(session);
+		}
+		
+		let refreshed = match session.refreshToken()
+		{
+			// Sessions stored before refresh tokens were kept cannot be refreshed
+			None => None,
+			Some(refreshToken) => {
+				info!("[EpicGamesStore] Session has expired; Attempting refresh");
+				
+				let auth = getEpicGamesStoreClientAuth()?;
+				let authManager = AuthorizationManager::new(
+					auth.clientId().clone(),
+					auth.clientSecret().clone(),
+					Self::AuthUrl.into(),
+					Self::TokenUrl.into(),
+					None
+				)?;
+				
+				authManager.refreshTokenFlow::<EgsSession>(refreshToken.clone())
+					.await?
+					.map(|refreshed| refreshed.retainRefreshToken(refreshToken.clone()))
+			},
+		};
+		
+		return match refreshed
+		{
+			None => {
+				if let Err(e) = invalidateEpicGamesStoreSession()
+				{
+					warn!("[EpicGamesStore] Error flagging session for re-authentication: {:?}", e);
+				}
+				
+				Err(anyhow!("[EpicGamesStore] Session could not be refreshed; Re-authentication required"))
+			},
+			
+			Some(refreshed) => {
+				setEpicGamesStoreSession(refreshed.clone())
+					.context("[EpicGamesStore] Error saving session data to vault")?;
+				
+				info!("[EpicGamesStore] Session refreshed successfully");
+				Ok(refreshed)
+			},
+		};
+	}
+	
 	/**
 	Execute an HTTP GET request to the GraphQL endpoint.
 	
@@ -170,6 +298,35 @@ impl EgsApi
 		
 		return Ok(response);
 	}
+	
+	/**
+	Execute an HTTP GET request on behalf of the signed in user.
+	
+	Passes the `EgsSession`'s access token in the `Authorization` header.
+	
+	Returns `Err` if the `EgsSession` has expired, so expired sessions must be
+	passed through `refreshSession` first.
+	*/
+	fn getAuthenticated<T>(requestUrl: String, session: &EgsSession) -> Result<T>
+		where T: DeserializeOwned
+	{
+		return match !session.hasExpired()
+		{
+			true => {
+				let response = ureq::get(requestUrl)
+					.header("Authorization", format!("Bearer {}", session.accessToken().secret()))
+					.call()
+						.context("Error retrieving Epic Games Store API response")?
+					.body_mut()
+					.read_json::<T>()
+						.context("Error parsing Epic Games Store API response as JSON")?;
+				
+				Ok(response)
+			},
+			
+			false => Err(anyhow!("[EpicGamesStore] Session expired!")),
+		};
+	}
 }
 
 #[cfg(test)]
@@ -209,4 +366,19 @@ mod tests
 		assert_eq!(&profile.data.PlayerProfile.playerProfile.displayName, "display name");
 		assert_eq!(&profile.data.PlayerProfile.playerProfile.epicAccountId, "account id string");
 	}
+	
+	#[test]
+	fn parseUserInfoPayload()
+	{
+		let response = r#"{
+	"sub": "account id string",
+	"preferred_username": "display name"
+}"#;
+		let payload = serde_json::from_str::<Payload_UserInfo>(response);
+		assert!(payload.is_ok());
+		
+		let userInfo = payload.unwrap();
+		assert_eq!(&userInfo.sub, "account id string");
+		assert_eq!(&userInfo.preferred_username, "display name");
+	}
 }
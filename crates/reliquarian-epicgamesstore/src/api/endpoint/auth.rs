@@ -0,0 +1,53 @@
+use std::fmt::{Debug, Display, Formatter};
+
+#[derive(Clone, Default)]
+pub struct EgsAuth
+{
+	/// The Client ID of the user's Epic Account Services application
+	clientId: String,
+	/// The Client Secret of the user's Epic Account Services application
+	clientSecret: String,
+}
+
+impl Debug for EgsAuth
+{
+	fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result
+	{
+		return write!(f, "EgsAuth Redacted");
+	}
+}
+
+impl Display for EgsAuth
+{
+	fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result
+	{
+		return write!(f, "EgsAuth Redacted");
+	}
+}
+
+impl EgsAuth
+{
+	pub const ClientIdKey: &str = "epicGamesStoreClientId";
+	pub const ClientSecretKey: &str = "epicGamesStoreClientSecret";
+	
+	/// The Client ID of the user's Epic Account Services application
+	pub fn clientId(&self) -> &String
+	{
+		return &self.clientId;
+	}
+	
+	/// The Client Secret of the user's Epic Account Services application
+	pub fn clientSecret(&self) -> &String
+	{
+		return &self.clientSecret;
+	}
+	
+	pub fn new(id: String, secret: String) -> Self
+	{
+		return Self
+		{
+			clientId: id,
+			clientSecret: secret,
+		};
+	}
+}
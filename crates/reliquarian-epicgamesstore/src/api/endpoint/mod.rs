@@ -1,8 +1,11 @@
 pub mod achievement;
+pub mod auth;
 pub mod player;
 pub mod private;
 pub mod progress;
+pub mod session;
 pub mod unified;
+pub mod userinfo;
 
 use serde::Serialize;
 
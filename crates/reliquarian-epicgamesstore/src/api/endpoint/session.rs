@@ -0,0 +1,165 @@
+use std::fmt::{Debug, Display, Formatter};
+use std::time::Duration;
+use chrono::{DateTime, Utc};
+use net::{Oauth2Session, Reauthentication, SessionStatus};
+use oauth2::{AccessToken, EmptyExtraTokenFields, RefreshToken,
+	StandardTokenResponse, TokenResponse};
+use oauth2::basic::BasicTokenType;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Deserialize, Serialize)]
+pub struct EgsSession
+{
+	accessToken: AccessToken,
+	
+	/// The lifetime of the access token, which is zero when Epic did not report one.
+	expiresIn: Duration,
+	
+	/// Set when Epic rejects the refresh token, requiring the user to sign in again.
+	#[serde(default)]
+	reauthenticate: Reauthentication,
+	
+	#[serde(default)]
+	refreshToken: Option<RefreshToken>,
+	
+	timestamp: DateTime<Utc>,
+	tokenType: BasicTokenType,
+}
+
+impl Debug for EgsSession
+{
+	fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result
+	{
+		return write!(f, "EgsSession Redacted");
+	}
+}
+
+impl Display for EgsSession
+{
+	fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result
+	{
+		return write!(f, "EgsSession Redacted");
+	}
+}
+
+impl Oauth2Session for EgsSession
+{
+	fn fromTokenResult(result: StandardTokenResponse<EmptyExtraTokenFields, BasicTokenType>) -> Self
+	{
+		return Self
+		{
+			accessToken: result.access_token().clone(),
+			expiresIn: match result.expires_in()
+			{
+				None => Duration::default(),
+				Some(expiresIn) => expiresIn.clone(),
+			},
+			reauthenticate: Reauthentication::default(),
+			refreshToken: result.refresh_token().cloned(),
+			timestamp: Utc::now(),
+			tokenType: result.token_type().clone(),
+		};
+	}
+}
+
+impl EgsSession
+{
+	pub const SecretKey: &str = "epicGamesStoreSession";
+	
+	/**
+	Test if the access token has expired yet.
+	
+	Returns `TRUE` is the access token is expired.
+	Otherwise returns `FALSE`.
+	
+	Access tokens issued without a lifetime are assumed not to expire, leaving
+	Epic to reject them once they are no longer valid.
+	*/
+	pub fn hasExpired(&self) -> bool
+	{
+		if self.expiresIn.is_zero()
+		{
+			return false;
+		}
+		
+		let expiration = self.timestamp + self.expiresIn;
+		return expiration.signed_duration_since(Utc::now()).num_seconds() <= 0;
+	}
+	
+	pub fn invalidate(&mut self)
+	{
+		self.reauthenticate.invalidate();
+	}
+	
+	pub fn requiresReauthentication(&self) -> bool
+	{
+		return self.reauthenticate.isRequired();
+	}
+	
+	/**
+	Keep using `refreshToken` if Epic did not issue a new refresh token along
+	with this session.
+	*/
+	pub fn retainRefreshToken(mut self, refreshToken: RefreshToken) -> Self
+	{
+		if self.refreshToken.is_none()
+		{
+			self.refreshToken = Some(refreshToken);
+		}
+		
+		return self;
+	}
+	
+	pub fn status(&self) -> SessionStatus
+	{
+		return self.reauthenticate.status();
+	}
+	
+	pub fn accessToken(&self) -> &AccessToken
+	{
+		return &self.accessToken;
+	}
+	
+	pub fn refreshToken(&self) -> Option<&RefreshToken>
+	{
+		return self.refreshToken.as_ref();
+	}
+}
+
+#[cfg(test)]
+mod tests
+{
+	use super::*;
+	
+	/// Stored before refresh tokens were kept, by a token response without a lifetime.
+	const StoredJson: &str = r#"{
+	"accessToken": "access",
+	"expiresIn": { "secs": 0, "nanos": 0 },
+	"timestamp": "2025-11-19T09:09:08Z",
+	"tokenType": "bearer"
+}"#;
+	
+	#[test]
+	fn invalidate()
+	{
+		let session = serde_json::from_str::<EgsSession>(StoredJson).unwrap();
+		assert!(!session.hasExpired());
+		assert!(!session.requiresReauthentication());
+		assert!(session.refreshToken().is_none());
+		assert_eq!(session.status(), SessionStatus::Active);
+		
+		let session = session.retainRefreshToken(RefreshToken::new("refresh".into()));
+		assert_eq!(session.refreshToken().map(|t| t.secret().clone()), Some("refresh".to_string()));
+		
+		let mut session = session.retainRefreshToken(RefreshToken::new("older".into()));
+		assert_eq!(session.refreshToken().map(|t| t.secret().clone()), Some("refresh".to_string()));
+		
+		session.invalidate();
+		assert!(session.requiresReauthentication());
+		assert_eq!(session.status(), SessionStatus::ReauthenticationRequired);
+		
+		let json = serde_json::to_string(&session).unwrap();
+		let session = serde_json::from_str::<EgsSession>(&json).unwrap();
+		assert!(session.requiresReauthentication());
+	}
+}
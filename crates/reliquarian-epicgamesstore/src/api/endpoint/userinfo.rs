@@ -0,0 +1,12 @@
+use serde::{Deserialize, Serialize};
+
+#[allow(non_camel_case_types)]
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct Payload_UserInfo
+{
+	/// The user's Epic Account ID.
+	pub sub: String,
+	
+	#[serde(default)]
+	pub preferred_username: String,
+}
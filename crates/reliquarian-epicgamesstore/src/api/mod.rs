@@ -4,7 +4,10 @@ mod endpoint;
 pub use api::EgsApi;
 pub use endpoint::{
 	achievement::{Achievement, Payload_Achievement},
+	auth::EgsAuth,
 	player::Payload_PlayerProfile,
 	private::{AchievementSummary, Payload_PlayerProfilePrivate},
 	progress::{Payload_AchievementProgress, PlayerAchievementContainer},
+	session::EgsSession,
+	userinfo::Payload_UserInfo,
 };
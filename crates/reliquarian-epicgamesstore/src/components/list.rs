@@ -1,18 +1,23 @@
 use std::path::PathBuf;
+use components::button::icon::IconButton;
+use components::export::promptExport;
 use components::extensions::PressableExt;
 use components::input::filter::GamesFilter;
 use data::constants::{BorderColor, ButtonBackgroundColor, CornerRadius,
 	FileName_GameIcon, Path_Games, RetroAchievementsProgressColorBackground,
 	RetroAchievementsProgressColorHardcore};
 use data::enums::GamePlatforms;
+use data::export::AchievementExport;
 use data::filter::{FilterCriteria, Filterable};
 use data::io::{FileLocation, filePathExists, getImagePath};
+use freya::icons::lucide;
 use freya::prelude::{Alignment, Border, BorderAlignment, ChildrenExt, Code,
 	Color, Component, ContainerExt, ContainerSizeExt, ContainerWithContentExt,
 	Content, Direction, Event, EventHandlersExt, FontWeight, Gaps, ImageViewer,
 	IntoElement, KeyboardEventData, ProgressBar, ProgressBarThemePartialExt,
 	ScrollConfig, ScrollPosition, Size, Span, StyleExt, TextAlign, TextStyleExt,
-	VirtualScrollView, label, paragraph, rect, use_scroll_controller, use_state};
+	VirtualScrollView, label, paragraph, rect, spawn, use_scroll_controller,
+	use_state};
 use freya::radio::use_radio;
 use macros::{join, jpg};
 use crate::api::EgsApi;
@@ -31,12 +36,14 @@ impl Component for GameList
 		let caseSensitive = use_state(bool::default);
 		let search = use_state(String::default);
 		
-		let games = user.read().filter(FilterCriteria
+		let criteria = FilterCriteria
 		{
 			caseSensitive: caseSensitive(),
 			text: search.read().clone(),
 			..Default::default()
-		});
+		};
+		
+		let games = user.read().filter(criteria.clone());
 		let gamesLength = games.len();
 		
 		return rect()
@@ -64,9 +71,29 @@ impl Component for GameList
 			)
 			
 			.child(
-				GamesFilter::new(caseSensitive, search)
+				rect()
+					.content(Content::Flex)
+					.cross_align(Alignment::Center)
+					.direction(Direction::Horizontal)
 					.margin(Gaps::new(5.0, 0.0, 0.0, 0.0))
+					.spacing(10.0)
 					.width(Size::percent(50.0))
+					
+					.child(
+						GamesFilter::new(caseSensitive, search)
+							.width(Size::flex(1.0))
+					)
+					
+					.child(
+						IconButton::new(lucide::download())
+							.alt("Export")
+							.onPress(move |_| {
+								let export = AchievementExport::fromFilteredGames(&*user.read(), criteria.clone());
+								spawn(async move {
+									promptExport(export, "Epic Games Store").await;
+								});
+							})
+					)
 			)
 			
 			.child(
@@ -46,11 +46,9 @@ impl Component for EgsUserProfile
 				if confirmed()
 				{
 					spawn(async move {
-						rateLimiter.read().pushAll(vec![
-							EgsOperation::GetPlayerProfile.into(),
-							EgsOperation::GetPlayerProfilePrivate.into(),
-							EgsOperation::SaveToFile.into(),
-						]).await;
+						let mut requests = EgsOperation::refreshRequests();
+						requests.push(EgsOperation::SaveToFile.into());
+						rateLimiter.read().pushAll(requests).await;
 						
 						**requestEvent.write() = RequestEvent::Added;
 					});
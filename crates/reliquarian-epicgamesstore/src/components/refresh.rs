@@ -3,12 +3,13 @@ use data::io::FileLocation;
 use macros::{join, jpg, png};
 use net::{DataOperation, DataRequest};
 use tracing::{info, warn};
-use crate::api::EgsApi;
+use crate::api::{EgsApi, Payload_Achievement};
 use crate::data::io::saveUserData;
 use crate::data::operation::EgsOperation;
 use crate::data::result::EgsOperationResult;
 use crate::data::user::EgsUser;
-use crate::secure::getEpicGamesStoreAccountId;
+use crate::secure::{getEpicGamesStoreAccountId, getEpicGamesStoreSession,
+	setEpicGamesStoreAccountId};
 
 pub async fn handleEgsOperation(user: EgsUser, dataOperation: DataOperation) -> Option<EgsOperationResult>
 {
@@ -45,21 +46,94 @@ pub async fn handleEgsOperation(user: EgsUser, dataOperation: DataOperation) ->
 				Some(result)
 			}
 			
+			EgsOperation::GetUserInfo => {
+				let result = refreshEgsUserInfo(user).await;
+				info!("[EpicGamesStore] Refreshed signed in account");
+				
+				Some(result)
+			}
+			
 			EgsOperation::SaveToFile => {
 				match saveUserData(&user)
 				{
 					Err(e) => warn!("[EpicGamesStore] Error saving user data: {:?}", e),
 					Ok(_) => info!("[EpicGamesStore] Saved user data"),
 				}
-			
+				
 				None
 			}
 		}
 	};
 }
 
+/**
+Apply an achievement metadata payload to a game and cache its achievement
+icons.
+
+Returns the requests which cache the icons.
+*/
+fn applyEgsAchievementMetadata(user: &mut EgsUser, sandboxId: &String, payload: &Payload_Achievement) -> Vec<DataRequest>
+{
+	let mut requests = vec![];
+	
+	// Cache icons
+	let group = join!(Path_Games, sandboxId.clone());
+	let platform = EgsApi::Platform.to_lowercase();
+	
+	for container in payload.data.Achievement
+		.productAchievementsRecordBySandbox.achievements.iter()
+	{
+		requests.push(DataRequest
+		{
+			destination: Some(FileLocation
+			{
+				fileName: container.achievement.unlockedIconId.clone(),
+				group: group.clone(),
+				platform: platform.clone(),
+			}),
+			operation: DataOperation::CacheImage(false),
+			url: Some(container.achievement.unlockedIconLink.clone()),
+		});
+		
+		requests.push(DataRequest
+		{
+			destination: Some(FileLocation
+			{
+				fileName: container.achievement.lockedIconId.clone(),
+				group: group.clone(),
+				platform: platform.clone(),
+			}),
+			operation: DataOperation::CacheImage(false),
+			url: Some(container.achievement.lockedIconLink.clone()),
+		});
+		
+		if let Some(game) = user.games.iter_mut()
+			.find(|g| &g.sandboxId == sandboxId)
+		{
+			match game.achievements.iter_mut()
+				.find(|a| a.id == container.achievement.name)
+			{
+				None => game.achievements.push((&container.achievement).into()),
+				Some(chievo) => chievo.updateMetadata(&container.achievement),
+			}
+		}
+	}
+	
+	if let Some(game) = user.games.iter_mut()
+		.find(|g| &g.sandboxId == sandboxId)
+	{
+		game.achievementsCount = payload.data.Achievement.productAchievementsRecordBySandbox.totalAchievements;
+		game.maxXp = payload.data.Achievement.productAchievementsRecordBySandbox.totalProductXP;
+		game.platinumRarity = (payload.data.Achievement.productAchievementsRecordBySandbox.platinumRarity.percent * 10.0) as u64;
+		game.productId = payload.data.Achievement.productAchievementsRecordBySandbox.productId.clone();
+	}
+	
+	return requests;
+}
+
 fn refreshEgsAchievementMetadata(mut user: EgsUser, sandboxId: &String) -> EgsOperationResult
 {
+	let mut failed = true;
 	let mut requests = vec![];
 	
 	if let Ok(accountId) = getEpicGamesStoreAccountId()
@@ -68,64 +142,15 @@ fn refreshEgsAchievementMetadata(mut user: EgsUser, sandboxId: &String) -> EgsOp
 		{
 			Err(e) => warn!("Failed to refresh Epic Games Store achievements metadata data: {:?}", e),
 			Ok(payload) => {
-				
-				// Cache icons
-				let group = join!(Path_Games, sandboxId.clone());
-				let platform = EgsApi::Platform.to_lowercase();
-				
-				for container in payload.data.Achievement
-					.productAchievementsRecordBySandbox.achievements.iter()
-				{
-					requests.push(DataRequest
-					{
-						destination: Some(FileLocation
-						{
-							fileName: container.achievement.unlockedIconId.clone(),
-							group: group.clone(),
-							platform: platform.clone(),
-						}),
-						operation: DataOperation::CacheImage(false),
-						url: Some(container.achievement.unlockedIconLink.clone()),
-					});
-					
-					requests.push(DataRequest
-					{
-						destination: Some(FileLocation
-						{
-							fileName: container.achievement.lockedIconId.clone(),
-							group: group.clone(),
-							platform: platform.clone(),
-						}),
-						operation: DataOperation::CacheImage(false),
-						url: Some(container.achievement.lockedIconLink.clone()),
-					});
-					
-					if let Some(game) = user.games.iter_mut()
-						.find(|g| &g.sandboxId == sandboxId)
-					{
-						match game.achievements.iter_mut()
-							.find(|a| a.id == container.achievement.name)
-						{
-							None => game.achievements.push((&container.achievement).into()),
-							Some(chievo) => chievo.updateMetadata(&container.achievement),
-						}
-					}
-				}
-				
-				if let Some(game) = user.games.iter_mut()
-					.find(|g| &g.sandboxId == sandboxId)
-				{
-					game.achievementsCount = payload.data.Achievement.productAchievementsRecordBySandbox.totalAchievements;
-					game.maxXp = payload.data.Achievement.productAchievementsRecordBySandbox.totalProductXP;
-					game.platinumRarity = (payload.data.Achievement.productAchievementsRecordBySandbox.platinumRarity.percent * 10.0) as u64;
-					game.productId = payload.data.Achievement.productAchievementsRecordBySandbox.productId.clone();
-				}
+				failed = false;
+				requests = applyEgsAchievementMetadata(&mut user, sandboxId, &payload);
 			}
 		}
 	}
 	
 	return EgsOperationResult
 	{
+		failed,
 		user,
 		requests,
 	};
@@ -133,12 +158,16 @@ fn refreshEgsAchievementMetadata(mut user: EgsUser, sandboxId: &String) -> EgsOp
 
 fn refreshEgsAchievementProgress(mut user: EgsUser, productId: &String) -> EgsOperationResult
 {
+	let mut failed = true;
+	
 	if let Ok(accountId) = getEpicGamesStoreAccountId()
 	{
 		match EgsApi::getAchievementProgress(&accountId, productId)
 		{
 			Err(e) => warn!("Failed to refresh Epic Games Store achievements progress data: {:?}", e),
 			Ok(payload) => {
+				failed = false;
+				
 				if let Some(game) = user.games.iter_mut()
 					.find(|g| &g.productId == productId)
 				{
@@ -164,11 +193,17 @@ fn refreshEgsAchievementProgress(mut user: EgsUser, productId: &String) -> EgsOp
 		}
 	}
 	
-	return user.into();
+	return EgsOperationResult
+	{
+		failed,
+		user,
+		requests: vec![],
+	};
 }
 
 fn refreshEgsPlayerProfile(mut user: EgsUser) -> EgsOperationResult
 {
+	let mut failed = true;
 	let mut requests = vec![];
 	
 	if let Ok(accountId) = getEpicGamesStoreAccountId()
@@ -177,6 +212,8 @@ fn refreshEgsPlayerProfile(mut user: EgsUser) -> EgsOperationResult
 		{
 			Err(e) => warn!("Failed to refresh Epic Games Store player profile data: {:?}", e),
 			Ok(payload) => {
+				failed = false;
+				
 				// Cache icon
 				requests.push(DataRequest
 				{
@@ -197,6 +234,7 @@ fn refreshEgsPlayerProfile(mut user: EgsUser) -> EgsOperationResult
 	
 	return EgsOperationResult
 	{
+		failed,
 		user,
 		requests,
 	};
@@ -204,6 +242,7 @@ fn refreshEgsPlayerProfile(mut user: EgsUser) -> EgsOperationResult
 
 fn refreshEgsPlayerProfilePrivate(mut user: EgsUser) -> EgsOperationResult
 {
+	let mut failed = true;
 	let mut requests = vec![];
 	
 	if let Ok(accountId) = getEpicGamesStoreAccountId()
@@ -212,6 +251,8 @@ fn refreshEgsPlayerProfilePrivate(mut user: EgsUser) -> EgsOperationResult
 		{
 			Err(e) => warn!("Failed to refresh Epic Games Store private player profile data: {:?}", e),
 			Ok(payload) => {
+				failed = false;
+				
 				//Cache game icons
 				for summary in payload.data.PlayerProfile.playerProfile.achievementsSummaries.data.iter()
 				{
@@ -239,7 +280,45 @@ fn refreshEgsPlayerProfilePrivate(mut user: EgsUser) -> EgsOperationResult
 	
 	return EgsOperationResult
 	{
+		failed,
 		user,
 		requests,
 	};
 }
+
+async fn refreshEgsUserInfo(mut user: EgsUser) -> EgsOperationResult
+{
+	let mut failed = true;
+	
+	let session = match getEpicGamesStoreSession()
+	{
+		Err(e) => Err(e),
+		Ok(session) => EgsApi::refreshSession(session).await,
+	};
+	
+	match session
+	{
+		Err(e) => warn!("Failed to refresh Epic Games Store account, not signed in: {:?}", e),
+		Ok(session) => match EgsApi::getUserInfo(&session)
+		{
+			Err(e) => warn!("Failed to refresh Epic Games Store account: {:?}", e),
+			Ok(payload) => {
+				failed = false;
+				
+				if let Err(e) = setEpicGamesStoreAccountId(payload.sub.clone())
+				{
+					warn!("[EpicGamesStore] Error saving account ID to vault: {:?}", e);
+				}
+				
+				user.updateUserInfo(payload);
+			}
+		},
+	}
+	
+	return EgsOperationResult
+	{
+		failed,
+		user,
+		requests: vec![],
+	};
+}
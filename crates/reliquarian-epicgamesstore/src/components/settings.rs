@@ -1,11 +1,17 @@
 use components::settings::switch::InputModeSwitch;
 use components::settings::util::separatorElement;
-use data::constants::InputModeHiddenChar;
-use freya::prelude::{Alignment, ChildrenExt, Component, ContainerExt,
+use data::constants::{InputModeHiddenChar, TextColor, WarningColor};
+use freya::prelude::{Alignment, Button, ChildrenExt, Component, ContainerExt,
 	ContainerSizeExt, ContainerWithContentExt, Content, Direction, Gaps, Input,
-	InputMode, IntoElement, Size, TextAlign, TextStyleExt, label, rect,
-	use_memo, use_state};
-use crate::secure::{getEpicGamesStoreAccountId, setEpicGamesStoreAccountId};
+	InputMode, IntoElement, Size, TextAlign, TextStyleExt, WritableUtils, label,
+	rect, spawn, use_hook, use_memo, use_state};
+use net::SessionStatus;
+use tracing::{info, warn};
+use crate::api::EgsApi;
+use crate::secure::{getEpicGamesStoreAccountId, getEpicGamesStoreClientAuth,
+	getEpicGamesStoreSessionStatus, removeEpicGamesStoreSession,
+	setEpicGamesStoreAccountId, setEpicGamesStoreClientId,
+	setEpicGamesStoreClientSecret};
 
 #[derive(Clone, PartialEq)]
 pub struct EgsSettingsElement
@@ -17,9 +23,21 @@ impl Component for EgsSettingsElement
 {
 	fn render(&self) -> impl IntoElement
 	{
-		let accountId = use_state(|| getEpicGamesStoreAccountId().unwrap_or_default());
+		let mut accountId = use_state(|| getEpicGamesStoreAccountId().unwrap_or_default());
 		let inputModeId = use_state(|| InputMode::Hidden(InputModeHiddenChar));
 		
+		let mut clientId = use_state(String::default);
+		let mut clientSecret = use_state(String::default);
+		let inputModeClientId = use_state(|| InputMode::Hidden(InputModeHiddenChar));
+		let inputModeClientSecret = use_state(|| InputMode::Hidden(InputModeHiddenChar));
+		let mut sessionStatus = use_state(getEpicGamesStoreSessionStatus);
+		
+		use_hook(|| {
+			let auth = getEpicGamesStoreClientAuth().unwrap_or_default();
+			clientId.set(auth.clientId().clone());
+			clientSecret.set(auth.clientSecret().clone());
+		});
+		
 		use_memo(move || {
 			_ = setEpicGamesStoreAccountId(accountId.read().clone());
 		});
@@ -74,6 +92,153 @@ impl Component for EgsSettingsElement
 					)
 					
 					.child(InputModeSwitch(inputModeId))
+			)
+			
+			.child(
+				label()
+					.margin(Gaps::new_symmetric(5.0, 0.0))
+					.text_align(TextAlign::Center)
+					.width(Size::Fill)
+					.text("Epic Account Services Authentication")
+			)
+			
+			.child(
+				label()
+					.color(match sessionStatus()
+					{
+						SessionStatus::ReauthenticationRequired => WarningColor,
+						_ => TextColor,
+					})
+					.text_align(TextAlign::Center)
+					.width(Size::Fill)
+					.text(format!("Session Status: {}", sessionStatus()))
+			)
+			
+			.child(
+				label()
+					.text_align(TextAlign::Center)
+					.width(Size::percent(75.0))
+					.text(format!(
+						"Signing in fills in the Account ID of the signed in account. Register http://127.0.0.1:{} as the redirect URL of your application.",
+						EgsApi::RedirectPort
+					))
+			)
+			
+			.child(
+				rect()
+					.content(Content::Flex)
+					.direction(Direction::Horizontal)
+					.main_align(Alignment::Center)
+					.spacing(5.0)
+					.width(Size::percent(75.0))
+					
+					.child(
+						label()
+							.margin(Gaps::new(5.0, 5.0, 0.0, 0.0))
+							.min_width(Size::px(102.0))
+							.text_align(TextAlign::End)
+							.width(self.labelWidth.clone())
+							.text("Client ID")
+					)
+					
+					.child(
+						Input::new(clientId)
+							.mode(inputModeClientId.read().clone())
+							.placeholder("Epic Client ID")
+							.width(Size::flex(1.0))
+					)
+					
+					.child(
+						label()
+							.margin(Gaps::new(5.0, 0.0, 0.0, 0.0))
+							.text_align(TextAlign::End)
+							.width(Size::FillMinimum)
+							.text("Show")
+					)
+					
+					.child(InputModeSwitch(inputModeClientId))
+			)
+			
+			.child(
+				rect()
+					.content(Content::Flex)
+					.direction(Direction::Horizontal)
+					.main_align(Alignment::Center)
+					.spacing(5.0)
+					.width(Size::percent(75.0))
+					
+					.child(
+						label()
+							.margin(Gaps::new(5.0, 5.0, 0.0, 0.0))
+							.min_width(Size::px(102.0))
+							.text_align(TextAlign::End)
+							.width(self.labelWidth.clone())
+							.text("Client Secret")
+					)
+					
+					.child(
+						Input::new(clientSecret)
+							.mode(inputModeClientSecret.read().clone())
+							.placeholder("Epic Client Secret")
+							.width(Size::flex(1.0))
+					)
+					
+					.child(
+						label()
+							.margin(Gaps::new(5.0, 0.0, 0.0, 0.0))
+							.text_align(TextAlign::End)
+							.width(Size::FillMinimum)
+							.text("Show")
+					)
+					
+					.child(InputModeSwitch(inputModeClientSecret))
+			)
+			
+			.child(
+				rect()
+					.content(Content::Flex)
+					.direction(Direction::Horizontal)
+					.main_align(Alignment::Center)
+					.spacing(10.0)
+					.width(Size::percent(75.0))
+					
+					.child(
+						Button::new()
+							.on_press(move |_| saveClientAuth(clientId.read().clone(), clientSecret.read().clone()))
+							.child("Save Credentials")
+					)
+					
+					.child(
+						Button::new()
+							.on_press(move |_| {
+								saveClientAuth(clientId.read().clone(), clientSecret.read().clone());
+								
+								spawn(async move {
+									match EgsApi::authorize().await
+									{
+										Err(e) => warn!("[EpicGamesStore] Error logging in: {:?}", e),
+										Ok(_) => accountId.set(getEpicGamesStoreAccountId().unwrap_or_default()),
+									}
+									
+									sessionStatus.set(getEpicGamesStoreSessionStatus());
+								});
+							})
+							.child("Log In")
+					)
+					
+					.child(
+						Button::new()
+							.on_press(move |_| {
+								match removeEpicGamesStoreSession()
+								{
+									Err(e) => warn!("[EpicGamesStore] Error removing session data: {:?}", e),
+									Ok(_) => info!("[EpicGamesStore] Session data removed"),
+								}
+								
+								sessionStatus.set(getEpicGamesStoreSessionStatus());
+							})
+							.child("Clear Session")
+					)
 			);
 	}
 }
@@ -95,3 +260,28 @@ impl EgsSettingsElement
 		return self;
 	}
 }
+
+/**
+Save the Epic Account Services client credentials to the vault.
+
+Empty values are skipped so that clearing an input does not erase the stored
+credential.
+*/
+fn saveClientAuth(id: String, secret: String)
+{
+	if !id.is_empty()
+	{
+		if let Err(e) = setEpicGamesStoreClientId(id)
+		{
+			warn!("[EpicGamesStore] Error saving client ID: {:?}", e);
+		}
+	}
+	
+	if !secret.is_empty()
+	{
+		if let Err(e) = setEpicGamesStoreClientSecret(secret)
+		{
+			warn!("[EpicGamesStore] Error saving client secret: {:?}", e);
+		}
+	}
+}
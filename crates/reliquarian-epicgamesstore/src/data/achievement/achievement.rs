@@ -4,6 +4,9 @@ use anyhow::{Result, anyhow};
 use chrono::{DateTime, NaiveDateTime, Utc};
 use chrono::serde::ts_milliseconds_option;
 use data::constants::Format_ChronoDateTime;
+use data::enums::GamePlatforms;
+use data::unified::{EgsExtension, Unifiable, UnifiedAchievement,
+	UnifiedExtension};
 use serde::{Deserialize, Serialize};
 use serde_json::{Map, Value};
 use crate::api::{Achievement, PlayerAchievementContainer};
@@ -134,6 +137,36 @@ impl PartialOrd for EgsAchievement
 	}
 }
 
+impl Unifiable<UnifiedAchievement> for EgsAchievement
+{
+	fn unify(&self) -> UnifiedAchievement
+	{
+		let strings = match self.isUnlocked
+		{
+			false => &self.locked,
+			true => &self.unlocked,
+		};
+		
+		return UnifiedAchievement
+		{
+			description: strings.description.clone(),
+			extension: UnifiedExtension::EpicGamesStore(EgsExtension
+			{
+				tierColor: self.tier.color.clone(),
+				tierName: self.tier.name.clone(),
+				xp: self.xp,
+			}),
+			hidden: self.hidden,
+			id: self.id.clone(),
+			name: strings.name.clone(),
+			platform: GamePlatforms::EpicGamesStore,
+			rarity: Some(self.rarity as f64 / 10.0),
+			unlocked: self.isUnlocked,
+			unlockedTimestamp: self.dateUnlocked,
+		};
+	}
+}
+
 impl EgsAchievement
 {
 	const DateUnlockedFormat: &str = "%FT%T.%3fZ";
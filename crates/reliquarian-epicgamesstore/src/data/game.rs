@@ -1,8 +1,13 @@
+use data::constants::{FileName_GameIcon, Path_Games};
+use data::enums::GamePlatforms;
 use data::filter::{FilterCriteria, Filterable};
 use data::format::truncateF32;
+use data::io::FileLocation;
+use data::unified::{Unifiable, UnifiedGame};
+use macros::{join, jpg};
 use serde::{Deserialize, Serialize};
 use serde_json::{Map, Value};
-use crate::api::AchievementSummary;
+use crate::api::{AchievementSummary, EgsApi};
 use super::achievement::EgsAchievement;
 use super::set::AchievementSet;
 
@@ -138,6 +143,34 @@ impl PartialOrd for EgsGame
 	}
 }
 
+impl Unifiable<UnifiedGame> for EgsGame
+{
+	fn unify(&self) -> UnifiedGame
+	{
+		let mut game = UnifiedGame::new(
+			GamePlatforms::EpicGamesStore,
+			self.sandboxId.clone(),
+			self.name.clone(),
+			self.achievements.iter()
+				.map(|a| a.unify())
+				.collect()
+		);
+		
+		// The summary counts are available before the achievements are retrieved
+		game.achievementsTotal = self.achievementsCount;
+		game.achievementsUnlocked = self.achievementsUnlocked;
+		
+		game.icon = Some(FileLocation
+		{
+			fileName: jpg!(FileName_GameIcon),
+			group: join!(Path_Games, self.sandboxId),
+			platform: EgsApi::Platform.to_lowercase(),
+		});
+		
+		return game;
+	}
+}
+
 impl EgsGame
 {
 	pub fn parseJsonMap(map: &Map<String, Value>) -> Option<Self>
@@ -3,6 +3,7 @@ use anyhow::{Error, anyhow};
 use data::enums::GamePlatforms;
 use net::{DataOperation, DataRequest};
 use strum_macros::{AsRefStr, EnumString};
+use crate::secure::getEpicGamesStoreSession;
 
 #[derive(AsRefStr, Clone, Debug, EnumString, Eq, PartialEq, PartialOrd, Ord)]
 pub enum EgsOperation
@@ -11,6 +12,8 @@ pub enum EgsOperation
 	GetAchievementProgress(String),
 	GetPlayerProfile,
 	GetPlayerProfilePrivate,
+	/// Retrieve the account of the signed in user.
+	GetUserInfo,
 	SaveToFile,
 }
 
@@ -30,6 +33,7 @@ impl From<EgsOperation> for DataOperation
 			
 			EgsOperation::GetPlayerProfile
 				| EgsOperation::GetPlayerProfilePrivate
+				| EgsOperation::GetUserInfo
 			=> DataOperation::Platform(
 				GamePlatforms::EpicGamesStore,
 				value.as_ref().to_string()
@@ -67,6 +71,7 @@ impl TryFrom<DataOperation> for EgsOperation
 					{
 						Self::GetPlayerProfile => Ok(Self::GetPlayerProfile),
 						Self::GetPlayerProfilePrivate => Ok(Self::GetPlayerProfilePrivate),
+						Self::GetUserInfo => Ok(Self::GetUserInfo),
 						_ => Err(anyhow!("Invalid Epic Games Store operation")),
 					},
 					
@@ -97,3 +102,28 @@ impl TryFrom<DataOperation> for EgsOperation
 		};
 	}
 }
+
+impl EgsOperation
+{
+	/**
+	The operations which refresh the user's profile and progress.
+	
+	While signed in, the account of the signed in user is retrieved first, so
+	the public profile operations follow it. Expired sessions are refreshed
+	when the account is requested.
+	*/
+	pub fn refreshRequests() -> Vec<DataRequest>
+	{
+		let mut requests = vec![];
+		
+		if getEpicGamesStoreSession().is_ok_and(|session| !session.requiresReauthentication())
+		{
+			requests.push(Self::GetUserInfo.into());
+		}
+		
+		requests.push(Self::GetPlayerProfile.into());
+		requests.push(Self::GetPlayerProfilePrivate.into());
+		
+		return requests;
+	}
+}
@@ -4,6 +4,8 @@ use super::user::EgsUser;
 #[derive(Clone, Debug)]
 pub struct EgsOperationResult
 {
+	/// Whether the operation failed to retrieve the data it was meant to refresh.
+	pub failed: bool,
 	pub user: EgsUser,
 	pub requests: Vec<DataRequest>,
 }
@@ -14,8 +16,23 @@ impl From<EgsUser> for EgsOperationResult
 	{
 		return Self
 		{
+			failed: false,
 			user: value,
 			requests: Default::default(),
 		};
 	}
 }
+
+impl EgsOperationResult
+{
+	/// A result which leaves the user unchanged and marks the operation as failed.
+	pub fn failure(user: EgsUser) -> Self
+	{
+		return Self
+		{
+			failed: true,
+			user,
+			requests: Default::default(),
+		};
+	}
+}
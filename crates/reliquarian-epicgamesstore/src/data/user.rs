@@ -1,10 +1,14 @@
 use anyhow::Result;
 use data::enums::GamePlatforms;
 use data::filter::{FilterCriteria, Filterable};
+use data::migration::Versioned;
+use data::storage::Storable;
+use data::unified::{Unifiable, UnifiedGame};
 use freya::radio::RadioChannel;
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
-use crate::api::{Payload_PlayerProfile, Payload_PlayerProfilePrivate};
+use crate::api::{Payload_PlayerProfile, Payload_PlayerProfilePrivate,
+	Payload_UserInfo};
 use crate::data::achievement::EgsAchievement;
 use super::game::EgsGame;
 
@@ -50,6 +54,30 @@ impl Filterable<EgsGame> for EgsUser
 
 impl RadioChannel<EgsUser> for GamePlatforms {}
 
+impl Storable for EgsUser
+{
+	const DataFileName: &'static str = EgsUser::FileName;
+	const GameIdKey: &'static str = "sandboxId";
+	const Platform: GamePlatforms = GamePlatforms::EpicGamesStore;
+	
+	fn parseLossy(json: String) -> Result<Self>
+	{
+		return EgsUser::parseJsonLossy(json);
+	}
+}
+
+impl Unifiable<Vec<UnifiedGame>> for EgsUser
+{
+	fn unify(&self) -> Vec<UnifiedGame>
+	{
+		return self.games.iter()
+			.map(|g| g.unify())
+			.collect();
+	}
+}
+
+impl Versioned for EgsUser {}
+
 impl EgsUser
 {
 	pub const FileName: &str = "egs.json";
@@ -156,6 +184,22 @@ impl EgsUser
 			}
 		}
 	}
+	
+	/**
+	Follow the account of the signed in user.
+	
+	The display name is left for the public profile to fill in when Epic does
+	not report one.
+	*/
+	pub fn updateUserInfo(&mut self, payload: Payload_UserInfo)
+	{
+		self.accountId = payload.sub;
+		
+		if !payload.preferred_username.is_empty()
+		{
+			self.name = payload.preferred_username;
+		}
+	}
 }
 
 #[cfg(test)]
@@ -1,5 +1,7 @@
 use anyhow::Result;
 use data::Secrets;
+use net::SessionStatus;
+use crate::api::{EgsAuth, EgsSession};
 use crate::data::settings::EgsSettings;
 
 pub fn getEpicGamesStoreAccountId() -> Result<String>
@@ -9,6 +11,40 @@ pub fn getEpicGamesStoreAccountId() -> Result<String>
 	return Ok(id);
 }
 
+pub fn getEpicGamesStoreClientAuth() -> Result<EgsAuth>
+{
+	let secrets = Secrets.blocking_lock();
+	let id = secrets.get(EgsAuth::ClientIdKey)?;
+	let key = secrets.get(EgsAuth::ClientSecretKey)?;
+	return Ok(EgsAuth::new(id, key));
+}
+
+pub fn getEpicGamesStoreSession() -> Result<EgsSession>
+{
+	let secrets = Secrets.blocking_lock();
+	let json = secrets.get(EgsSession::SecretKey)?;
+	return Ok(serde_json::from_str(&json)?);
+}
+
+pub fn getEpicGamesStoreSessionStatus() -> SessionStatus
+{
+	return match getEpicGamesStoreSession()
+	{
+		Err(_) => SessionStatus::Missing,
+		Ok(session) => session.status(),
+	};
+}
+
+/**
+Flag the stored session as requiring the user to sign in again.
+*/
+pub fn invalidateEpicGamesStoreSession() -> Result<()>
+{
+	let mut session = getEpicGamesStoreSession()?;
+	session.invalidate();
+	return setEpicGamesStoreSession(session);
+}
+
 #[allow(unused)]
 pub fn removeEpicGamesStoreAccountId() -> Result<()>
 {
@@ -25,3 +61,35 @@ pub fn setEpicGamesStoreAccountId(id: String) -> Result<()>
 	_ = secrets.save()?;
 	return Ok(());
 }
+
+pub fn removeEpicGamesStoreSession() -> Result<()>
+{
+	let mut secrets = Secrets.blocking_lock();
+	secrets.remove(EgsSession::SecretKey)?;
+	_ = secrets.save()?;
+	return Ok(());
+}
+
+pub fn setEpicGamesStoreClientId(id: String) -> Result<()>
+{
+	let mut secrets = Secrets.blocking_lock();
+	secrets.set(EgsAuth::ClientIdKey, id);
+	_ = secrets.save()?;
+	return Ok(());
+}
+
+pub fn setEpicGamesStoreClientSecret(secret: String) -> Result<()>
+{
+	let mut secrets = Secrets.blocking_lock();
+	secrets.set(EgsAuth::ClientSecretKey, secret);
+	_ = secrets.save()?;
+	return Ok(());
+}
+
+pub fn setEpicGamesStoreSession(session: EgsSession) -> Result<()>
+{
+	let mut secrets = Secrets.blocking_lock();
+	secrets.set(EgsSession::SecretKey, serde_json::to_string(&session)?);
+	_ = secrets.save()?;
+	return Ok(());
+}
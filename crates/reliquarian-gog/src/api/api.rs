@@ -8,9 +8,10 @@ use regex::Regex;
 use reqwest::Url;
 use reqwest::header::AUTHORIZATION;
 use serde::de::DeserializeOwned;
-use tracing::error;
-use crate::secure::setGogSession;
+use tracing::{error, info, warn};
+use crate::secure::{invalidateGogSession, setGogSession};
 use super::{FilteredProductsPage, GogSession, Payload_Achievements, UserInfo};
+use super::endpoint::gameplay::AchievementsParameters;
 use super::endpoint::listing::FilteredProductsParameters;
 use super::endpoint::auth::{AuthParameters, TokenAuthParameters,
 	TokenRefreshParameters, TokenResponse};
@@ -38,6 +39,9 @@ impl GogApi
 	const GameIcon_Suffix: &str = "_product_tile_117h_2x.jpg";
 	const MediaType_Game: u64 = 1;
 	
+	const Status_BadRequest: u16 = 400;
+	const Status_Unauthorized: u16 = 401;
+	
 	pub const AuthCodeUrlRegex: &str = r"https\:\/\/embed\.gog\.com\/on_login_success\?origin\=client\&code\=(?P<code>.*)";
 	pub const Platform: &str = "GOG";
 	
@@ -102,18 +106,27 @@ impl GogApi
 	}
 	
 	/**
-	Gets the achievements list for a product.
+	Gets a page of the achievements list for a product.
+	
+	The first page is requested when `pageToken` is `None`. Subsequent pages
+	are requested using the `page_token` returned with the previous page.
 	*/
-	pub fn getAchievements(session: &GogSession, userId: String, productId: u64) -> Result<Payload_Achievements>
+	pub fn getAchievements(session: &GogSession, userId: String, productId: u64, pageToken: Option<String>) -> Result<Payload_Achievements>
 	{
 		let finalEndpoint = Self::AchievementsEndpoint
 			.replace("[productId]", &productId.to_string())
 			.replace("[userId]", &userId.clone());
 		
+		let params = AchievementsParameters
+		{
+			page_token: pageToken,
+		};
+		
 		let url = Url::from_str(&format!(
-			"{}{}",
+			"{}{}?{}",
 			Self::GameplayHost,
-			finalEndpoint
+			finalEndpoint,
+			serde_url_params::to_string(&params)?
 		))?;
 		
 		let response = Self::get::<Payload_Achievements>(url, session)?;
@@ -176,19 +189,73 @@ impl GogApi
 	
 	/**
 	Execute an HTTP GET request.
+	
+	An expired session is refreshed before the request is sent. If GOG rejects
+	the access token regardless, the session is refreshed and the request is
+	retried once.
+	
+	Returns `Err` if the session requires the user to log in again.
 	*/
 	fn get<T>(url: Url, session: &GogSession) -> Result<T>
 		where T: DeserializeOwned
 	{
-		let response = ureq::get(url.to_string())
+		if session.requiresReauthentication()
+		{
+			return Err(anyhow!("[GOG] Session requires re-authentication"));
+		}
+		
+		let session = match session.hasExpired()
+		{
+			false => session.clone(),
+			true => {
+				info!("[GOG] Session has expired; Refreshing before sending request");
+				Self::refreshAccessToken(session.refreshToken().clone())?
+			},
+		};
+		
+		return Self::sendWithRetry(
+			session,
+			|session| Self::sendRequest::<T>(&url, session),
+			|session| Self::refreshAccessToken(session.refreshToken().clone())
+		)
+			.context("Error retrieving GOG API response");
+	}
+	
+	/**
+	Send a request using `session`. If GOG rejects the access token, refresh the
+	session and send the request once more.
+	
+	## Parameters
+	- session: `GogSession` The session whose access token authorizes the request.
+	- send: `impl Fn(&GogSession) -> Result<T, ureq::Error>` Sends the request.
+	- refresh: `impl FnOnce(&GogSession) -> Result<GogSession>` Refreshes the rejected session.
+	*/
+	fn sendWithRetry<T>(
+		session: GogSession,
+		send: impl Fn(&GogSession) -> Result<T, ureq::Error>,
+		refresh: impl FnOnce(&GogSession) -> Result<GogSession>,
+	) -> Result<T>
+	{
+		return match send(&session)
+		{
+			Err(ureq::Error::StatusCode(Self::Status_Unauthorized)) => {
+				info!("[GOG] Access token was rejected; Refreshing session and retrying request");
+				let session = refresh(&session)?;
+				Ok(send(&session)?)
+			},
+			
+			result => Ok(result?),
+		};
+	}
+	
+	fn sendRequest<T>(url: &Url, session: &GogSession) -> Result<T, ureq::Error>
+		where T: DeserializeOwned
+	{
+		return ureq::get(url.to_string())
 			.header(AUTHORIZATION, format!("Bearer {}", session.accessToken()))
-			.call()
-				.context("Error retrieving GOG API response")?
+			.call()?
 			.body_mut()
-			.read_json::<T>()
-				.context("Error parsing GOG API response as JSON")?;
-		
-		return Ok(response);
+			.read_json::<T>();
 	}
 	
 	/**
@@ -228,7 +295,13 @@ impl GogApi
 		};
 	}
 	
-	pub fn refreshAccessToken(refreshToken: String) -> Result<()>
+	/**
+	Exchange the refresh token for a new session, saving it to the vault.
+	
+	If GOG rejects the refresh token, the stored session is flagged as
+	requiring the user to log in again.
+	*/
+	pub fn refreshAccessToken(refreshToken: String) -> Result<GogSession>
 	{
 		if refreshToken.is_empty()
 		{
@@ -249,18 +322,131 @@ impl GogApi
 			serde_url_params::to_string(&refreshParams)?
 		))?;
 		
-		let tokenResponse = ureq::get(url.to_string())
-			.call()
-				.context("[GOG] Error sending request to GOG")?
+		let tokenResponse = match ureq::get(url.to_string()).call()
+		{
+			Err(ureq::Error::StatusCode(status))
+				if status == Self::Status_BadRequest || status == Self::Status_Unauthorized
+			=> {
+				if let Err(e) = invalidateGogSession()
+				{
+					warn!("[GOG] Error flagging session for re-authentication: {:?}", e);
+				}
+				
+				return Err(anyhow!("[GOG] Refresh token was rejected; Re-authentication required"));
+			},
+			
+			result => result.context("[GOG] Error sending request to GOG")?,
+		}
 			.body_mut()
 			.read_json::<TokenResponse>()
 				.context("[GOG] Error parsing resopnse into JSON")?;
 		
-		let session = tokenResponse.into();
+		let session: GogSession = tokenResponse.into();
 		
-		setGogSession(session)
+		setGogSession(session.clone())
 			.context("[GOG] Error saving session data to vauld")?;
 		
-		return Ok(());
+		return Ok(session);
+	}
+}
+
+#[cfg(test)]
+mod tests
+{
+	use std::cell::Cell;
+	use super::*;
+	
+	const SessionJson: &str = r#"{
+	"accessToken": "rejected",
+	"expiresIn": 3600,
+	"refreshToken": "refresh",
+	"sessionId": "session",
+	"timestamp": "2025-11-19T09:09:08Z",
+	"userId": "user"
+}"#;
+	
+	const RefreshedJson: &str = r#"{
+	"accessToken": "accepted",
+	"expiresIn": 3600,
+	"refreshToken": "refresh",
+	"sessionId": "session",
+	"timestamp": "2025-11-19T09:09:08Z",
+	"userId": "user"
+}"#;
+	
+	fn parseSession(json: &str) -> GogSession
+	{
+		return serde_json::from_str(json).unwrap();
+	}
+	
+	#[test]
+	fn sendWithRetry()
+	{
+		let refreshes = Cell::new(0);
+		let sends = Cell::new(0);
+		
+		// A rejected access token is refreshed and the request is retried
+		let result = GogApi::sendWithRetry(
+			parseSession(SessionJson),
+			|session| {
+				sends.set(sends.get() + 1);
+				match session.accessToken().as_str()
+				{
+					"accepted" => Ok(7),
+					_ => Err(ureq::Error::StatusCode(401)),
+				}
+			},
+			|_| {
+				refreshes.set(refreshes.get() + 1);
+				Ok(parseSession(RefreshedJson))
+			}
+		);
+		
+		assert_eq!(result.unwrap(), 7);
+		assert_eq!((sends.get(), refreshes.get()), (2, 1));
+		
+		// The request is only retried once
+		sends.set(0);
+		let result = GogApi::sendWithRetry::<u64>(
+			parseSession(SessionJson),
+			|_| {
+				sends.set(sends.get() + 1);
+				Err(ureq::Error::StatusCode(401))
+			},
+			|session| Ok(session.clone())
+		);
+		
+		assert!(result.is_err());
+		assert_eq!(sends.get(), 2);
+		
+		// Other errors, and a rejected refresh token, are returned without retrying
+		sends.set(0);
+		refreshes.set(0);
+		let result = GogApi::sendWithRetry::<u64>(
+			parseSession(SessionJson),
+			|_| {
+				sends.set(sends.get() + 1);
+				Err(ureq::Error::StatusCode(500))
+			},
+			|session| {
+				refreshes.set(refreshes.get() + 1);
+				Ok(session.clone())
+			}
+		);
+		
+		assert!(result.is_err());
+		assert_eq!((sends.get(), refreshes.get()), (1, 0));
+		
+		let result = GogApi::sendWithRetry::<u64>(
+			parseSession(SessionJson),
+			|_| {
+				sends.set(sends.get() + 1);
+				Err(ureq::Error::StatusCode(401))
+			},
+			|_| Err(anyhow!("Refresh token was rejected"))
+		);
+		
+		assert!(result.is_err());
+		assert_eq!(sends.get(), 2);
 	}
 }
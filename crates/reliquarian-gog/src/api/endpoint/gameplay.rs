@@ -11,6 +11,18 @@ pub struct AchievementMetadata
 	pub image_url_unlocked: String,
 	pub image_url_locked: String,
 	pub date_unlocked: Option<String>,
+	#[serde(default)]
+	pub rarity: Option<f64>,
+	#[serde(default)]
+	pub rarity_level_description: Option<String>,
+	#[serde(default)]
+	pub rarity_level_slug: Option<String>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct AchievementsParameters
+{
+	pub page_token: Option<String>,
 }
 
 #[allow(non_camel_case_types)]
@@ -19,6 +31,7 @@ pub struct Payload_Achievements
 {
 	pub total_count: u64,
 	pub limit: u64,
+	#[serde(default)]
 	pub page_token: String,
 	pub items: Vec<AchievementMetadata>,
 }
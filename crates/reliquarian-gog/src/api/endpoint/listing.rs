@@ -65,7 +65,7 @@ pub struct Product
 	pub updates: u64,
 	pub url: String,
 	#[serde(default)]
-	pub worksOk: Option<ProductWorksOn>,
+	pub worksOn: Option<ProductWorksOn>,
 }
 
 #[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
@@ -5,7 +5,7 @@ mod session;
 pub use api::GogApi;
 pub use endpoint::{
 	gameplay::{AchievementMetadata, Payload_Achievements},
-	listing::{FilteredProductsPage, Product},
+	listing::{FilteredProductsPage, Product, ProductUserTag, ProductWorksOn},
 	users::UserInfo,
 };
 pub use session::GogSession;
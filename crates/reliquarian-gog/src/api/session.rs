@@ -1,5 +1,6 @@
 use std::fmt::{Debug, Display, Formatter};
 use chrono::{DateTime, Utc};
+use net::{Reauthentication, SessionStatus};
 use serde::{Deserialize, Serialize};
 use super::endpoint::auth::TokenResponse;
 
@@ -8,6 +9,11 @@ pub struct GogSession
 {
 	accessToken: String,
 	expiresIn: u64,
+	
+	/// Set when GOG rejects the refresh token, requiring the user to log in again.
+	#[serde(default)]
+	reauthenticate: Reauthentication,
+	
 	refreshToken: String,
 	sessionId: String,
 	timestamp: DateTime<Utc>,
@@ -38,6 +44,7 @@ impl From<TokenResponse> for GogSession
 		{
 			accessToken: value.access_token.clone(),
 			expiresIn: value.expires_in.clone(),
+			reauthenticate: Reauthentication::default(),
 			refreshToken: value.refresh_token.clone(),
 			sessionId: value.session_id.clone(),
 			timestamp: Utc::now(),
@@ -63,6 +70,21 @@ impl GogSession
 		};
 	}
 	
+	pub fn invalidate(&mut self)
+	{
+		self.reauthenticate.invalidate();
+	}
+	
+	pub fn requiresReauthentication(&self) -> bool
+	{
+		return self.reauthenticate.isRequired();
+	}
+	
+	pub fn status(&self) -> SessionStatus
+	{
+		return self.reauthenticate.status();
+	}
+	
 	pub fn accessToken(&self) -> &String
 	{
 		return &self.accessToken;
@@ -97,3 +119,36 @@ impl GogSession
 		return &self.userId;
 	}
 }
+
+#[cfg(test)]
+mod tests
+{
+	use super::*;
+	
+	const StoredJson: &str = r#"{
+	"accessToken": "access",
+	"expiresIn": 3600,
+	"refreshToken": "refresh",
+	"sessionId": "session",
+	"timestamp": "2025-11-19T09:09:08Z",
+	"userId": "user"
+}"#;
+	
+	#[test]
+	fn invalidate()
+	{
+		// Sessions stored before re-authentication was tracked remain usable
+		let mut session = serde_json::from_str::<GogSession>(StoredJson).unwrap();
+		assert!(session.hasExpired());
+		assert!(!session.requiresReauthentication());
+		assert_eq!(session.status(), SessionStatus::Active);
+		
+		session.invalidate();
+		assert!(session.requiresReauthentication());
+		assert_eq!(session.status(), SessionStatus::ReauthenticationRequired);
+		
+		let json = serde_json::to_string(&session).unwrap();
+		let session = serde_json::from_str::<GogSession>(&json).unwrap();
+		assert!(session.requiresReauthentication());
+	}
+}
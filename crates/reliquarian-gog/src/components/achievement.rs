@@ -1,10 +1,12 @@
 use std::path::PathBuf;
 use data::constants::{BorderColor, CornerRadius, Icon_Locked, Path_Games};
-use data::enums::GamePlatforms;
+use data::enums::{DataChannel, GamePlatforms};
 use data::io::{FileLocation, filePathExists, getImagePath};
-use freya::prelude::{Alignment, Border, BorderAlignment, ChildrenExt, Component,
-	ContainerExt, ContainerSizeExt, ContainerWithContentExt, Content, Direction,
-	Gaps, ImageViewer, IntoElement, Size, StyleExt, TextStyleExt, label, rect};
+use data::settings::AppSettings;
+use freya::prelude::{Alignment, Border, BorderAlignment, ChildrenExt, Color,
+	Component, ContainerExt, ContainerSizeExt, ContainerWithContentExt, Content,
+	Direction, Gaps, ImageViewer, IntoElement, Size, Span, StyleExt, TextAlign,
+	TextStyleExt, label, paragraph, rect};
 use freya::radio::use_radio;
 use macros::{join, jpg, jpgAlt};
 use crate::api::GogApi;
@@ -21,6 +23,7 @@ impl Component for AchievementElement
 {
 	fn render(&self) -> impl IntoElement
 	{
+		let appSettings = use_radio::<AppSettings, DataChannel>(DataChannel::Settings);
 		let user = use_radio::<GogUser, GamePlatforms>(GamePlatforms::Gog);
 		
 		let achievement = user.read()
@@ -43,6 +46,14 @@ impl Component for AchievementElement
 		let timestamp = achievement.formatEarnedTimestamp()
 			.unwrap_or_default();
 		
+		let tier = achievement.rarityTier(&appSettings.read().rarity);
+		
+		let rarity = match achievement.rarity
+		{
+			None => Default::default(),
+			Some(percent) => format!("{}% of players have this achievement", percent),
+		};
+		
 		let showIcon = filePathExists(&iconPath);
 		
 		return rect()
@@ -96,10 +107,39 @@ impl Component for AchievementElement
 							)
 							
 							.child(
-								label()
-									.font_size(10.0)
-									.text(timestamp)
+								rect()
+									.content(Content::Flex)
+									.direction(Direction::Horizontal)
+									.main_align(Alignment::SpaceBetween)
 									.width(Size::flex(1.0))
+									
+									.child(
+										label()
+											.font_size(10.0)
+											.text_align(TextAlign::Start)
+											.text(timestamp)
+											.width(Size::flex(0.5))
+									)
+									
+									.child(
+										paragraph()
+											.text_align(TextAlign::End)
+											.width(Size::flex(0.5))
+											
+											.spans_iter(
+												achievement.rarityLevel.map(|level|
+													Span::new(format!("{} ", level))
+														.color(tier.map(|t| t.color()).unwrap_or(Color::GRAY))
+														.font_size(10.0)
+												).into_iter()
+											)
+											
+											.span(
+												Span::new(rarity)
+													.color(tier.map(|t| t.color()).unwrap_or(Color::GRAY))
+													.font_size(10.0)
+											)
+									)
 							)
 					)
 			);
@@ -1,9 +1,11 @@
 use std::path::PathBuf;
 use components::button::icon::IconButton;
+use components::export::promptExport;
 use components::input::filter::AchievementsFilter;
 use components::overlay::refresh::ConfirmRefresh;
 use data::constants::{CornerRadius, FileName_GameIcon, Path_Games};
 use data::enums::{DataChannel, GamePlatforms};
+use data::export::AchievementExport;
 use data::filter::{FilterCriteria, Filterable};
 use data::io::{FileLocation, filePathExists, getImagePath};
 use freya::icons::lucide;
@@ -49,14 +51,16 @@ impl Component for GameElement
 		let game = user.read().getGame(self.gameId)
 			.unwrap_or_default();
 		
-		let achievements = game.filter(FilterCriteria
+		let criteria = FilterCriteria
 		{
 			caseSensitive: caseSensitive(),
 			locked: locked(),
 			nameOnly: nameOnly(),
 			text: search.read().clone(),
 			..Default::default()
-		});
+		};
+		
+		let achievements = game.filter(criteria.clone());
 		
 		let achievementsListLength = achievements.len();
 		
@@ -141,6 +145,18 @@ impl Component for GameElement
 							.width(Size::flex(1.0))
 					)
 					
+					.child(
+						IconButton::new(lucide::download())
+							.alt("Export")
+							.onPress(move |_| if let Some(game) = user.read().getGame(gameId)
+							{
+								let export = AchievementExport::fromFilteredAchievements(&game, criteria.clone());
+								spawn(async move {
+									promptExport(export, &game.name).await;
+								});
+							})
+					)
+					
 					.child(
 						IconButton::new(lucide::refresh_ccw())
 							.alt("Refresh")
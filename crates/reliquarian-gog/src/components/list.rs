@@ -1,19 +1,24 @@
 use std::path::PathBuf;
+use components::button::icon::IconButton;
+use components::export::promptExport;
 use components::extensions::PressableExt;
 use components::input::filter::GamesFilter;
 use data::constants::{BorderColor, ButtonBackgroundColor, CornerRadius,
-	FileName_GameIcon, GogProgressColor, Path_Games,
+	FileName_GameIcon, GogProgressColor, LinkBlue, Path_Games,
 	RetroAchievementsProgressColorBackground};
 use data::enums::{DataChannel, GamePlatforms};
+use data::export::AchievementExport;
 use data::filter::{FilterCriteria, Filterable};
 use data::io::{FileLocation, filePathExists, getImagePath};
 use data::settings::AppSettings;
+use freya::icons::lucide;
 use freya::prelude::{Alignment, Border, BorderAlignment, ChildrenExt, Code,
 	Color, Component, ContainerExt, ContainerSizeExt, ContainerWithContentExt,
 	Content, Direction, Event, EventHandlersExt, FontWeight, Gaps, ImageViewer,
 	IntoElement, KeyboardEventData, ProgressBar, ProgressBarThemePartialExt,
 	ScrollConfig, ScrollPosition, Size, Span, StyleExt, TextAlign, TextStyleExt,
-	VirtualScrollView, label, paragraph, rect, use_scroll_controller, use_state};
+	VirtualScrollView, label, paragraph, rect, spawn, use_scroll_controller,
+	use_state};
 use freya::radio::use_radio;
 use macros::{join, jpg};
 use crate::api::GogApi;
@@ -31,16 +36,26 @@ impl Component for GameList
 		
 		let mut scrollController = use_scroll_controller(ScrollConfig::default);
 		let caseSensitive = use_state(bool::default);
+		let dlc = use_state(bool::default);
+		let hideHidden = use_state(bool::default);
+		let linuxNative = use_state(bool::default);
 		let search = use_state(String::default);
 		let showAll = use_state(|| appSettings.read().displayGamesWithoutAchievements);
+		let tag = use_state(Option::<String>::default);
 		
-		let games = user.read().filter(FilterCriteria
+		let criteria = FilterCriteria
 		{
 			caseSensitive: caseSensitive(),
+			dlc: dlc(),
+			hideHidden: hideHidden(),
+			linuxNative: linuxNative(),
 			showAll: showAll(),
+			tag: tag.read().clone(),
 			text: search.read().clone(),
 			..Default::default()
-		});
+		};
+		
+		let games = user.read().filter(criteria.clone());
 		
 		let gamesLength = games.len();
 		
@@ -69,10 +84,34 @@ impl Component for GameList
 			)
 			
 			.child(
-				GamesFilter::new(caseSensitive, search)
+				rect()
+					.content(Content::Flex)
+					.cross_align(Alignment::Center)
+					.direction(Direction::Horizontal)
 					.margin(Gaps::new(5.0, 0.0, 0.0, 0.0))
-					.showAll(showAll)
+					.spacing(10.0)
 					.width(Size::percent(50.0))
+					
+					.child(
+						GamesFilter::new(caseSensitive, search)
+							.dlc(dlc)
+							.hideHidden(hideHidden)
+							.linuxNative(linuxNative)
+							.showAll(showAll)
+							.tag(tag, user.read().tags())
+							.width(Size::flex(1.0))
+					)
+					
+					.child(
+						IconButton::new(lucide::download())
+							.alt("Export")
+							.onPress(move |_| {
+								let export = AchievementExport::fromFilteredGames(&*user.read(), criteria.clone());
+								spawn(async move {
+									promptExport(export, "GOG").await;
+								});
+							})
+					)
 			)
 			
 			.child(
@@ -138,7 +177,9 @@ impl Component for GameListNode
 			.filter(|a| a.dateUnlocked.is_some())
 			.count();
 		
+		let details = game.details().join(" · ");
 		let name = game.name.clone();
+		let tags = game.tags.join(", ");
 		
 		let showIcon = filePathExists(&iconPath);
 		
@@ -189,6 +230,20 @@ impl Component for GameListNode
 									.font_size(18.0)
 									.text(name)
 							)
+							
+							.maybe_child((!details.is_empty()).then(||
+								label()
+									.color(Color::GREY)
+									.font_size(12.0)
+									.text(details)
+							))
+							
+							.maybe_child((!tags.is_empty()).then(||
+								label()
+									.color(LinkBlue)
+									.font_size(12.0)
+									.text(tags)
+							))
 					)
 					
 					.child(
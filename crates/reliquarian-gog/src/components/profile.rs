@@ -2,7 +2,7 @@ use std::path::PathBuf;
 use components::button::icon::IconButton;
 use components::overlay::refresh::ConfirmRefresh;
 use data::constants::{BorderColor, CornerRadius, OverlayBackgroundColor,
-	OverlayGreyoutColor, Path_Avatars};
+	OverlayGreyoutColor, Path_Avatars, WarningColor};
 use data::enums::{DataChannel, GamePlatforms};
 use data::io::{FileLocation, filePathExists, getImagePath};
 use freya::icons::lucide;
@@ -15,11 +15,11 @@ use freya::prelude::{Alignment, Border, BorderAlignment, BorderWidth, Button,
 use freya::radio::use_radio;
 use freya::winit::dpi::PhysicalSize;
 use macros::jpg;
-use net::{RateLimiter, RequestEvent};
+use net::{RateLimiter, RequestEvent, SessionStatus};
 use crate::api::GogApi;
 use crate::data::operation::GogOperation;
 use crate::data::user::GogUser;
-use crate::secure::getGogSession;
+use crate::secure::getGogSessionStatus;
 use super::refresh::{exchangeCode, openBrowserForAuthorization};
 
 #[derive(Clone, PartialEq)]
@@ -84,8 +84,9 @@ impl Component for GogUserProfile
 			}
 		});
 		
-		let validSession = getGogSession()
-			.is_ok_and(|s| !s.hasExpired());
+		// Expired sessions are refreshed automatically, so only a rejected refresh token requires logging in again
+		let sessionStatus = getGogSessionStatus();
+		let validSession = sessionStatus == SessionStatus::Active;
 		
 		return rect()
 			.border(Some(
@@ -117,7 +118,20 @@ impl Component for GogUserProfile
 					.main_align(Alignment::SpaceBetween)
 					.width(Size::flex(1.0))
 					
-					.child(username)
+					.child(
+						rect()
+							.direction(Direction::Vertical)
+							.spacing(5.0)
+							
+							.child(username)
+							
+							.maybe_child((sessionStatus == SessionStatus::ReauthenticationRequired).then(||
+								label()
+									.color(WarningColor)
+									.font_size(12.0)
+									.text("GOG rejected the saved session. Refresh to log in again.")
+							))
+					)
 					
 					.child(
 						IconButton::new(lucide::refresh_ccw())
@@ -195,7 +209,7 @@ impl Component for GogUserProfile
 									.main_align(Alignment::SpaceAround)
 									.width(Size::percent(100.0))
 									.spacing(5.0)
-							
+									
 									.child(
 										label()
 											.width(Size::percent(100.0))
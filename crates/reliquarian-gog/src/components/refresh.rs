@@ -1,17 +1,16 @@
-use anyhow::Result;
-use data::constants::{Icon_Locked, Path_Avatars, Path_Games};
+use anyhow::{Result, anyhow};
+use data::constants::Path_Avatars;
 use data::io::FileLocation;
 use freya::prelude::spawn;
-use macros::{join, jpg, jpgAlt};
+use macros::jpg;
 use net::{DataOperation, DataRequest};
 use tracing::{error, info, warn};
 use crate::api::{GogApi, GogSession};
-use crate::data::achievement::GogAchievement;
 use crate::data::io::saveUserData;
 use crate::data::operation::GogOperation;
 use crate::data::result::GogOperationResult;
 use crate::data::user::GogUser;
-use crate::secure::{getGogSession, removeGogSession, setGogSession};
+use crate::secure::{getGogSession, setGogSession};
 
 pub fn exchangeCode(url: String)
 {
@@ -36,26 +35,46 @@ pub async fn handleGogOperation(user: GogUser, dataOperation: DataOperation) ->
 		Ok(operation) => match operation
 		{
 			GogOperation::RefreshSession => {
-				_ = refreshSession();
-				info!("[GOG] Refreshed user session");
-				
-				None
+				// Return the user regardless so the UI reflects any change to the session status
+				Some(match refreshSession()
+				{
+					Err(e) => {
+						warn!("[GOG] Error refreshing user session: {:?}", e);
+						GogOperationResult::failure(user)
+					},
+					
+					Ok(_) => {
+						info!("[GOG] Refreshed user session");
+						user.into()
+					},
+				})
 			}
 			
 			GogOperation::GetAchievements(id) => match getGogSession()
 			{
-				Err(_) => None,
+				Err(_) => Some(GogOperationResult::failure(user)),
 				Ok(session) => {
-					let result = refreshGameAchievements(user, session, id);
+					let result = refreshGameAchievements(user, session, id, None);
 					info!("[GOG] Refreshed achievements for game id {}", id);
 					
 					Some(result)
 				}
 			}
 			
+			GogOperation::GetAchievementsPage(id, pageToken) => match getGogSession()
+			{
+				Err(_) => Some(GogOperationResult::failure(user)),
+				Ok(session) => {
+					let result = refreshGameAchievements(user, session, id, Some(pageToken));
+					info!("[GOG] Refreshed the next page of achievements for game id {}", id);
+					
+					Some(result)
+				}
+			}
+			
 			GogOperation::GetFilteredProducts(page) => match getGogSession()
 			{
-				Err(_) => None,
+				Err(_) => Some(GogOperationResult::failure(user)),
 				Ok(session) => {
 					let result = refreshGameList(user, session, page);
 					info!("[GOG] Refreshed game list page {}", match page
@@ -70,7 +89,7 @@ pub async fn handleGogOperation(user: GogUser, dataOperation: DataOperation) ->
 			
 			GogOperation::GetUserInfo => match getGogSession()
 			{
-				Err(_) => None,
+				Err(_) => Some(GogOperationResult::failure(user)),
 				Ok(session) => {
 					let result = refreshUserInfo(user, session);
 					info!("[GOG] Refreshed user info");
@@ -92,54 +111,49 @@ pub async fn handleGogOperation(user: GogUser, dataOperation: DataOperation) ->
 	};
 }
 
-fn refreshGameAchievements(mut user: GogUser, session: GogSession, gameId: u64) -> GogOperationResult
+fn refreshGameAchievements(mut user: GogUser, session: GogSession, gameId: u64, pageToken: Option<String>) -> GogOperationResult
 {
+	let mut failed = true;
 	let mut requests = vec![];
-	if let Ok(payload) = GogApi::getAchievements(
+	let firstPage = pageToken.is_none();
+	
+	match GogApi::getAchievements(
 		&session,
 		user.id.clone(),
-		gameId
+		gameId,
+		pageToken
 	)
 	{
-		let group = join!(Path_Games, gameId.to_string());
-		let platform = GogApi::Platform.to_lowercase();
+		Err(e) => warn!("[GOG] Error retrieving achievements for game id {}: {:?}", gameId, e),
 		
-		let mut achievements = vec![];
-		for metadata in payload.items
-		{
-			requests.push(DataRequest
-			{
-				destination: Some(FileLocation
-				{
-					fileName: jpgAlt!(metadata.achievement_id.clone(), Icon_Locked),
-					group: group.clone(),
-					platform: platform.clone(),
-				}),
-				operation: DataOperation::CacheImage(false),
-				url: Some(metadata.image_url_locked.clone()),
-				..Default::default()
-			});
+		Ok(payload) => {
+			failed = false;
 			
-			requests.push(DataRequest
+			match user.updateGameAchievementsPage(gameId, payload, firstPage)
 			{
-				destination: Some(FileLocation
-				{
-					fileName: jpg!(metadata.achievement_id.clone()),
-					group: group.clone(),
-					platform: platform.clone(),
-				}),
-				operation: DataOperation::CacheImage(false),
-				url: Some(metadata.image_url_unlocked.clone()),
-			});
-			
-			achievements.push(GogAchievement::from(metadata));
-		}
-		
-		user.updateGameAchievements(gameId, achievements);
+				Some(token) => requests.push(GogOperation::GetAchievementsPage(gameId, token).into()),
+				
+				None => {
+					// Every page has been stored, so cache the icons of the whole set
+					if let Some(game) = user.getGame(gameId)
+					{
+						requests.extend(game.achievements.iter()
+							.flat_map(|a| a.iconRequests(gameId)));
+					}
+					
+					// The first page is saved by whichever request queued it
+					if !firstPage
+					{
+						requests.push(GogOperation::SaveToFile.into());
+					}
+				},
+			}
+		},
 	}
 	
 	return GogOperationResult
 	{
+		failed,
 		user,
 		requests
 	};
@@ -148,22 +162,16 @@ fn refreshGameAchievements(mut user: GogUser, session: GogSession, gameId: u64)
 fn refreshSession() -> Result<()>
 {
 	let session = getGogSession()?;
+	if session.requiresReauthentication()
+	{
+		return Err(anyhow!("The session must be re-authenticated"));
+	}
+	
 	if session.hasExpired()
 	{
 		info!("[GOG] Session has expired; Attempting refresh");
-		match GogApi::refreshAccessToken(session.refreshToken().clone())
-		{
-			Err(e) => {
-				warn!("[GOG] Session invalidated; Error refreshing session: {:?}", e);
-				match removeGogSession()
-				{
-					Err(e) => warn!("[GOG] Failed to remove session file: {:?}", e),
-					Ok(_) => info!("[GOG] Removed expired session file"),
-				}
-			},
-			
-			Ok(_) => info!("[GOG] Session refreshed successfully"),
-		}
+		GogApi::refreshAccessToken(session.refreshToken().clone())?;
+		info!("[GOG] Session refreshed successfully");
 	}
 	
 	return Ok(());
@@ -192,6 +200,7 @@ fn refreshUserInfo(mut user: GogUser, session: GogSession) -> GogOperationResult
 	
 	return GogOperationResult
 	{
+		failed: request.is_none(),
 		user,
 		requests: match request
 		{
@@ -203,11 +212,13 @@ fn refreshUserInfo(mut user: GogUser, session: GogSession) -> GogOperationResult
 
 fn refreshGameList(mut user: GogUser, session: GogSession, page: Option<u64>) -> GogOperationResult
 {
+	let mut failed = true;
 	let mut requests = vec![];
 	
 	if let Ok(payload) = GogApi::getFilteredProducts(&session, page)
 	{
-		user.updateGames(payload.products.clone());
+		failed = false;
+		user.updateGames(payload.products.clone(), &payload.tags);
 		
 		let lastPage = payload.page >= payload.totalPages;
 		
@@ -234,6 +245,7 @@ fn refreshGameList(mut user: GogUser, session: GogSession, page: Option<u64>) ->
 	
 	return GogOperationResult
 	{
+		failed,
 		user,
 		requests,
 	};
@@ -1,9 +1,14 @@
 use components::settings::util::separatorElement;
+use data::constants::{TextColor, WarningColor};
+use data::enums::GamePlatforms;
 use freya::prelude::{Alignment, Button, ChildrenExt, Component, ContainerExt,
 	ContainerSizeExt, ContainerWithContentExt, Content, Direction, Gaps,
 	IntoElement, Size, TextAlign, TextStyleExt, label, rect};
+use freya::radio::use_radio;
+use net::SessionStatus;
 use tracing::{info, warn};
-use crate::secure::removeGogSession;
+use crate::data::user::GogUser;
+use crate::secure::{getGogSessionStatus, removeGogSession};
 
 #[derive(Clone, PartialEq)]
 pub struct GogSettingsElement
@@ -15,6 +20,12 @@ impl Component for GogSettingsElement
 {
 	fn render(&self) -> impl IntoElement
 	{
+		// Refreshes return the user whenever they change the session status, so
+		// reading it re-renders the status after a background refresh
+		let mut user = use_radio::<GogUser, GamePlatforms>(GamePlatforms::Gog);
+		_ = user.read();
+		let sessionStatus = getGogSessionStatus();
+		
 		let _labelWidth = self.labelWidth.clone();
 		
 		return rect()
@@ -34,6 +45,18 @@ impl Component for GogSettingsElement
 					.text("GOG Session Management")
 			)
 			
+			.child(
+				label()
+					.color(match sessionStatus
+					{
+						SessionStatus::ReauthenticationRequired => WarningColor,
+						_ => TextColor,
+					})
+					.text_align(TextAlign::Center)
+					.width(Size::Fill)
+					.text(format!("Session Status: {}", sessionStatus))
+			)
+			
 			.child(
 				rect()
 					.content(Content::Flex)
@@ -44,10 +67,15 @@ impl Component for GogSettingsElement
 					
 					.child(
 						Button::new()
-							.on_press(move |_| match removeGogSession()
-							{
-								Err(e) => warn!("[GOG] Error removing session data: {:?}", e),
-								Ok(_) => info!("[GOG] Session data removed"),
+							.on_press(move |_| {
+								match removeGogSession()
+								{
+									Err(e) => warn!("[GOG] Error removing session data: {:?}", e),
+									Ok(_) => info!("[GOG] Session data removed"),
+								}
+								
+								// Notify every GOG view so they show the new session status
+								user.write();
 							})
 							.child("Clear Session")
 					)
@@ -2,15 +2,21 @@ use std::cmp::Ordering;
 use std::io::ErrorKind;
 use anyhow::{anyhow, Result};
 use chrono::DateTime;
-use data::constants::{Format_ChronoDateTime, TheString};
+use data::constants::{Format_ChronoDateTime, Icon_Locked, Path_Games, TheString};
+use data::enums::{GamePlatforms, RarityTier};
+use data::io::FileLocation;
+use data::settings::RarityThresholds;
+use data::unified::{Unifiable, UnifiedAchievement, UnifiedExtension};
+use macros::{join, jpg, jpgAlt};
+use net::{DataOperation, DataRequest};
 use serde::{Deserialize, Serialize};
 use serde_json::{Map, Value};
-use crate::api::AchievementMetadata;
+use crate::api::{AchievementMetadata, GogApi};
 
 /**
 The 
 */
-#[derive(Clone, Debug, Default, Deserialize, Eq, Hash, PartialEq, Ord, Serialize)]
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
 pub struct GogAchievement
 {
 	/// 
@@ -21,6 +27,14 @@ pub struct GogAchievement
 	#[serde(default)]
 	pub description: String,
 	
+	/// The URL used to retrieve the locked icon.
+	#[serde(default)]
+	pub iconLockedUrl: String,
+	
+	/// The URL used to retrieve the unlocked icon.
+	#[serde(default)]
+	pub iconUrl: String,
+	
 	/// 
 	#[serde(default)]
 	pub id: String,
@@ -33,6 +47,18 @@ pub struct GogAchievement
 	#[serde(default)]
 	pub name: String,
 	
+	/// The percentage of GOG players who have unlocked the achievement.
+	#[serde(default)]
+	pub rarity: Option<f32>,
+	
+	/// GOG's human-readable name for the rarity level of the achievement.
+	#[serde(default)]
+	pub rarityLevel: Option<String>,
+	
+	/// GOG's identifier for the rarity level of the achievement, e.g. `rare`.
+	#[serde(default)]
+	pub raritySlug: Option<String>,
+	
 	/// 
 	#[serde(default)]
 	pub visible: bool,
@@ -48,6 +74,18 @@ impl From<AchievementMetadata> for GogAchievement
 	}
 }
 
+/// Rarity percentages are never `NaN`, so equality is total.
+impl Eq for GogAchievement {}
+
+impl Ord for GogAchievement
+{
+	fn cmp(&self, other: &Self) -> Ordering
+	{
+		return self.partial_cmp(other)
+			.unwrap_or(Ordering::Equal);
+	}
+}
+
 impl PartialOrd for GogAchievement
 {
 	fn partial_cmp(&self, other: &Self) -> Option<Ordering>
@@ -83,6 +121,26 @@ impl PartialOrd for GogAchievement
 	}
 }
 
+impl Unifiable<UnifiedAchievement> for GogAchievement
+{
+	fn unify(&self) -> UnifiedAchievement
+	{
+		return UnifiedAchievement
+		{
+			description: self.description.clone(),
+			extension: UnifiedExtension::None,
+			hidden: !self.visible,
+			id: self.id.clone(),
+			name: self.name.clone(),
+			platform: GamePlatforms::Gog,
+			rarity: self.rarity.map(f64::from),
+			unlocked: self.dateUnlocked.is_some(),
+			unlockedTimestamp: self.dateUnlocked
+				.and_then(|ts| DateTime::from_timestamp(ts, 0)),
+		};
+	}
+}
+
 impl GogAchievement
 {
 	const DateUnlockedFormat: &str = "%FT%T%z";
@@ -125,6 +183,24 @@ impl GogAchievement
 			}
 		}
 		
+		if let Some((_, value)) = map.iter()
+			.find(|(key, _)| key.as_str() == "iconLockedUrl")
+		{
+			if let Value::String(inner) = value
+			{
+				achievement.iconLockedUrl = inner.clone();
+			}
+		}
+		
+		if let Some((_, value)) = map.iter()
+			.find(|(key, _)| key.as_str() == "iconUrl")
+		{
+			if let Value::String(inner) = value
+			{
+				achievement.iconUrl = inner.clone();
+			}
+		}
+		
 		if let Some((_, value)) = map.iter()
 			.find(|(key, _)| key.as_str() == "id")
 		{
@@ -152,6 +228,34 @@ impl GogAchievement
 			}
 		}
 		
+		if let Some((_, value)) = map.iter()
+			.find(|(key, _)| key.as_str() == "rarity")
+		{
+			if let Value::Number(inner) = value
+			{
+				achievement.rarity = inner.as_f64()
+					.map(|number| number as f32);
+			}
+		}
+		
+		if let Some((_, value)) = map.iter()
+			.find(|(key, _)| key.as_str() == "rarityLevel")
+		{
+			if let Value::String(inner) = value
+			{
+				achievement.rarityLevel = Some(inner.clone());
+			}
+		}
+		
+		if let Some((_, value)) = map.iter()
+			.find(|(key, _)| key.as_str() == "raritySlug")
+		{
+			if let Value::String(inner) = value
+			{
+				achievement.raritySlug = Some(inner.clone());
+			}
+		}
+		
 		if let Some((_, value)) = map.iter()
 			.find(|(key, _)| key.as_str() == "visible")
 		{
@@ -168,6 +272,41 @@ impl GogAchievement
 		};
 	}
 	
+	/**
+	The requests which cache the unlocked and locked icons of the achievement,
+	skipping any icon whose URL has not been retrieved.
+	*/
+	pub fn iconRequests(&self, gameId: u64) -> Vec<DataRequest>
+	{
+		let group = join!(Path_Games, gameId.to_string());
+		let platform = GogApi::Platform.to_lowercase();
+		
+		return [
+			(jpg!(self.id.clone()), &self.iconUrl),
+			(jpgAlt!(self.id.clone(), Icon_Locked), &self.iconLockedUrl),
+		]
+			.into_iter()
+			.filter(|(_, url)| !url.is_empty())
+			.map(|(fileName, url)| DataRequest
+			{
+				destination: Some(FileLocation
+				{
+					fileName,
+					group: group.clone(),
+					platform: platform.clone(),
+				}),
+				operation: DataOperation::CacheImage(false),
+				url: Some(url.clone()),
+			})
+			.collect();
+	}
+	
+	pub fn rarityTier(&self, thresholds: &RarityThresholds) -> Option<RarityTier>
+	{
+		return self.rarity
+			.map(|percent| thresholds.tier(percent));
+	}
+	
 	pub fn sortName(&self) -> String
 	{
 		return match self.name.starts_with(TheString)
@@ -195,9 +334,14 @@ impl GogAchievement
 		};
 		
 		self.description = achievement.description.clone();
+		self.iconLockedUrl = achievement.image_url_locked.clone();
+		self.iconUrl = achievement.image_url_unlocked.clone();
 		self.id = achievement.achievement_id.clone();
 		self.key = achievement.achievement_key.clone();
 		self.name = achievement.name.clone();
+		self.rarity = achievement.rarity.map(|r| r as f32);
+		self.rarityLevel = achievement.rarity_level_description.clone();
+		self.raritySlug = achievement.rarity_level_slug.clone();
 		self.visible = achievement.visible;
 	}
 }
@@ -216,7 +360,12 @@ mod tests
 			map.insert("id".into(), "The id".into());
 		}
 		
+		map.insert("iconLockedUrl".into(), "The icon locked url".into());
+		map.insert("iconUrl".into(), "The icon url".into());
 		map.insert("key".into(), "The key".into());
+		map.insert("rarity".into(), 12.5.into());
+		map.insert("rarityLevel".into(), "Rare".into());
+		map.insert("raritySlug".into(), "rare".into());
 		map.insert("visible".into(), true.into());
 		map.insert("name".into(), "The name".into());
 		map.insert("description".into(), "The description".into());
@@ -244,5 +393,46 @@ mod tests
 		assert_eq!(achievement.name, "The name".to_string());
 		assert_eq!(achievement.description, "The description".to_string());
 		assert_eq!(achievement.dateUnlocked, None);
+		assert_eq!(achievement.iconLockedUrl, "The icon locked url".to_string());
+		assert_eq!(achievement.iconUrl, "The icon url".to_string());
+		assert_eq!(achievement.rarity, Some(12.5));
+		assert_eq!(achievement.rarityLevel, Some("Rare".to_string()));
+		assert_eq!(achievement.raritySlug, Some("rare".to_string()));
+	}
+	
+	#[test]
+	fn update()
+	{
+		let metadata = serde_json::from_str::<AchievementMetadata>(r#"{
+	"achievement_id": "57896",
+	"achievement_key": "ACH_01",
+	"visible": true,
+	"name": "First Steps",
+	"description": "Complete the tutorial",
+	"image_url_unlocked": "https://images.gog.com/unlocked.png",
+	"image_url_locked": "https://images.gog.com/locked.png",
+	"date_unlocked": "2024-10-11T01:58:38+0000",
+	"rarity": 4.5,
+	"rarity_level_description": "Epic",
+	"rarity_level_slug": "epic"
+}"#).unwrap();
+		
+		let achievement = GogAchievement::from(metadata);
+		assert_eq!(achievement.dateUnlocked, Some(1728611918));
+		assert_eq!(achievement.iconLockedUrl, "https://images.gog.com/locked.png".to_string());
+		assert_eq!(achievement.iconUrl, "https://images.gog.com/unlocked.png".to_string());
+		assert_eq!(achievement.rarity, Some(4.5));
+		assert_eq!(achievement.rarityTier(&RarityThresholds::default()), Some(RarityTier::UltraRare));
+		assert_eq!(achievement.rarityLevel, Some("Epic".to_string()));
+		assert_eq!(achievement.raritySlug, Some("epic".to_string()));
+		
+		let urls = achievement.iconRequests(7).into_iter()
+			.map(|request| (request.destination.unwrap().fileName, request.url.unwrap()))
+			.collect::<Vec<_>>();
+		
+		assert_eq!(urls, vec![
+			(jpg!("57896"), "https://images.gog.com/unlocked.png".to_string()),
+			(jpgAlt!("57896", Icon_Locked), "https://images.gog.com/locked.png".to_string()),
+		]);
 	}
 }
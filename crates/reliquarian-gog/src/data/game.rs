@@ -1,23 +1,35 @@
 use std::cmp::Ordering;
 use chrono::DateTime;
-use data::constants::TheString;
+use data::constants::{FileName_GameIcon, Path_Games, TheString};
+use data::enums::GamePlatforms;
 use data::filter::{FilterCriteria, Filterable};
 use data::format::truncateF32;
+use data::io::FileLocation;
+use data::unified::{Unifiable, UnifiedGame};
+use macros::{join, jpg};
 use serde::{Deserialize, Serialize};
 use serde_json::{Map, Value};
-use crate::api::Product;
+use crate::api::{GogApi, Product, ProductUserTag, ProductWorksOn};
 use super::achievement::GogAchievement;
 
 /**
 The 
 */
-#[derive(Clone, Debug, Default, Deserialize, Eq, Hash, PartialEq, Ord, Serialize)]
+#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Ord, Serialize)]
 pub struct Game
 {
 	/// The list of achievements in the set.
 	#[serde(default)]
 	pub achievements: Vec<GogAchievement>,
 	
+	/// The genre of the game.
+	#[serde(default)]
+	pub category: String,
+	
+	/// The number of DLC the user owns for the game.
+	#[serde(default)]
+	pub dlcCount: u64,
+	
 	/// 
 	#[serde(default)]
 	pub flags: GameFlags,
@@ -34,6 +46,11 @@ pub struct Game
 	#[serde(default)]
 	pub name: String,
 	
+	/// The achievements gathered from the pages retrieved so far by a refresh
+	/// which has not yet reached the last page.
+	#[serde(skip)]
+	pub pendingAchievements: Vec<GogAchievement>,
+	
 	/// 
 	#[serde(default)]
 	pub rating: u64,
@@ -50,9 +67,13 @@ pub struct Game
 	#[serde(default)]
 	pub storePageUrl: String,
 	
-	/// 
+	/// The names of the user-defined tags applied to the game.
 	#[serde(default)]
 	pub tags: Vec<String>,
+	
+	/// The operating systems on which the game runs natively.
+	#[serde(default)]
+	pub worksOn: GameWorksOn,
 }
 
 impl Filterable<GogAchievement> for Game
@@ -132,6 +153,30 @@ impl PartialOrd for Game
 	}
 }
 
+impl Unifiable<UnifiedGame> for Game
+{
+	fn unify(&self) -> UnifiedGame
+	{
+		let mut game = UnifiedGame::new(
+			GamePlatforms::Gog,
+			self.id.to_string(),
+			self.name.clone(),
+			self.achievements.iter()
+				.map(|a| a.unify())
+				.collect()
+		);
+		
+		game.icon = Some(FileLocation
+		{
+			fileName: jpg!(FileName_GameIcon),
+			group: join!(Path_Games, self.id),
+			platform: GogApi::Platform.to_lowercase(),
+		});
+		
+		return game;
+	}
+}
+
 impl Game
 {
 	const ReleaseDateFormat: &str = "%F %T%.6f%:z";
@@ -140,6 +185,27 @@ impl Game
 	{
 		let mut game = Self::default();
 		
+		if let Some((_, value)) = map.iter()
+			.find(|(key, _)| key.as_str() == "category")
+		{
+			if let Value::String(string) = value
+			{
+				game.category = string.clone();
+			}
+		}
+		
+		if let Some((_, value)) = map.iter()
+			.find(|(key, _)| key.as_str() == "dlcCount")
+		{
+			if let Value::Number(number) = value
+			{
+				if let Some(uint) = number.as_u64()
+				{
+					game.dlcCount = uint;
+				}
+			}
+		}
+		
 		if let Some((_, value)) = map.iter()
 			.find(|(key, _)| key.as_str() == "flags")
 		{
@@ -229,6 +295,15 @@ impl Game
 			}
 		}
 		
+		if let Some((_, value)) = map.iter()
+			.find(|(key, _)| key.as_str() == "worksOn")
+		{
+			if let Value::Object(obj) = value
+			{
+				game.worksOn = GameWorksOn::parseJsonMap(obj);
+			}
+		}
+		
 		if let Some((_, value)) = map.iter()
 			.find(|(key, _)| key.as_str() == "achievements")
 		{
@@ -256,6 +331,38 @@ impl Game
 		};
 	}
 	
+	/**
+	Short descriptions of the game's library metadata, suitable for display
+	alongside its name.
+	*/
+	pub fn details(&self) -> Vec<String>
+	{
+		let mut details = vec![];
+		
+		if !self.category.is_empty()
+		{
+			details.push(self.category.clone());
+		}
+		
+		let systems = self.worksOn.names();
+		if !systems.is_empty()
+		{
+			details.push(systems.join(" / "));
+		}
+		
+		if self.dlcCount > 0
+		{
+			details.push(format!("{} DLC", self.dlcCount));
+		}
+		
+		if self.flags.galaxyCompatible
+		{
+			details.push("Galaxy".into());
+		}
+		
+		return details;
+	}
+	
 	pub fn percentUnlocked(&self) -> f32
 	{
 		return match self.achievements.is_empty()
@@ -289,6 +396,8 @@ impl Game
 	
 	pub fn update(&mut self, game: &Product)
 	{
+		self.category = game.category.clone();
+		self.dlcCount = game.dlcCount;
 		self.flags = GameFlags::from(game);
 		self.id = game.id;
 		self.name = game.title.clone();
@@ -310,8 +419,60 @@ impl Game
 		self.slug = game.slug.clone();
 		self.storePageUrl = game.url.clone();
 		
-		//TODO: Translate the tag id to the tag name
 		self.tags = game.tags.clone();
+		self.worksOn = game.worksOn.as_ref()
+			.map(GameWorksOn::from)
+			.unwrap_or_default();
+	}
+	
+	/**
+	Replace the game's tag ids with the names of the user-defined tags to which
+	they refer.
+	
+	Ids which do not match any of `userTags` are retained as-is.
+	*/
+	pub fn updateTagNames(&mut self, userTags: &[ProductUserTag])
+	{
+		for tag in self.tags.iter_mut()
+		{
+			if let Some(userTag) = userTags.iter()
+				.find(|t| &t.id == tag)
+			{
+				*tag = userTag.name.clone();
+			}
+		}
+	}
+	
+	/**
+	Add a page of achievements to those gathered by the current refresh,
+	replacing any which were already retrieved. The first page discards
+	anything gathered by an earlier refresh which did not finish.
+	*/
+	pub fn gatherAchievements(&mut self, achievements: impl Into<Vec<GogAchievement>>, firstPage: bool)
+	{
+		if firstPage
+		{
+			self.pendingAchievements.clear();
+		}
+		
+		for achievement in achievements.into()
+		{
+			match self.pendingAchievements.iter_mut()
+				.find(|a| a.id == achievement.id)
+			{
+				None => self.pendingAchievements.push(achievement),
+				Some(existing) => *existing = achievement,
+			}
+		}
+	}
+	
+	/**
+	Replace the game's achievements with those gathered by the current refresh.
+	*/
+	pub fn finishGatheringAchievements(&mut self)
+	{
+		let achievements = std::mem::take(&mut self.pendingAchievements);
+		self.updateAchievements(achievements);
 	}
 	
 	pub fn updateAchievements(&mut self, achievements: impl Into<Vec<GogAchievement>>)
@@ -324,17 +485,17 @@ impl Game
 #[derive(Clone, Debug, Default, Deserialize, Eq, Hash, PartialEq, PartialOrd, Ord, Serialize)]
 pub struct GameFlags
 {
-	available: bool,
-	availableInAccount: bool,
-	baseProductMissing: bool,
-	comingSoon: bool,
-	galaxyCompatible: bool,
-	game: bool,
-	hidden: bool,
-	hidingDisabled: bool,
-	inDevelopment: bool,
-	movie: bool,
-	new: bool,
+	pub available: bool,
+	pub availableInAccount: bool,
+	pub baseProductMissing: bool,
+	pub comingSoon: bool,
+	pub galaxyCompatible: bool,
+	pub game: bool,
+	pub hidden: bool,
+	pub hidingDisabled: bool,
+	pub inDevelopment: bool,
+	pub movie: bool,
+	pub new: bool,
 }
 
 impl From<&Product> for GameFlags
@@ -467,6 +628,87 @@ impl GameFlags
 	}
 }
 
+#[derive(Clone, Debug, Default, Deserialize, Eq, Hash, PartialEq, PartialOrd, Ord, Serialize)]
+pub struct GameWorksOn
+{
+	pub linux: bool,
+	pub mac: bool,
+	pub windows: bool,
+}
+
+impl From<&ProductWorksOn> for GameWorksOn
+{
+	fn from(value: &ProductWorksOn) -> Self
+	{
+		return Self
+		{
+			linux: value.Linux,
+			mac: value.Mac,
+			windows: value.Windows,
+		};
+	}
+}
+
+impl GameWorksOn
+{
+	/// The display names of the supported operating systems.
+	pub fn names(&self) -> Vec<&'static str>
+	{
+		let mut names = vec![];
+		
+		if self.windows
+		{
+			names.push("Windows");
+		}
+		
+		if self.mac
+		{
+			names.push("macOS");
+		}
+		
+		if self.linux
+		{
+			names.push("Linux");
+		}
+		
+		return names;
+	}
+	
+	pub fn parseJsonMap(map: &Map<String, Value>) -> Self
+	{
+		let mut worksOn = Self::default();
+		
+		if let Some((_, value)) = map.iter()
+			.find(|(key, _)| key.as_str() == "linux")
+		{
+			if let Value::Bool(inner) = value
+			{
+				worksOn.linux = *inner;
+			}
+		}
+		
+		if let Some((_, value)) = map.iter()
+			.find(|(key, _)| key.as_str() == "mac")
+		{
+			if let Value::Bool(inner) = value
+			{
+				worksOn.mac = *inner;
+			}
+		}
+		
+		if let Some((_, value)) = map.iter()
+			.find(|(key, _)| key.as_str() == "windows")
+		{
+			if let Value::Bool(inner) = value
+			{
+				worksOn.windows = *inner;
+			}
+		}
+		
+		return worksOn;
+	}
+}
+
 #[cfg(test)]
 mod tests
 {
@@ -488,6 +730,10 @@ mod tests
 		let mut gameFlags = Map::new();
 		gameFlags.insert("game".into(), true.into());
 		
+		let mut worksOn = Map::new();
+		worksOn.insert("linux".into(), true.into());
+		worksOn.insert("windows".into(), true.into());
+		
 		let mut map = Map::new();
 		
 		map.insert("achievements".into(), vec![
@@ -497,6 +743,8 @@ mod tests
 		].into());
 		
 		map.insert("awardedCasual".into(), gameFlags.into());
+		map.insert("category".into(), "Role-playing".into());
+		map.insert("dlcCount".into(), 2.into());
 		
 		if successful
 		{
@@ -509,6 +757,7 @@ mod tests
 		map.insert("slug".into(), "The slug".into());
 		map.insert("storePageUrl".into(), "The store page url".into());
 		map.insert("tags".into(), vec!["tag1".to_string(), "tag2".to_string()].into());
+		map.insert("worksOn".into(), worksOn.into());
 		
 		return map;
 	}
@@ -526,6 +775,8 @@ mod tests
 		
 		let game = success.unwrap();
 		assert_eq!(game.achievements.len(), 2);
+		assert_eq!(&game.category, "Role-playing");
+		assert_eq!(game.dlcCount, 2);
 		assert_eq!(game.id, 9);
 		assert_eq!(&game.name, "The name");
 		assert_eq!(game.rating, 4);
@@ -533,6 +784,9 @@ mod tests
 		assert_eq!(&game.slug, "The slug");
 		assert_eq!(&game.storePageUrl, "The store page url");
 		assert_eq!(game.tags, vec!["tag1".to_string(), "tag2".to_string()]);
+		assert!(game.worksOn.linux);
+		assert!(!game.worksOn.mac);
+		assert!(game.worksOn.windows);
 		
 		assert!(game.achievements.iter().any(|a| &a.id == "1"));
 		let firstAchievement = game.achievements.iter()
@@ -546,4 +800,51 @@ mod tests
 			.unwrap();
 		assert_eq!(lastAchievement.name, "Last achievement".to_string());
 	}
+	
+	fn parseGame(json: &str) -> Game
+	{
+		let map = serde_json::from_str::<Map<String, Value>>(json).unwrap();
+		return Game::parseJsonMap(&map).unwrap();
+	}
+	
+	const DetailsJson: &str = r#"{
+	"id": 9,
+	"name": "The name",
+	"category": "Strategy",
+	"dlcCount": 3,
+	"worksOn": { "linux": true, "mac": false, "windows": true }
+}"#;
+	
+	#[test]
+	fn details()
+	{
+		let mut game = parseGame(DetailsJson);
+		assert_eq!(game.details(), vec!["Strategy", "Windows / Linux", "3 DLC"]);
+		
+		game.flags.galaxyCompatible = true;
+		game.dlcCount = 0;
+		assert_eq!(game.details(), vec!["Strategy", "Windows / Linux", "Galaxy"]);
+		
+		assert!(Game::default().details().is_empty());
+	}
+	
+	const UserTagsJson: &str = r#"[
+	{ "id": "1234", "name": "Favorites", "productCount": "1" }
+]"#;
+	
+	const TaggedGameJson: &str = r#"{
+	"id": 9,
+	"name": "The name",
+	"tags": ["1234", "5678"]
+}"#;
+	
+	#[test]
+	fn updateTagNames()
+	{
+		let userTags = serde_json::from_str::<Vec<ProductUserTag>>(UserTagsJson).unwrap();
+		
+		let mut game = parseGame(TaggedGameJson);
+		game.updateTagNames(&userTags);
+		assert_eq!(game.tags, vec!["Favorites".to_string(), "5678".to_string()]);
+	}
 }
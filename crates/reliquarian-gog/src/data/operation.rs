@@ -8,6 +8,7 @@ use strum_macros::{AsRefStr, EnumString};
 pub enum GogOperation
 {
 	GetAchievements(u64),
+	GetAchievementsPage(u64, String),
 	GetFilteredProducts(Option<u64>),
 	GetUserInfo,
 	RefreshSession,
@@ -26,6 +27,13 @@ impl From<GogOperation> for DataOperation
 				gameId
 			),
 			
+			GogOperation::GetAchievementsPage(gameId, ref pageToken) => DataOperation::PlatformGameIdPage(
+				GamePlatforms::Gog,
+				value.as_ref().to_string(),
+				gameId,
+				pageToken.clone()
+			),
+			
 			GogOperation::GetFilteredProducts(page) => DataOperation::PlatformOptionalInt(
 				GamePlatforms::Gog,
 				value.as_ref().to_string(),
@@ -92,6 +100,22 @@ impl TryFrom<DataOperation> for GogOperation
 				_ => Err(anyhow!("Invalid GOG operation")),
 			}
 			
+			DataOperation::PlatformGameIdPage(
+				platform,
+				operationName,
+				gameId,
+				pageToken
+			) => match platform
+			{
+				GamePlatforms::Gog => match GogOperation::from_str(&operationName)?
+				{
+					Self::GetAchievementsPage(_, _) => Ok(Self::GetAchievementsPage(gameId, pageToken)),
+					_ => Err(anyhow!("Invalid GOG operation")),
+				},
+				
+				_ => Err(anyhow!("Invalid GOG operation")),
+			}
+			
 			DataOperation::PlatformOptionalInt(
 				platform,
 				operationName,
@@ -4,6 +4,8 @@ use super::user::GogUser;
 #[derive(Clone, Debug)]
 pub struct GogOperationResult
 {
+	/// Whether the operation failed to retrieve the data it was meant to refresh.
+	pub failed: bool,
 	pub user: GogUser,
 	pub requests: Vec<DataRequest>,
 }
@@ -14,8 +16,23 @@ impl From<GogUser> for GogOperationResult
 	{
 		return Self
 		{
+			failed: false,
 			user: value,
 			requests: Default::default(),
 		};
 	}
 }
+
+impl GogOperationResult
+{
+	/// A result which leaves the user unchanged and marks the operation as failed.
+	pub fn failure(user: GogUser) -> Self
+	{
+		return Self
+		{
+			failed: true,
+			user,
+			requests: Default::default(),
+		};
+	}
+}
@@ -3,10 +3,13 @@ use chrono::{DateTime, Utc};
 use chrono::serde::ts_seconds;
 use data::enums::GamePlatforms;
 use data::filter::{FilterCriteria, Filterable};
+use data::migration::Versioned;
+use data::storage::Storable;
+use data::unified::{Unifiable, UnifiedGame};
 use freya::radio::RadioChannel;
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
-use crate::api::{Product, UserInfo};
+use crate::api::{Payload_Achievements, Product, ProductUserTag, UserInfo};
 use super::achievement::GogAchievement;
 use super::game::Game;
 
@@ -29,6 +32,9 @@ impl Filterable<Game> for GogUser
 		
 		let allGames = filter.showAll;
 		let caseSensitive = filter.caseSensitive;
+		let dlc = filter.dlc;
+		let hideHidden = filter.hideHidden;
+		let linuxNative = filter.linuxNative;
 		let search = match caseSensitive
 		{
 			false => filter.text.to_lowercase(),
@@ -37,6 +43,10 @@ impl Filterable<Game> for GogUser
 		
 		let mut games = self.games.iter()
 			.filter(|g| allGames || g.hasAchievements.is_none_or(|b| b))
+			.filter(|g| !dlc || g.dlcCount > 0)
+			.filter(|g| !hideHidden || !g.flags.hidden)
+			.filter(|g| !linuxNative || g.worksOn.linux)
+			.filter(|g| filter.tag.as_ref().is_none_or(|t| g.tags.contains(t)))
 			.filter(|g| match caseSensitive
 			{
 				false => g.name.to_lowercase().contains(&search),
@@ -53,6 +63,29 @@ impl Filterable<Game> for GogUser
 
 impl RadioChannel<GogUser> for GamePlatforms {}
 
+impl Storable for GogUser
+{
+	const DataFileName: &'static str = GogUser::FileName;
+	const Platform: GamePlatforms = GamePlatforms::Gog;
+	
+	fn parseLossy(json: String) -> Result<Self>
+	{
+		return GogUser::parseJsonLossy(json);
+	}
+}
+
+impl Unifiable<Vec<UnifiedGame>> for GogUser
+{
+	fn unify(&self) -> Vec<UnifiedGame>
+	{
+		return self.games.iter()
+			.map(|g| g.unify())
+			.collect();
+	}
+}
+
+impl Versioned for GogUser {}
+
 impl GogUser
 {
 	pub const FileName: &str = "gog.json";
@@ -169,7 +202,20 @@ impl GogUser
 			.cloned();
 	}
 	
-	pub fn updateGames(&mut self, games: impl Into<Vec<Product>>)
+	/// The names of every user-defined tag applied to at least one game, sorted alphabetically.
+	pub fn tags(&self) -> Vec<String>
+	{
+		let mut tags = self.games.iter()
+			.flat_map(|g| g.tags.clone())
+			.collect::<Vec<_>>();
+		
+		tags.sort();
+		tags.dedup();
+		
+		return tags;
+	}
+	
+	pub fn updateGames(&mut self, games: impl Into<Vec<Product>>, userTags: &[ProductUserTag])
 	{
 		for product in games.into()
 		{
@@ -177,10 +223,13 @@ impl GogUser
 				.find(|g| g.id == product.id)
 			{
 				game.update(&product);
+				game.updateTagNames(userTags);
 			}
 			else
 			{
-				self.games.push(Game::from(product.clone()));
+				let mut game = Game::from(product.clone());
+				game.updateTagNames(userTags);
+				self.games.push(game);
 			}
 		}
 	}
@@ -197,6 +246,41 @@ impl GogUser
 		}
 	}
 	
+	/**
+	Update a game's achievements from a single page of the achievements list.
+	
+	Pages are gathered until the last one is retrieved, which then replaces the
+	game's achievements. A refresh which fails partway therefore keeps the
+	achievements from the previous refresh, rather than a truncated list.
+	Returns the token of the next page when achievements remain to be retrieved.
+	*/
+	pub fn updateGameAchievementsPage(&mut self, gameId: impl Into<u64>, payload: Payload_Achievements, firstPage: bool) -> Option<String>
+	{
+		let gameId = gameId.into();
+		
+		let game = self.games.iter_mut()
+			.find(|g| g.id == gameId)?;
+		
+		let pageEmpty = payload.items.is_empty();
+		let achievements = payload.items.into_iter()
+			.map(GogAchievement::from)
+			.collect::<Vec<_>>();
+		
+		game.gatherAchievements(achievements, firstPage);
+		
+		let remaining = (game.pendingAchievements.len() as u64) < payload.total_count;
+		
+		return match !pageEmpty && remaining && !payload.page_token.is_empty()
+		{
+			false => {
+				game.finishGatheringAchievements();
+				None
+			},
+			
+			true => Some(payload.page_token),
+		};
+	}
+	
 	pub fn updateUserInfo(&mut self, userInfo: impl Into<UserInfo>)
 	{
 		let userInfo = userInfo.into();
@@ -247,6 +331,126 @@ mod tests
 	"name": "Test User"
 }"#;
 	
+	const LibraryMetadataJson: &str = r#"{
+	"createdTimestamp": 1763543348,
+	"employee": false,
+	"games": [
+		{
+			"id": 1,
+			"name": "1",
+			"dlcCount": 2,
+			"flags": { "hidden": false },
+			"tags": ["Favorites"],
+			"worksOn": { "linux": true }
+		},
+		
+		{
+			"id": 2,
+			"name": "2",
+			"dlcCount": 0,
+			"flags": { "hidden": true },
+			"tags": [],
+			"worksOn": { "linux": true }
+		},
+		
+		{
+			"id": 3,
+			"name": "3",
+			"dlcCount": 1,
+			"flags": { "hidden": false },
+			"tags": ["Backlog", "Favorites"],
+			"worksOn": { "linux": false }
+		}
+	],
+	"id": "The id",
+	"name": "Test User"
+}"#;
+	
+	#[test]
+	fn filterLibraryMetadata()
+	{
+		let user = GogUser::parseJsonLossy(LibraryMetadataJson.into()).unwrap();
+		assert_eq!(user.games.len(), 3);
+		
+		let ids = |criteria: FilterCriteria| user.filter(criteria)
+			.iter()
+			.map(|g| g.id)
+			.collect::<Vec<_>>();
+		
+		let base = FilterCriteria
+		{
+			showAll: true,
+			..Default::default()
+		};
+		
+		assert_eq!(ids(base.clone()), vec![1, 2, 3]);
+		assert_eq!(ids(FilterCriteria { dlc: true, ..base.clone() }), vec![1, 3]);
+		assert_eq!(ids(FilterCriteria { hideHidden: true, ..base.clone() }), vec![1, 3]);
+		assert_eq!(ids(FilterCriteria { linuxNative: true, ..base.clone() }), vec![1, 2]);
+		assert_eq!(ids(FilterCriteria { tag: Some("Backlog".into()), ..base.clone() }), vec![3]);
+		
+		assert_eq!(user.tags(), vec!["Backlog".to_string(), "Favorites".to_string()]);
+	}
+	
+	#[test]
+	fn updateGameAchievementsPage()
+	{
+		let firstPage = serde_json::from_str::<Payload_Achievements>(
+			include_str!("../../fixtures/achievements-page1.json")
+		).unwrap();
+		
+		let secondPage = serde_json::from_str::<Payload_Achievements>(
+			include_str!("../../fixtures/achievements-page2.json")
+		).unwrap();
+		
+		let mut user = GogUser
+		{
+			games: vec![
+				Game
+				{
+					id: 7,
+					..Default::default()
+				},
+			],
+			..Default::default()
+		};
+		
+		let token = user.updateGameAchievementsPage(7u64, firstPage.clone(), true);
+		assert_eq!(token, Some(firstPage.page_token.clone()));
+		
+		// Nothing is replaced until the last page has been retrieved
+		let game = user.getGame(7u64).unwrap();
+		assert!(game.achievements.is_empty());
+		assert_eq!(game.pendingAchievements.len(), 2);
+		
+		let token = user.updateGameAchievementsPage(7u64, secondPage.clone(), false);
+		assert_eq!(token, None);
+		
+		let game = user.getGame(7u64).unwrap();
+		assert_eq!(game.achievements.len(), 3);
+		assert_eq!(game.hasAchievements, Some(true));
+		assert!(game.achievements.iter().any(|a| a.key == "COMPLETIONIST"));
+		
+		assert!(game.pendingAchievements.is_empty());
+		
+		// A refresh which never retrieves its last page keeps the previous list
+		let token = user.updateGameAchievementsPage(7u64, firstPage.clone(), true);
+		assert!(token.is_some());
+		assert_eq!(user.getGame(7u64).unwrap().achievements.len(), 3);
+		
+		// Refreshing from the first page starts the gathered list over
+		let token = user.updateGameAchievementsPage(7u64, firstPage, true);
+		assert!(token.is_some());
+		
+		let game = user.getGame(7u64).unwrap();
+		assert_eq!(game.achievements.len(), 3);
+		assert_eq!(game.pendingAchievements.len(), 2);
+		
+		// Unknown games are left alone
+		assert_eq!(user.updateGameAchievementsPage(8u64, secondPage, true), None);
+		assert!(user.getGame(8u64).is_none());
+	}
+	
 	#[test]
 	fn parseJsonLossy()
 	{
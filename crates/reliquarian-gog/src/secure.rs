@@ -1,5 +1,6 @@
 use anyhow::Result;
 use data::Secrets;
+use net::SessionStatus;
 use crate::api::GogSession;
 
 pub fn getGogSession() -> Result<GogSession>
@@ -9,6 +10,25 @@ pub fn getGogSession() -> Result<GogSession>
 	return Ok(serde_json::from_str(&json)?);
 }
 
+pub fn getSessionStatus() -> SessionStatus
+{
+	return match getGogSession()
+	{
+		Err(_) => SessionStatus::Missing,
+		Ok(session) => session.status(),
+	};
+}
+
+/**
+Flag the stored session as requiring the user to log in again.
+*/
+pub fn invalidateGogSession() -> Result<()>
+{
+	let mut session = getGogSession()?;
+	session.invalidate();
+	return setGogSession(session);
+}
+
 pub fn removeGogSession() -> Result<()>
 {
 	let mut secrets = Secrets.blocking_lock();
@@ -12,4 +12,4 @@ pub use limiter::{
 };
 
 pub use oauth::AuthorizationManager;
-pub use session::Oauth2Session;
+pub use session::{Oauth2Session, Reauthentication, SessionStatus};
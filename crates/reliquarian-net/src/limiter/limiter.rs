@@ -1,11 +1,11 @@
-use std::collections::VecDeque;
+use std::collections::{HashSet, VecDeque};
 use std::sync::atomic::AtomicBool;
 use std::time::Duration;
 use std::sync::atomic::Ordering;
 use std::sync::Arc;
 use std::sync::atomic::AtomicU64;
 use chrono::{DateTime, Utc};
-use data::enums::DataChannel;
+use data::enums::{DataChannel, GamePlatforms};
 use freya::radio::RadioChannel;
 use tokio::sync::Mutex;
 use tokio::time::sleep;
@@ -15,6 +15,7 @@ use crate::DataRequest;
 pub struct RateLimiter
 {
 	capacity: AtomicU64,
+	failures: Arc<Mutex<HashSet<GamePlatforms>>>,
 	lastUsed: Arc<Mutex<DateTime<Utc>>>,
 	lastRefunded: AtomicBool,
 	requests: Arc<Mutex<VecDeque<DataRequest>>>,
@@ -152,4 +153,21 @@ impl RateLimiter
 	{
 		self.lastRefunded.store(true, Ordering::SeqCst);
 	}
+	
+	/// Record that a request for the platform failed to refresh its data.
+	pub async fn reportFailure(&self, platform: GamePlatforms)
+	{
+		self.failures.lock()
+			.await
+			.insert(platform);
+	}
+	
+	/// Retrieve and clear the platforms which have failed since the last call.
+	pub async fn takeFailures(&self) -> HashSet<GamePlatforms>
+	{
+		let mut failures = self.failures.lock()
+			.await;
+		
+		return std::mem::take(&mut *failures);
+	}
 }
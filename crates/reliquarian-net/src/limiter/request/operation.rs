@@ -8,6 +8,7 @@ pub enum DataOperation
 	Platform(GamePlatforms, String),
 	PlatformGameId(GamePlatforms, String, u64),
 	PlatformGameIdBool(GamePlatforms, String, u64, bool),
+	PlatformGameIdPage(GamePlatforms, String, u64, String),
 	PlatformGameIdString(GamePlatforms, String, String),
 	PlatformOptionalInt(GamePlatforms, String, Option<u64>),
 	PlatformSaveToFile(GamePlatforms),
@@ -42,3 +43,24 @@ impl PartialOrd for DataOperation
 		};
 	}
 }
+
+impl DataOperation
+{
+	/// The platform whose handler processes this operation, if any.
+	pub fn platform(&self) -> Option<GamePlatforms>
+	{
+		return match self
+		{
+			Self::CacheImage(_) => None,
+			
+			Self::Platform(platform, _)
+				| Self::PlatformGameId(platform, _, _)
+				| Self::PlatformGameIdBool(platform, _, _, _)
+				| Self::PlatformGameIdPage(platform, _, _, _)
+				| Self::PlatformGameIdString(platform, _, _)
+				| Self::PlatformOptionalInt(platform, _, _)
+				| Self::PlatformSaveToFile(platform)
+				| Self::PlatformThreeInt(platform, _, _, _, _) => Some(*platform),
+		};
+	}
+}
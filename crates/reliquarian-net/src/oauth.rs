@@ -3,7 +3,8 @@ use std::net::TcpListener;
 use anyhow::{anyhow, Result};
 use oauth2::basic::{BasicClient};
 use oauth2::{AccessToken, AuthUrl, AuthorizationCode, ClientId, ClientSecret,
-	CsrfToken, PkceCodeChallenge, RedirectUrl, ResponseType, Scope, TokenUrl};
+	CsrfToken, PkceCodeChallenge, RedirectUrl, RefreshToken, RequestTokenError,
+	ResponseType, Scope, TokenUrl};
 use reqwest::{ClientBuilder, Url};
 use reqwest::header::HeaderValue;
 use reqwest::redirect::Policy;
@@ -141,6 +142,37 @@ impl AuthorizationManager
 		};
 	}
 	
+	/**
+	Exchange a refresh token for a new access token.
+	
+	Returns `Ok(None)` if the token endpoint rejected the refresh token, in
+	which case the user must complete the authorization code flow again.
+	*/
+	pub async fn refreshTokenFlow<T>(&self, refreshToken: RefreshToken) -> Result<Option<T>>
+		where T: Oauth2Session
+	{
+		let client = BasicClient::new(self.clientId.clone())
+			.set_client_secret(self.clientSecret.clone())
+			.set_token_uri(self.tokenUrl.clone());
+		
+		let httpClient = ClientBuilder::new()
+			.redirect(Policy::none())
+			.build()?;
+		
+		return match client.exchange_refresh_token(&refreshToken)
+			.request_async(&httpClient)
+			.await
+		{
+			Err(RequestTokenError::ServerResponse(e)) => {
+				warn!("[OAuth2] Refresh token was rejected: {:?}", e);
+				Ok(None)
+			},
+			
+			Err(e) => Err(anyhow!("Refresh token flow failed: {:?}", e)),
+			Ok(tokenResult) => Ok(Some(T::fromTokenResult(tokenResult))),
+		};
+	}
+	
 	fn bindListener(&mut self) -> Result<()>
 	{
 		let uri = format!("127.0.0.1:{}", match self.preferredRedirectPort
@@ -1,7 +1,56 @@
 use oauth2::{EmptyExtraTokenFields, StandardTokenResponse};
 use oauth2::basic::BasicTokenType;
+use serde::{Deserialize, Serialize};
+use strum_macros::Display;
 
 pub trait Oauth2Session
 {
 	fn fromTokenResult(result: StandardTokenResponse<EmptyExtraTokenFields, BasicTokenType>) -> Self;
 }
+
+/**
+Whether a stored session can no longer be refreshed, requiring the user to
+sign in again.
+
+The session is retained, rather than removed, so the UI is able to explain
+why the user must sign in again. Serialized as a plain boolean.
+*/
+#[derive(Clone, Copy, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(transparent)]
+pub struct Reauthentication(bool);
+
+/// The state of a platform session, as presented in the UI.
+#[derive(Clone, Copy, Debug, Display, Eq, PartialEq)]
+pub enum SessionStatus
+{
+	Active,
+	
+	#[strum(to_string="Not Signed In")]
+	Missing,
+	
+	#[strum(to_string="Session Expired, Sign In Again")]
+	ReauthenticationRequired,
+}
+
+impl Reauthentication
+{
+	/// Mark the session as unusable because its refresh token was rejected.
+	pub fn invalidate(&mut self)
+	{
+		self.0 = true;
+	}
+	
+	pub fn isRequired(&self) -> bool
+	{
+		return self.0;
+	}
+	
+	pub fn status(&self) -> SessionStatus
+	{
+		return match self.0
+		{
+			false => SessionStatus::Active,
+			true => SessionStatus::ReauthenticationRequired,
+		};
+	}
+}
@@ -7,8 +7,11 @@ use serde::de::DeserializeOwned;
 use crate::secure::getRetroAchievementsAuth;
 use super::auth::RetroAchievementsAuth;
 use super::endpoint::gameinfo::Payload_GetGameInfo;
+use super::endpoint::recentachievements::Payload_GetUserRecentAchievements;
+use super::endpoint::recentlyplayedgames::Payload_GetUserRecentlyPlayedGames;
 use super::endpoint::usercompletionprogress::Payload_GetUserCompletionProgress;
 use super::endpoint::userprofile::Payload_GetUserProfile;
+use super::endpoint::wanttoplaylist::Payload_GetUserWantToPlayList;
 
 pub struct RetroAchievementsApi;
 
@@ -20,8 +23,15 @@ impl RetroAchievementsApi
 	const Endpoint_GetGameInfo: &str = "API_GetGameInfoAndUserProgress.php";
 	const Endpoint_GetUserGameCompletion: &str = "API_GetUserCompletionProgress.php";
 	const Endpoint_GetUserProfile: &str = "API_GetUserProfile.php";
+	const Endpoint_GetUserRecentAchievements: &str = "API_GetUserRecentAchievements.php";
+	const Endpoint_GetUserRecentlyPlayedGames: &str = "API_GetUserRecentlyPlayedGames.php";
+	const Endpoint_GetUserWantToPlayList: &str = "API_GetUserWantToPlayList.php";
 	
 	pub const GetUserGameCompletion_Count: u64 = 100;
+	pub const GetUserRecentAchievements_DefaultMinutes: u64 = 60;
+	pub const GetUserRecentAchievements_Minutes: u64 = 1440;
+	pub const GetUserRecentlyPlayedGames_Count: u64 = 50;
+	pub const GetUserWantToPlayList_Count: u64 = 500;
 	
 	pub const BadgePath: &str = "Badge";
 	pub const BadgeLockedSuffix: &str = "lock";
@@ -284,6 +294,221 @@ impl RetroAchievementsApi
 			))?);
 	}
 	
+	/**
+	Call the GetUserRecentAchievements endpoint to retrieve the achievements
+	the current user has unlocked within the given number of minutes.
+	
+	---
+	
+	# [GetUserRecentAchievements](https://api-docs.retroachievements.org/v1/get-user-recent-achievements.html)
+	
+	Example URL:
+	`https://retroachievements.org/API/API_GetUserRecentAchievements.php?u=XXXXXXXX&y=XXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX`
+	
+	---
+	
+	### Arguments
+	
+	Name | Required | Description
+	:--|:--|:--
+	y | Yes | Your web API key.
+	u | Yes | The target username or ULID.
+	m | No | Minutes to look back (default: 60).
+	
+	You must query the user by either their username or their ULID. Please note the username is not considered a stable value. As of 2025, users can change their usernames. Initially querying by username is a good way to fetch a ULID.
+	
+	### Return Value
+	
+	A JSON array of Achievement objects with the following properties:
+	
+	Name | Description
+	:--|:--
+	Date | Timestamp string
+	HardcoreMode | int
+	AchievementID | int
+	Title | String
+	Description | String
+	BadgeName | String
+	Points | int
+	TrueRatio | int
+	Type | String ; optional
+	Author | String
+	AuthorULID | String
+	GameTitle | String
+	GameIcon | String
+	GameID | int
+	ConsoleName | String
+	BadgeURL | String
+	GameURL | String
+	*/
+	pub fn getUserRecentAchievements(ulid: Option<String>, minutes: Option<u64>) -> Result<Payload_GetUserRecentAchievements>
+	{
+		let auth = getRetroAchievementsAuth()?;
+		let mut parameters = Self::generateParameterMap(&auth);
+		parameters.remove(Self::Parameter_ApiUsername);
+		parameters.insert("u".into(), match ulid
+		{
+			Some(ulid) => ulid,
+			None => auth.username().clone(),
+		});
+		
+		if let Some(m) = minutes
+		{
+			parameters.insert("m".into(), m.to_string());
+		}
+		
+		return Ok(Self::get::<Payload_GetUserRecentAchievements>(
+			&Self::Endpoint_GetUserRecentAchievements.into(),
+			&parameters
+		)
+			.context(format!(
+				"Error retrieving recent achievements for username {}",
+				auth.username()
+			))?);
+	}
+	
+	/**
+	Call the GetUserRecentlyPlayedGames endpoint to retrieve the games the
+	current user has most recently played, along with their progress in each.
+	
+	---
+	
+	# [GetUserRecentlyPlayedGames](https://api-docs.retroachievements.org/v1/get-user-recently-played-games.html)
+	
+	Example URL:
+	`https://retroachievements.org/API/API_GetUserRecentlyPlayedGames.php?u=XXXXXXXX&y=XXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX`
+	
+	---
+	
+	### Arguments
+	
+	Name | Required | Description
+	:--|:--|:--
+	y | Yes | Your web API key.
+	u | Yes | The target username or ULID.
+	c | No | Count, number of records to return (default: 10, max: 50).
+	o | No | Offset, number of entries to skip (default: 0).
+	
+	You must query the user by either their username or their ULID. Please note the username is not considered a stable value. As of 2025, users can change their usernames. Initially querying by username is a good way to fetch a ULID.
+	
+	### Return Value
+	
+	A JSON array of Game objects with the following properties:
+	
+	Name | Description
+	:--|:--
+	GameID | int
+	ConsoleID | int
+	ConsoleName | String
+	Title | String
+	ImageIcon | String
+	ImageTitle | String
+	ImageIngame | String
+	ImageBoxArt | String
+	LastPlayed | Timestamp string
+	AchievementsTotal | int
+	NumPossibleAchievements | int
+	PossibleScore | int
+	NumAchieved | int
+	ScoreAchieved | int
+	NumAchievedHardcore | int
+	ScoreAchievedHardcore | int
+	*/
+	pub fn getUserRecentlyPlayedGames(ulid: Option<String>) -> Result<Payload_GetUserRecentlyPlayedGames>
+	{
+		let auth = getRetroAchievementsAuth()?;
+		let mut parameters = Self::generateParameterMap(&auth);
+		parameters.remove(Self::Parameter_ApiUsername);
+		parameters.insert("u".into(), match ulid
+		{
+			Some(ulid) => ulid,
+			None => auth.username().clone(),
+		});
+		parameters.insert("c".into(), Self::GetUserRecentlyPlayedGames_Count.to_string());
+		
+		return Ok(Self::get::<Payload_GetUserRecentlyPlayedGames>(
+			&Self::Endpoint_GetUserRecentlyPlayedGames.into(),
+			&parameters
+		)
+			.context(format!(
+				"Error retrieving recently played games for username {}",
+				auth.username()
+			))?);
+	}
+	
+	/**
+	Call the GetUserWantToPlayList endpoint to retrieve the games the current
+	user has added to their Want to Play list.
+	
+	---
+	
+	# [GetUserWantToPlayList](https://api-docs.retroachievements.org/v1/get-user-want-to-play-list.html)
+	
+	Example URL:
+	`https://retroachievements.org/API/API_GetUserWantToPlayList.php?u=XXXXXXXX&y=XXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX`
+	
+	---
+	
+	### Arguments
+	
+	Name | Required | Description
+	:--|:--|:--
+	y | Yes | Your web API key.
+	u | Yes | The target username or ULID.
+	c | No | Count, number of records to return (default: 100, max: 500).
+	o | No | Offset, number of entries to skip (default: 0).
+	
+	You must query the user by either their username or their ULID. Please note the username is not considered a stable value. As of 2025, users can change their usernames. Initially querying by username is a good way to fetch a ULID.
+	
+	### Return Value
+	
+	A JSON response with the following properties:
+	
+	Name | Description
+	:--|:--
+	Count | int
+	Total | int
+	Results | Array\<Game\>
+	
+	#### Game JSON properties:
+	
+	Name | Description
+	:--|:--
+	ID | int
+	Title | String
+	ImageIcon | String
+	ConsoleID | int
+	ConsoleName | String
+	PointsTotal | int
+	AchievementsPublished | int
+	*/
+	pub fn getUserWantToPlayList(ulid: Option<String>, offset: Option<u64>) -> Result<Payload_GetUserWantToPlayList>
+	{
+		let auth = getRetroAchievementsAuth()?;
+		let mut parameters = Self::generateParameterMap(&auth);
+		parameters.remove(Self::Parameter_ApiUsername);
+		parameters.insert("u".into(), match ulid
+		{
+			Some(ulid) => ulid,
+			None => auth.username().clone(),
+		});
+		parameters.insert("c".into(), Self::GetUserWantToPlayList_Count.to_string());
+		
+		if let Some(o) = offset
+		{
+			parameters.insert("o".into(), o.to_string());
+		}
+		
+		return Ok(Self::get::<Payload_GetUserWantToPlayList>(
+			&Self::Endpoint_GetUserWantToPlayList.into(),
+			&parameters
+		)
+			.context(format!(
+				"Error retrieving want to play list for username {}",
+				auth.username()
+			))?);
+	}
+	
 	pub fn buildMediaUrl(endpoint: &str) -> Option<String>
 	{
 		return Self::buildUrl(Self::MediaUrl, endpoint);
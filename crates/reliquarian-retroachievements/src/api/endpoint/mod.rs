@@ -1,3 +1,6 @@
 pub mod gameinfo;
+pub mod recentachievements;
+pub mod recentlyplayedgames;
 pub mod usercompletionprogress;
 pub mod userprofile;
+pub mod wanttoplaylist;
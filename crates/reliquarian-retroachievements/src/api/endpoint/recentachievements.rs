@@ -0,0 +1,26 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct RecentAchievementMetadata
+{
+	pub AchievementID: u64,
+	pub Author: String,
+	pub AuthorULID: String,
+	pub BadgeName: String,
+	pub BadgeURL: String,
+	pub ConsoleName: String,
+	pub Date: String,
+	pub Description: String,
+	pub GameIcon: String,
+	pub GameID: u64,
+	pub GameTitle: String,
+	pub GameURL: String,
+	pub HardcoreMode: u64,
+	pub Points: u64,
+	pub Title: String,
+	pub TrueRatio: u64,
+	pub Type: Option<String>,
+}
+
+#[allow(non_camel_case_types)]
+pub type Payload_GetUserRecentAchievements = Vec<RecentAchievementMetadata>;
@@ -0,0 +1,25 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct RecentlyPlayedMetadata
+{
+	pub AchievementsTotal: u64,
+	pub ConsoleID: u64,
+	pub ConsoleName: String,
+	pub GameID: u64,
+	pub ImageBoxArt: String,
+	pub ImageIcon: String,
+	pub ImageIngame: String,
+	pub ImageTitle: String,
+	pub LastPlayed: String,
+	pub NumAchieved: u64,
+	pub NumAchievedHardcore: u64,
+	pub NumPossibleAchievements: u64,
+	pub PossibleScore: u64,
+	pub ScoreAchieved: u64,
+	pub ScoreAchievedHardcore: u64,
+	pub Title: String,
+}
+
+#[allow(non_camel_case_types)]
+pub type Payload_GetUserRecentlyPlayedGames = Vec<RecentlyPlayedMetadata>;
@@ -1,3 +1,4 @@
+use chrono::DateTime;
 use serde::{Deserialize, Serialize};
 
 #[derive(Clone, Debug, Default, Deserialize, Eq, Hash, PartialEq, Serialize)]
@@ -24,3 +25,21 @@ pub struct Payload_GetUserCompletionProgress
 	pub Results: Vec<GameMetadata>,
 	pub Total: u64,
 }
+
+impl Payload_GetUserCompletionProgress
+{
+	/**
+	Whether the games are ordered from the most recent unlock to the oldest,
+	followed by the games without any unlocks.
+	*/
+	pub fn isSortedByMostRecentAward(&self) -> bool
+	{
+		let awarded = self.Results.iter()
+			.map(|g| g.MostRecentAwardedDate.as_ref()
+				.and_then(|date| DateTime::parse_from_rfc3339(date).ok()))
+			.collect::<Vec<_>>();
+		
+		return awarded.windows(2)
+			.all(|pair| pair[0] >= pair[1]);
+	}
+}
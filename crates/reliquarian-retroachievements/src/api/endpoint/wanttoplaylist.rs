@@ -0,0 +1,22 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Default, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub struct WantToPlayMetadata
+{
+	pub AchievementsPublished: u64,
+	pub ConsoleID: u64,
+	pub ConsoleName: String,
+	pub ID: u64,
+	pub ImageIcon: String,
+	pub PointsTotal: u64,
+	pub Title: String,
+}
+
+#[allow(non_camel_case_types)]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct Payload_GetUserWantToPlayList
+{
+	pub Count: u64,
+	pub Results: Vec<WantToPlayMetadata>,
+	pub Total: u64,
+}
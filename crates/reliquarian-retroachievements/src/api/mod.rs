@@ -7,6 +7,9 @@ pub use api::RetroAchievementsApi;
 
 pub use endpoint::{
 	gameinfo::{AchievementMetadata, Payload_GetGameInfo},
+	recentachievements::{Payload_GetUserRecentAchievements, RecentAchievementMetadata},
+	recentlyplayedgames::{Payload_GetUserRecentlyPlayedGames, RecentlyPlayedMetadata},
 	usercompletionprogress::{GameMetadata, Payload_GetUserCompletionProgress},
 	userprofile::Payload_GetUserProfile,
+	wanttoplaylist::{Payload_GetUserWantToPlayList, WantToPlayMetadata},
 };
@@ -2,7 +2,8 @@ use std::path::PathBuf;
 use data::constants::{BorderColor, CornerRadius, Icon_Locked, Path_Games,
 	RetroAchievementsProgressColorBackground,
 	RetroAchievementsProgressColorCasual,
-	RetroAchievementsProgressColorHardcore};
+	RetroAchievementsProgressColorHardcore, RetroAchievementsTypeMissable,
+	RetroAchievementsTypeProgression, RetroAchievementsTypeWinCondition};
 use data::enums::GamePlatforms;
 use data::io::{FileLocation, filePathExists, getImagePath};
 use freya::prelude::{Alignment, Border, BorderAlignment, ChildrenExt, Color,
@@ -13,6 +14,7 @@ use freya::prelude::{Alignment, Border, BorderAlignment, ChildrenExt, Color,
 use freya::radio::use_radio;
 use macros::{join, png, pngAlt};
 use crate::api::RetroAchievementsApi;
+use crate::data::achievementtype::AchievementType;
 use crate::data::mode::RetroAchievementsMode;
 use crate::data::user::RetroAchievementsUser;
 
@@ -113,9 +115,30 @@ impl Component for AchievementElement
 							.width(Size::flex(1.0))
 							
 							.child(
-								label()
-									.text(achievement.name)
+								rect()
+									.content(Content::Flex)
+									.cross_align(Alignment::Center)
+									.direction(Direction::Horizontal)
+									.spacing(10.0)
 									.width(Size::flex(1.0))
+									
+									.child(
+										label()
+											.text(achievement.name)
+											.width(Size::flex(1.0))
+									)
+									
+									.maybe_child(achievement.achievementType.map(typeBadge))
+									
+									.child(
+										paragraph()
+											.font_size(12)
+											.span(achievement.points.to_string())
+											.span(
+												Span::new(format!(" ({})", achievement.trueRatio))
+													.color(Color::GREY)
+											)
+									)
 							)
 							
 							.child(
@@ -219,3 +242,30 @@ impl AchievementElement
 		};
 	}
 }
+
+fn typeBadge(achievementType: AchievementType) -> impl IntoElement
+{
+	let color = match achievementType
+	{
+		AchievementType::Missable => RetroAchievementsTypeMissable,
+		AchievementType::Progression => RetroAchievementsTypeProgression,
+		AchievementType::WinCondition => RetroAchievementsTypeWinCondition,
+	};
+	
+	return rect()
+		.border(Some(
+			Border::new()
+				.alignment(BorderAlignment::Center)
+				.fill(color)
+				.width(1.0)
+		))
+		.corner_radius(CornerRadius)
+		.padding(Gaps::new_symmetric(2.0, 6.0))
+		
+		.child(
+			label()
+				.color(color)
+				.font_size(10.0)
+				.text(achievementType.to_string())
+		);
+}
@@ -1,22 +1,26 @@
 use std::path::PathBuf;
 use components::button::icon::IconButton;
+use components::export::promptExport;
 use components::input::filter::AchievementsFilter;
 use components::overlay::refresh::ConfirmRefresh;
 use data::constants::{CornerRadius, FileName_GameIcon, Path_Games};
 use data::enums::{DataChannel, GamePlatforms};
+use data::export::AchievementExport;
 use data::filter::{FilterCriteria, Filterable};
 use data::io::{FileLocation, filePathExists, getImagePath};
 use freya::icons::lucide;
-use freya::prelude::{Alignment, ChildrenExt, Code, Component, ContainerExt,
+use freya::prelude::{Alignment, ChildrenExt, Code, Color, Component, ContainerExt,
 	ContainerSizeExt, ContainerWithContentExt, Content, Direction, Event,
 	EventHandlersExt, FontWeight, Gaps, ImageViewer, IntoElement,
-	KeyboardEventData, ScrollConfig, ScrollPosition, Size, TextAlign,
-	TextStyleExt, VirtualScrollView, WritableUtils, label, rect, spawn,
-	use_scroll_controller, use_side_effect, use_state};
+	KeyboardEventData, ScrollConfig, ScrollPosition, Size, Span, TextAlign,
+	TextStyleExt, VirtualScrollView, WritableUtils, label, paragraph, rect,
+	spawn, use_scroll_controller, use_side_effect, use_state};
 use freya::radio::use_radio;
 use macros::{join, png};
 use net::{RateLimiter, RequestEvent};
 use crate::api::RetroAchievementsApi;
+use crate::data::beaten::BeatenProgress;
+use crate::data::mode::RetroAchievementsMode;
 use crate::data::operation::RetroAchievementsOperation;
 use crate::data::user::RetroAchievementsUser;
 use super::achievement::AchievementElement;
@@ -42,7 +46,9 @@ impl Component for GameElement
 		let mut cancelled = use_state(bool::default);
 		let mut confirmed = use_state(bool::default);
 		let locked = use_state(bool::default);
+		let missable = use_state(bool::default);
 		let nameOnly = use_state(bool::default);
+		let progression = use_state(bool::default);
 		let search = use_state(String::default);
 		let mut showConfirmationDialog = use_state(bool::default);
 		
@@ -50,16 +56,26 @@ impl Component for GameElement
 			.getGame(self.gameId)
 			.unwrap_or_default();
 		
-		let achievements = game.filter(FilterCriteria
+		let criteria = FilterCriteria
 		{
 			caseSensitive: caseSensitive(),
 			locked: locked(),
+			missable: missable(),
 			nameOnly: nameOnly(),
+			progression: progression(),
 			text: search.read().clone(),
 			..Default::default()
-		});
+		};
+		
+		let achievements = game.filter(criteria.clone());
 		let achievementsListLength = achievements.len();
 		
+		let beatenCasual = game.beatenProgress(RetroAchievementsMode::Casual);
+		let beatenHardcore = game.beatenProgress(RetroAchievementsMode::Hardcore);
+		let retroPointsEarned = game.retroPointsEarned();
+		let retroPointsPossible = game.retroPointsPossible();
+		let showStats = !game.achievements.is_empty();
+		
 		let gameId = game.id;
 		
 		let iconPath = getImagePath(&FileLocation
@@ -137,6 +153,18 @@ impl Component for GameElement
 							.width(Size::flex(1.0))
 					)
 					
+					.child(
+						IconButton::new(lucide::download())
+							.alt("Export")
+							.onPress(move |_| if let Some(game) = user.read().getGame(gameId)
+							{
+								let export = AchievementExport::fromFilteredAchievements(&game, criteria.clone());
+								spawn(async move {
+									promptExport(export, &game.name).await;
+								});
+							})
+					)
+					
 					.child(
 						IconButton::new(lucide::refresh_ccw())
 							.alt("Refresh")
@@ -144,6 +172,38 @@ impl Component for GameElement
 					)
 			)
 			
+			.maybe_child(showStats.then(||
+				rect()
+					.direction(Direction::Horizontal)
+					.main_align(Alignment::Center)
+					.spacing(20.0)
+					.width(Size::percent(50.0))
+					
+					.child(
+						paragraph()
+							.span(
+								Span::new(format!("{} / {} ", retroPointsEarned, retroPointsPossible))
+									.font_size(12.0)
+									.font_weight(FontWeight::BOLD)
+							)
+							
+							.span(
+								Span::new("RetroPoints")
+									.color(Color::GRAY)
+									.font_size(12.0)
+									.font_weight(FontWeight::BOLD)
+							)
+					)
+					
+					.maybe_child(beatenCasual.isTracked().then(||
+						beatenProgressElement(beatenCasual, RetroAchievementsMode::Casual)
+					))
+					
+					.maybe_child(beatenHardcore.isTracked().then(||
+						beatenProgressElement(beatenHardcore, RetroAchievementsMode::Hardcore)
+					))
+			))
+			
 			.child(
 				AchievementsFilter::new(
 					caseSensitive,
@@ -152,6 +212,8 @@ impl Component for GameElement
 					search
 				)
 					.margin(Gaps::new(5.0, 0.0, 0.0, 0.0))
+					.missable(missable)
+					.progression(progression)
 					.width(Size::percent(50.0))
 			)
 			
@@ -186,3 +248,42 @@ impl GameElement
 		};
 	}
 }
+
+fn beatenProgressElement(progress: BeatenProgress, mode: RetroAchievementsMode) -> impl IntoElement
+{
+	let (modeName, fontWeight) = match mode
+	{
+		RetroAchievementsMode::Casual => ("Beaten (Casual)", FontWeight::NORMAL),
+		RetroAchievementsMode::Hardcore => ("Beaten", FontWeight::BOLD),
+	};
+	
+	let mut details = format!("{} / {} progression", progress.progressionUnlocked, progress.progressionTotal);
+	if progress.winConditionTotal > 0
+	{
+		details = match progress.winConditionUnlocked
+		{
+			false => format!("{}, win condition locked", details),
+			true => format!("{}, win condition unlocked", details),
+		};
+	}
+	
+	return paragraph()
+		.span(
+			Span::new(format!("{}: ", modeName))
+				.font_size(12.0)
+				.font_weight(fontWeight)
+		)
+		
+		.span(
+			Span::new(details)
+				.font_size(12.0)
+				.font_weight(fontWeight)
+		)
+		
+		.span(
+			Span::new(format!(" ({:.2}%)", progress.percent()))
+				.color(Color::GRAY)
+				.font_size(12.0)
+				.font_weight(fontWeight)
+		);
+}
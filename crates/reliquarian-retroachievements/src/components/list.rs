@@ -1,4 +1,6 @@
 use std::path::PathBuf;
+use components::button::icon::IconButton;
+use components::export::promptExport;
 use components::extensions::PressableExt;
 use components::input::filter::GamesFilter;
 use data::constants::{BorderColor, ButtonBackgroundColor, CornerRadius,
@@ -9,24 +11,28 @@ use data::constants::{BorderColor, ButtonBackgroundColor, CornerRadius,
 	RetroAchievementsProgressColorCasual,
 	RetroAchievementsProgressColorHardcore};
 use data::enums::GamePlatforms;
-use data::filter::{FilterCriteria, Filterable};
+use data::export::AchievementExport;
+use data::filter::FilterCriteria;
 use data::io::{FileLocation, filePathExists, getImagePath};
 use freya::icons::lucide;
 use freya::prelude::{AccessibilityExt, Alignment, Border, BorderAlignment,
 	ChildrenExt, Code, Color, Component, ContainerExt, ContainerSizeExt,
 	ContainerWithContentExt, Content, Direction, Event, EventHandlersExt,
 	FontWeight, Gaps, ImageViewer, IntoElement, KeyboardEventData, Layer,
-	LayerExt, Position, ProgressBar, ProgressBarThemePartialExt, ScrollConfig,
-	ScrollPosition, Size, Span, StyleExt, TextAlign, TextStyleExt,
-	VirtualScrollView, label, paragraph, rect, svg, use_scroll_controller,
-	use_state};
+	LayerExt, MenuItem, Position, ProgressBar, ProgressBarThemePartialExt,
+	ScrollConfig, ScrollPosition, Select, Size, Span, StyleExt, TextAlign,
+	TextStyleExt, VirtualScrollView, WritableUtils, label, paragraph, rect,
+	spawn, svg, use_scroll_controller, use_state};
 use freya::radio::use_radio;
 use macros::{join, png};
+use strum::IntoEnumIterator;
 use crate::api::RetroAchievementsApi;
 use crate::data::kind::AwardKind;
 use crate::data::mode::RetroAchievementsMode;
 use crate::data::settings::RetroAchievementsSettings;
+use crate::data::sort::RetroAchievementsSort;
 use crate::data::user::RetroAchievementsUser;
+use super::systems::SystemBrowser;
 
 #[derive(Clone, PartialEq)]
 pub struct GameList;
@@ -41,16 +47,33 @@ impl Component for GameList
 		let caseSensitive = use_state(bool::default);
 		let nameOnly = use_state(bool::default);
 		let search = use_state(String::default);
+		let mut showSystems = use_state(bool::default);
+		let mut sort = use_state(RetroAchievementsSort::default);
+		let mut system = use_state(|| None::<u64>);
+		let wantToPlay = use_state(bool::default);
 		
-		let games = user.read().filter(FilterCriteria
+		let criteria = FilterCriteria
 		{
 			caseSensitive: caseSensitive(),
 			nameOnly: nameOnly(),
 			text: search.read().clone(),
+			wantToPlay: wantToPlay(),
 			..Default::default()
-		});
+		};
+		
+		let games = user.read().filterAndSort(criteria.clone(), system(), sort());
 		let gamesLength = games.len();
 		
+		let systems = user.read().systemStats()
+			.into_iter()
+			.map(|s| s.system)
+			.collect::<Vec<_>>();
+		
+		let selectedSystemName = systems.iter()
+			.find(|s| Some(s.id) == system())
+			.map(|s| s.name.clone())
+			.unwrap_or("All".into());
+		
 		return rect()
 			.content(Content::Flex)
 			.cross_align(Alignment::Center)
@@ -76,13 +99,90 @@ impl Component for GameList
 			)
 			
 			.child(
-				GamesFilter::new(caseSensitive, search)
+				rect()
+					.content(Content::Flex)
+					.cross_align(Alignment::Center)
+					.direction(Direction::Horizontal)
 					.margin(Gaps::new(5.0, 0.0, 0.0, 0.0))
-					.nameOnly(nameOnly)
+					.spacing(10.0)
 					.width(Size::percent(50.0))
+					
+					.child(
+						GamesFilter::new(caseSensitive, search)
+							.nameOnly(nameOnly)
+							.wantToPlay(wantToPlay)
+							.width(Size::flex(1.0))
+					)
+					
+					.child(
+						IconButton::new(lucide::chart_bar())
+							.alt("Systems")
+							.onPress(move |_| showSystems.set(!showSystems()))
+					)
+					
+					.child(
+						IconButton::new(lucide::download())
+							.alt("Export")
+							.onPress(move |_| {
+								let export = AchievementExport::fromFilteredGames(&*user.read(), criteria.clone());
+								spawn(async move {
+									promptExport(export, "RetroAchievements").await;
+								});
+							})
+					)
 			)
 			
 			.child(
+				rect()
+					.cross_align(Alignment::Center)
+					.direction(Direction::Horizontal)
+					.main_align(Alignment::Center)
+					.spacing(10.0)
+					.width(Size::percent(50.0))
+					
+					.child("System")
+					
+					.child(
+						Select::new()
+							.selected_item(selectedSystemName)
+							
+							.child(
+								MenuItem::new()
+									.selected(system().is_none())
+									.on_press(move |_| system.set(None))
+									.child("All")
+							)
+							
+							.children(
+								systems.into_iter().map(|s| {
+									MenuItem::new()
+										.selected(system() == Some(s.id))
+										.on_press(move |_| system.set(Some(s.id)))
+										.child(s.name)
+										.into()
+								})
+							)
+					)
+					
+					.child("Sort By")
+					
+					.child(
+						Select::new()
+							.selected_item(sort().to_string())
+							
+							.children(
+								RetroAchievementsSort::iter().map(|s| {
+									MenuItem::new()
+										.selected(s == sort())
+										.on_press(move |_| sort.set(s))
+										.child(s.to_string())
+										.into()
+								})
+							)
+					)
+			)
+			
+			.maybe_child((!showSystems()).then(||
 				VirtualScrollView::new_controlled(
 					move |i, _| {
 						let game = &games[i];
@@ -95,7 +195,11 @@ impl Component for GameList
 					.item_size(105.0)
 					.length(gamesLength)
 					.scroll_with_arrows(true)
-			);
+			))
+			
+			.maybe_child(showSystems().then(||
+				SystemBrowser::new(system, showSystems)
+			));
 	}
 }
 
@@ -5,3 +5,4 @@ pub mod list;
 pub mod profile;
 pub mod refresh;
 pub mod settings;
+pub mod systems;
@@ -19,6 +19,7 @@ use net::{RateLimiter, RequestEvent};
 use crate::api::RetroAchievementsApi;
 use crate::data::kind::AwardKind;
 use crate::data::operation::RetroAchievementsOperation;
+use crate::data::settings::RetroAchievementsSettings;
 use crate::data::user::RetroAchievementsUser;
 
 #[derive(Clone, PartialEq)]
@@ -28,6 +29,7 @@ impl Component for RetroAchievementsUserProfile
 {
 	fn render(&self) -> impl IntoElement
 	{
+		let settings = use_radio::<RetroAchievementsSettings, GamePlatforms>(GamePlatforms::RetroAchievements);
 		let user = use_radio::<RetroAchievementsUser, GamePlatforms>(GamePlatforms::RetroAchievements);
 		let rateLimiter = use_radio::<RateLimiter, DataChannel>(DataChannel::RateLimiter);
 		let mut requestEvent = use_radio::<RequestEvent, DataChannel>(DataChannel::RateLimiter);
@@ -66,11 +68,9 @@ impl Component for RetroAchievementsUserProfile
 				if confirmed()
 				{
 					spawn(async move {
-						rateLimiter.read().pushAll(vec![
-							RetroAchievementsOperation::GetUserProfile.into(),
-							RetroAchievementsOperation::GetUserProgress(Default::default()).into(),
-							//GetUserProgress is recursive; it automatically pushes a SaveToFile operation when it is finished
-						]).await;
+						let incremental = settings.read().incrementalRefresh;
+						//The progress and want to play list operations are recursive; they automatically push a SaveToFile operation when they are finished
+						rateLimiter.read().pushAll(RetroAchievementsOperation::refreshRequests(incremental)).await;
 						
 						**requestEvent.write() = RequestEvent::Added;
 					});
@@ -197,7 +197,7 @@ impl RetroAchievementsUserProfile
 	}
 }
 
-fn awardElement(award: AwardKind, height: impl Into<Size>, value: usize) -> impl IntoElement
+pub fn awardElement(award: AwardKind, height: impl Into<Size>, value: usize) -> impl IntoElement
 {
 	let height = height.into();
 	
@@ -1,3 +1,4 @@
+use chrono::{TimeDelta, Utc};
 use data::constants::{FileName_GameIcon, Icon_Locked, Path_Avatars, Path_Games};
 use data::io::FileLocation;
 use macros::{join, png, pngAlt};
@@ -29,6 +30,20 @@ pub async fn handleRetroAchievementsOperation(
 				Some(result)
 			}
 			
+			RetroAchievementsOperation::GetRecentAchievements(minutes) => {
+				let result = refreshRecentAchievements(user, minutes);
+				info!("[RetroAchievements] Refreshed recent achievements");
+				
+				Some(result)
+			}
+			
+			RetroAchievementsOperation::GetRecentlyPlayed => {
+				let result = refreshRecentlyPlayed(user);
+				info!("[RetroAchievements] Refreshed recently played games");
+				
+				Some(result)
+			}
+			
 			RetroAchievementsOperation::GetUserProfile => {
 				let result = refreshUserProfile(user);
 				info!("[RetroAchievements] Refreshed user profile");
@@ -37,12 +52,26 @@ pub async fn handleRetroAchievementsOperation(
 			}
 			
 			RetroAchievementsOperation::GetUserProgress(state) => {
-				let result = refreshUserProgress(user, state.clone());
+				let result = refreshUserProgress(user, state.clone(), false);
 				info!("[RetroAchievements] Refreshed user progress");
 				
 				Some(result)
 			}
 			
+			RetroAchievementsOperation::GetUserProgressIncremental(state) => {
+				let result = refreshUserProgress(user, state.clone(), true);
+				info!("[RetroAchievements] Refreshed user progress incrementally");
+				
+				Some(result)
+			}
+			
+			RetroAchievementsOperation::GetWantToPlayList(state) => {
+				let result = refreshWantToPlayList(user, state.clone());
+				info!("[RetroAchievements] Refreshed want to play list");
+				
+				Some(result)
+			}
+			
 			RetroAchievementsOperation::SaveToFile => {
 				match saveUserData(&user)
 				{
@@ -58,6 +87,7 @@ pub async fn handleRetroAchievementsOperation(
 
 fn refreshUserProfile(mut user: RetroAchievementsUser) -> RetroAchievementsResult
 {
+	let mut failed = true;
 	let mut requests = vec![];
 	
 	if getRetroAchievementsAuth().is_ok_and(|a| a.isValid())
@@ -68,6 +98,8 @@ fn refreshUserProfile(mut user: RetroAchievementsUser) -> RetroAchievementsResul
 			Err(e) => warn!("[RetroAchievements] {:?}", e),
 			
 			Ok(payload) => {
+				failed = false;
+				
 				user.processUserProfile(&payload);
 				
 				if let Some(ulid) = ulid
@@ -94,16 +126,32 @@ fn refreshUserProfile(mut user: RetroAchievementsUser) -> RetroAchievementsResul
 	
 	return RetroAchievementsResult
 	{
+		failed,
 		user,
 		requests,
 	};
 }
 
+/**
+Retrieve a page of the user's progress, queueing the next page until every
+game has been received.
+
+The details of every game whose achievements have already been retrieved,
+but are out of date, are retrieved again. When `incremental` is set, the
+details of every other game which has unlocks are retrieved as well.
+
+An incremental refresh also relies on the games being received in order of
+their most recent unlock, so once a game which has not changed is received no
+further pages are retrieved. If a page is not in that order, every page is
+retrieved instead.
+*/
 fn refreshUserProgress(
 	mut user: RetroAchievementsUser,
-	mut state: RetroAchievementsProgressState
+	mut state: RetroAchievementsProgressState,
+	incremental: bool
 ) -> RetroAchievementsResult
 {
+	let mut failed = true;
 	let mut requests = vec![];
 	
 	if getRetroAchievementsAuth().is_ok_and(|a| a.isValid())
@@ -113,38 +161,164 @@ fn refreshUserProgress(
 			Err(e) => warn!("[RetroAchievements] Error retrieving user completion progress: {:?}", e),
 			
 			Ok(payload) => {
+				failed = false;
+				
 				// Update progress state
 				state.received += payload.Count;
 				state.offset += payload.Count;
 				state.total = payload.Total;
 				
-				user.processUserCompletionProgress(&payload);
+				let changed = user.processUserCompletionProgress(&payload);
+				
+				for gameId in changed.iter()
+				{
+					let loaded = user.getGame(*gameId)
+						.is_some_and(|g| !g.achievements.is_empty());
+					
+					if incremental || loaded
+					{
+						requests.push(RetroAchievementsOperation::GetGameInfo(*gameId).into());
+					}
+				}
+				
+				let reachedEnd = state.reachedEnd(RetroAchievementsApi::GetUserGameCompletion_Count)
+					|| (incremental
+						&& changed.len() < payload.Results.len()
+						&& payload.isSortedByMostRecentAward());
 				
-				match state.reachedEnd()
+				match reachedEnd
 				{
-					false => requests.push(RetroAchievementsOperation::GetUserProgress(state).into()),
+					false => requests.push(match incremental
+					{
+						false => RetroAchievementsOperation::GetUserProgress(state),
+						true => RetroAchievementsOperation::GetUserProgressIncremental(state),
+					}.into()),
+					
 					true => requests.push(RetroAchievementsOperation::SaveToFile.into()),
 				}
 				
 				// Cache game icons
-				let fileName = png!(FileName_GameIcon);
-				let platform = RetroAchievementsApi::Platform.to_string();
+				for game in payload.Results
+				{
+					if let Some(request) = gameIconRequest(game.GameID, &game.ImageIcon)
+					{
+						requests.push(request);
+					}
+				}
+			}
+		}
+	}
+	
+	return RetroAchievementsResult
+	{
+		failed,
+		user,
+		requests,
+	};
+}
+
+fn refreshRecentAchievements(mut user: RetroAchievementsUser, minutes: Option<u64>) -> RetroAchievementsResult
+{
+	let mut failed = true;
+	let mut requests = vec![];
+	
+	if getRetroAchievementsAuth().is_ok_and(|a| a.isValid())
+	{
+		let since = Utc::now() - TimeDelta::minutes(
+			minutes.unwrap_or(RetroAchievementsApi::GetUserRecentAchievements_DefaultMinutes) as i64
+		);
+		
+		match RetroAchievementsApi::getUserRecentAchievements(user.ulid.clone(), minutes)
+		{
+			Err(e) => warn!("[RetroAchievements] {:?}", e),
+			
+			Ok(payload) => {
+				failed = false;
+				
+				user.processRecentAchievements(&payload, since);
+				requests.push(RetroAchievementsOperation::SaveToFile.into());
+			}
+		}
+	}
+	
+	return RetroAchievementsResult
+	{
+		failed,
+		user,
+		requests,
+	};
+}
+
+fn refreshRecentlyPlayed(mut user: RetroAchievementsUser) -> RetroAchievementsResult
+{
+	let mut failed = true;
+	let mut requests = vec![];
+	
+	if getRetroAchievementsAuth().is_ok_and(|a| a.isValid())
+	{
+		match RetroAchievementsApi::getUserRecentlyPlayedGames(user.ulid.clone())
+		{
+			Err(e) => warn!("[RetroAchievements] {:?}", e),
+			
+			Ok(payload) => {
+				failed = false;
+				
+				user.processRecentlyPlayedGames(&payload);
+				requests.push(RetroAchievementsOperation::SaveToFile.into());
+				
+				for game in payload
+				{
+					if let Some(request) = gameIconRequest(game.GameID, &game.ImageIcon)
+					{
+						requests.push(request);
+					}
+				}
+			}
+		}
+	}
+	
+	return RetroAchievementsResult
+	{
+		failed,
+		user,
+		requests,
+	};
+}
+
+fn refreshWantToPlayList(
+	mut user: RetroAchievementsUser,
+	mut state: RetroAchievementsProgressState
+) -> RetroAchievementsResult
+{
+	let mut failed = true;
+	let mut requests = vec![];
+	
+	if getRetroAchievementsAuth().is_ok_and(|a| a.isValid())
+	{
+		match RetroAchievementsApi::getUserWantToPlayList(user.ulid.clone(), Some(state.offset))
+		{
+			Err(e) => warn!("[RetroAchievements] Error retrieving want to play list: {:?}", e),
+			
+			Ok(payload) => {
+				failed = false;
+				
+				user.processWantToPlayList(&payload, state.offset == 0);
+				
+				state.received += payload.Count;
+				state.offset += payload.Count;
+				state.total = payload.Total;
+				
+				match state.reachedEnd(RetroAchievementsApi::GetUserWantToPlayList_Count)
+				{
+					false => requests.push(RetroAchievementsOperation::GetWantToPlayList(state).into()),
+					true => requests.push(RetroAchievementsOperation::SaveToFile.into()),
+				}
 				
 				for game in payload.Results
 				{
-					if let Some(url) = RetroAchievementsApi::buildMediaUrl(&makeRelative(&game.ImageIcon))
+					if let Some(request) = gameIconRequest(game.ID, &game.ImageIcon)
 					{
-						requests.push(DataRequest
-						{
-							destination: Some(FileLocation
-							{
-								fileName: fileName.clone(),
-								group: join!(Path_Games, game.GameID.to_string()),
-								platform: platform.clone(),
-							}),
-							operation: DataOperation::CacheImage(false),
-							url: Some(url),
-						});
+						requests.push(request);
 					}
 				}
 			}
@@ -153,6 +327,7 @@ fn refreshUserProgress(
 	
 	return RetroAchievementsResult
 	{
+		failed,
 		user,
 		requests,
 	};
@@ -160,6 +335,7 @@ fn refreshUserProgress(
 
 fn refreshGameInfo(mut user: RetroAchievementsUser, id: u64) -> RetroAchievementsResult
 {
+	let mut failed = true;
 	let mut requests = vec![];
 	
 	if getRetroAchievementsAuth().is_ok_and(|a| a.isValid())
@@ -175,6 +351,8 @@ fn refreshGameInfo(mut user: RetroAchievementsUser, id: u64) -> RetroAchievement
 			Err(e) => warn!("[RetroAchievements] {:?}", e),
 			
 			Ok(payload) => {
+				failed = false;
+				
 				match user.games.iter_mut()
 					.find(|g| g.id == id)
 				{
@@ -243,7 +421,24 @@ fn refreshGameInfo(mut user: RetroAchievementsUser, id: u64) -> RetroAchievement
 	
 	return RetroAchievementsResult
 	{
+		failed,
 		user,
 		requests,
 	};
 }
+
+fn gameIconRequest(gameId: u64, imageIcon: &String) -> Option<DataRequest>
+{
+	return RetroAchievementsApi::buildMediaUrl(&makeRelative(imageIcon))
+		.map(|url| DataRequest
+		{
+			destination: Some(FileLocation
+			{
+				fileName: png!(FileName_GameIcon),
+				group: join!(Path_Games, gameId.to_string()),
+				platform: RetroAchievementsApi::Platform.to_string(),
+			}),
+			operation: DataOperation::CacheImage(false),
+			url: Some(url),
+		});
+}
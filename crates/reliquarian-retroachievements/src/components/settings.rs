@@ -26,6 +26,7 @@ impl Component for RetroAchievementsSettingsElement
 		let mut settings = use_radio::<RetroAchievementsSettings, GamePlatforms>(GamePlatforms::RetroAchievements);
 		
 		let mut apiKey = use_state(String::default);
+		let mut incrementalRefresh = use_state(|| settings.read().incrementalRefresh);
 		let inputModeApiKey = use_state(|| InputMode::Hidden(InputModeHiddenChar));
 		let inputModeUsername = use_state(|| InputMode::Hidden(InputModeHiddenChar));
 		let mut showGameAwards = use_state(|| settings.read().showGameAwardBadges);
@@ -35,9 +36,11 @@ impl Component for RetroAchievementsSettingsElement
 			_ = setRetroAchievementsApiKey(apiKey.read().clone());
 			_ = setRetroAchievementsUsername(username.read().clone());
 			
+			let incremental = incrementalRefresh();
 			let toggle = showGameAwards();
-			if settings.read().showGameAwardBadges != toggle
+			if settings.read().incrementalRefresh != incremental || settings.read().showGameAwardBadges != toggle
 			{
+				settings.write().incrementalRefresh = incremental;
 				settings.write().showGameAwardBadges = toggle;
 				match saveSettings(&settings.read())
 				{
@@ -171,6 +174,26 @@ impl Component for RetroAchievementsSettingsElement
 									})
 							)
 					)
+					
+					.child(
+						rect()
+							.cross_align(Alignment::Center)
+							.direction(Direction::Horizontal)
+							.main_align(Alignment::Center)
+							.spacing(10.0)
+							.width(Size::flex(1.0))
+							
+							.child("Only Refresh Changed Games")
+							
+							.child(
+								Switch::new()
+									.toggled(*incrementalRefresh.read())
+									.on_toggle(move |_| {
+										let value = *incrementalRefresh.read();
+										*incrementalRefresh.write() = !value;
+									})
+							)
+					)
 			);
 	}
 }
@@ -0,0 +1,209 @@
+use components::extensions::PressableExt;
+use data::constants::{BorderColor, ButtonBackgroundColor, CornerRadius};
+use data::enums::GamePlatforms;
+use freya::prelude::{Alignment, Border, BorderAlignment, ChildrenExt, Color,
+	Component, ContainerExt, ContainerSizeExt, ContainerWithContentExt, Content,
+	Direction, Gaps, IntoElement, ScrollView, Size, Span, StyleExt, TextAlign,
+	TextStyleExt, WritableUtils, label, paragraph, rect, use_state};
+use freya::radio::{Writable, use_radio};
+use crate::data::kind::AwardKind;
+use crate::data::stats::SystemStats;
+use crate::data::user::RetroAchievementsUser;
+use super::profile::awardElement;
+
+/**
+Every system on which the user has played games, along with the totals for
+each. Pressing a system selects it and returns to the list of games.
+*/
+#[derive(Clone, PartialEq)]
+pub struct SystemBrowser
+{
+	selectedSystem: Writable<Option<u64>>,
+	showSystems: Writable<bool>,
+}
+
+impl Component for SystemBrowser
+{
+	fn render(&self) -> impl IntoElement
+	{
+		let user = use_radio::<RetroAchievementsUser, GamePlatforms>(GamePlatforms::RetroAchievements);
+		
+		let stats = user.read().systemStats();
+		let selectedSystem = self.selectedSystem.clone();
+		let showSystems = self.showSystems.clone();
+		
+		return ScrollView::new()
+			.height(Size::flex(1.0))
+			.spacing(10.0)
+			
+			.children(
+				stats.into_iter().map(|s| {
+					SystemNode::new(s, selectedSystem.clone(), showSystems.clone()).into()
+				})
+			);
+	}
+}
+
+impl SystemBrowser
+{
+	pub fn new(
+		selectedSystem: impl Into<Writable<Option<u64>>>,
+		showSystems: impl Into<Writable<bool>>
+	) -> Self
+	{
+		return Self
+		{
+			selectedSystem: selectedSystem.into(),
+			showSystems: showSystems.into(),
+		};
+	}
+}
+
+#[derive(Clone, PartialEq)]
+struct SystemNode
+{
+	selectedSystem: Writable<Option<u64>>,
+	showSystems: Writable<bool>,
+	stats: SystemStats,
+}
+
+impl Component for SystemNode
+{
+	fn render(&self) -> impl IntoElement
+	{
+		let hovering = use_state(|| false);
+		
+		let background = match hovering()
+		{
+			false => Color::TRANSPARENT,
+			true => ButtonBackgroundColor,
+		};
+		
+		let height = Size::px(16.0);
+		let stats = self.stats.clone();
+		let systemId = stats.system.id;
+		
+		let mut selectedSystem = self.selectedSystem.clone();
+		let mut showSystems = self.showSystems.clone();
+		
+		return rect()
+			.direction(Direction::Horizontal)
+			.main_align(Alignment::SpaceAround)
+			.width(Size::Fill)
+			
+			.child(
+				rect()
+					.background(background)
+					.border(Some(
+						Border::new()
+							.alignment(BorderAlignment::Center)
+							.fill(BorderColor)
+							.width(1.0)
+					))
+					.content(Content::Flex)
+					.corner_radius(CornerRadius)
+					.cross_align(Alignment::Center)
+					.direction(Direction::Horizontal)
+					.min_width(Size::px(540.0))
+					.padding(Gaps::new_symmetric(10.0, 15.0))
+					.spacing(10.0)
+					.width(Size::percent(50.0))
+					
+					.pressableWithHover(
+						hovering,
+						move |_| {
+							selectedSystem.set(Some(systemId));
+							showSystems.set(false);
+						}
+					)
+					
+					.child(
+						rect()
+							.direction(Direction::Vertical)
+							.spacing(5.0)
+							.width(Size::flex(1.0))
+							
+							.child(
+								label()
+									.font_size(18.0)
+									.text(stats.system.name.clone())
+							)
+							
+							.child(
+								rect()
+									.cross_align(Alignment::Center)
+									.direction(Direction::Horizontal)
+									.height(height.clone())
+									.spacing(5.0)
+									
+									.maybe_child((stats.beatenCasual > 0).then(||
+										awardElement(AwardKind::BeatenCasual, height.clone(), stats.beatenCasual)
+									))
+									
+									.maybe_child((stats.beatenHardcore > 0).then(||
+										awardElement(AwardKind::BeatenHardcore, height.clone(), stats.beatenHardcore)
+									))
+									
+									.maybe_child((stats.completed > 0).then(||
+										awardElement(AwardKind::Completed, height.clone(), stats.completed)
+									))
+									
+									.maybe_child((stats.mastered > 0).then(||
+										awardElement(AwardKind::Mastered, height.clone(), stats.mastered)
+									))
+							)
+					)
+					
+					.child(
+						rect()
+							.cross_align(Alignment::End)
+							.direction(Direction::Vertical)
+							.spacing(5.0)
+							
+							.child(
+								label()
+									.font_size(12.0)
+									.text_align(TextAlign::End)
+									.text(match stats.games
+									{
+										1 => "1 game".to_string(),
+										count => format!("{} games", count),
+									})
+							)
+							
+							.child(
+								paragraph()
+									.text_align(TextAlign::End)
+									
+									.span(
+										Span::new(stats.pointsHardcore.to_string())
+											.font_size(12.0)
+									)
+									
+									.span(
+										Span::new(" Hardcore points from loaded games")
+											.color(Color::GRAY)
+											.font_size(12.0)
+									)
+							)
+					)
+			);
+	}
+}
+
+impl SystemNode
+{
+	fn new(
+		stats: SystemStats,
+		selectedSystem: Writable<Option<u64>>,
+		showSystems: Writable<bool>
+	) -> Self
+	{
+		return Self
+		{
+			selectedSystem,
+			showSystems,
+			stats,
+		};
+	}
+}
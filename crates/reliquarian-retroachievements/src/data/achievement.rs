@@ -1,18 +1,30 @@
 use std::cmp::Ordering;
 use std::io::ErrorKind;
 use anyhow::{anyhow, Result};
-use chrono::NaiveDateTime;
+use chrono::{DateTime, NaiveDateTime, Utc};
 use data::constants::{Format_ChronoDateTime, TheString};
+use data::enums::GamePlatforms;
+use data::unified::{RetroAchievementsExtension, Unifiable, UnifiedAchievement,
+	UnifiedExtension};
 use serde::{Deserialize, Serialize};
 use serde_json::{Map, Value};
 use crate::api::AchievementMetadata;
 
 use super::makeRelative;
+use super::achievementtype::AchievementType;
 use super::mode::RetroAchievementsMode;
 
 #[derive(Clone, Debug, Default, Deserialize, Eq, Hash, PartialEq, Ord, Serialize)]
 pub struct Achievement
 {
+	/// The role of the achievement in the set, if it has been assigned one.
+	#[serde(default)]
+	pub achievementType: Option<AchievementType>,
+	
+	/// The username of the achievement's developer.
+	#[serde(default)]
+	pub author: String,
+	
 	/// Number of users who have unlocked the achievement in Casual mode.
 	#[serde(default)]
 	pub awardedCasual: u64,
@@ -21,6 +33,10 @@ pub struct Achievement
 	#[serde(default)]
 	pub awardedHardcore: u64,
 	
+	/// The timestamp when the achievement was first published.
+	#[serde(default)]
+	pub dateCreated: Option<String>,
+	
 	/// Description of the achievement.
 	#[serde(default)]
 	pub description: String,
@@ -51,6 +67,10 @@ pub struct Achievement
 	/// The amount of points gained when unlocking the achievement.
 	#[serde(default)]
 	pub points: u64,
+	
+	/// The points weighted by how rarely the achievement is unlocked in Hardcore mode.
+	#[serde(default)]
+	pub trueRatio: u64,
 }
 
 impl From<AchievementMetadata> for Achievement
@@ -101,8 +121,58 @@ impl PartialOrd for Achievement
 	}
 }
 
+impl Unifiable<UnifiedAchievement> for Achievement
+{
+	fn unify(&self) -> UnifiedAchievement
+	{
+		let hardcore = self.unlocked(RetroAchievementsMode::Hardcore);
+		
+		return UnifiedAchievement
+		{
+			description: self.description.clone(),
+			extension: UnifiedExtension::RetroAchievements(RetroAchievementsExtension
+			{
+				hardcore,
+				points: self.points,
+			}),
+			hidden: false,
+			id: self.id.to_string(),
+			name: self.name.clone(),
+			platform: GamePlatforms::RetroAchievements,
+			rarity: None,
+			unlocked: hardcore || self.unlocked(RetroAchievementsMode::Casual),
+			unlockedTimestamp: self.earnedTimestamp(match hardcore
+			{
+				false => RetroAchievementsMode::Casual,
+				true => RetroAchievementsMode::Hardcore,
+			}),
+		};
+	}
+}
+
 impl Achievement
 {
+	/**
+	The moment when the achievement was unlocked in the given `mode`, if it has
+	been unlocked.
+	*/
+	pub fn earnedTimestamp(&self, mode: RetroAchievementsMode) -> Option<DateTime<Utc>>
+	{
+		let timestamp = match mode
+		{
+			RetroAchievementsMode::Casual => &self.earnedTimestampCasual,
+			RetroAchievementsMode::Hardcore => &self.earnedTimestampHardcore,
+		};
+		
+		return match timestamp
+		{
+			None => None,
+			Some(ts) => self.parseTimestamp(ts)
+				.ok()
+				.map(|dt| dt.and_utc()),
+		};
+	}
+	
 	pub fn formatEarnedTimestamp(&self, mode: RetroAchievementsMode) -> Result<String>
 	{
 		if let Some(timestamp) = match mode {
@@ -117,10 +187,37 @@ impl Achievement
 		return Err(anyhow!(ErrorKind::NotFound));
 	}
 	
+	pub fn isMissable(&self) -> bool
+	{
+		return self.achievementType == Some(AchievementType::Missable);
+	}
+	
+	/// Whether the achievement must be unlocked to reach the Beaten award.
+	pub fn isProgression(&self) -> bool
+	{
+		return self.achievementType.is_some_and(|t| t.isProgression());
+	}
+	
 	pub fn parseJsonMap(map: &Map<String, Value>) -> Option<Self>
 	{
 		let mut achievement = Self::default();
 		
+		if let Some((_, value)) = map.iter()
+			.find(|(key, _)| key.as_str() == "achievementType")
+		{
+			achievement.achievementType = serde_json::from_value(value.clone())
+				.unwrap_or_default();
+		}
+		
+		if let Some((_, value)) = map.iter()
+			.find(|(key, _)| key.as_str() == "author")
+		{
+			if let Value::String(inner) = value
+			{
+				achievement.author = inner.clone();
+			}
+		}
+		
 		if let Some((_, value)) = map.iter()
 			.find(|(key, _)| key.as_str() == "awardedCasual")
 		{
@@ -145,6 +242,18 @@ impl Achievement
 			}
 		}
 		
+		if let Some((_, value)) = map.iter()
+			.find(|(key, _)| key.as_str() == "dateCreated")
+		{
+			if let Value::String(inner) = value
+			{
+				if !inner.is_empty()
+				{
+					achievement.dateCreated = Some(inner.clone());
+				}
+			}
+		}
+		
 		if let Some((_, value)) = map.iter()
 			.find(|(key, _)| key.as_str() == "description")
 		{
@@ -232,6 +341,18 @@ impl Achievement
 			}
 		}
 		
+		if let Some((_, value)) = map.iter()
+			.find(|(key, _)| key.as_str() == "trueRatio")
+		{
+			if let Value::Number(inner) = value
+			{
+				if let Some(number) = inner.as_u64()
+				{
+					achievement.trueRatio = number;
+				}
+			}
+		}
+		
 		return match achievement.id
 		{
 			0 => None,
@@ -287,8 +408,16 @@ impl Achievement
 	
 	pub fn update(&mut self, achievement: &AchievementMetadata)
 	{
+		self.achievementType = achievement.Type.as_deref()
+			.and_then(AchievementType::parse);
+		self.author = achievement.Author.clone();
 		self.awardedCasual = achievement.NumAwarded;
 		self.awardedHardcore = achievement.NumAwardedHardcore;
+		self.dateCreated = match achievement.DateCreated.is_empty()
+		{
+			false => Some(achievement.DateCreated.clone()),
+			true => None,
+		};
 		self.description = achievement.Description.clone();
 		self.displayOrder = achievement.DisplayOrder;
 		self.earnedTimestampHardcore = achievement.DateEarnedHardcore.clone();
@@ -297,6 +426,7 @@ impl Achievement
 		self.id = achievement.ID;
 		self.name = achievement.Title.clone();
 		self.points = achievement.Points;
+		self.trueRatio = achievement.TrueRatio;
 	}
 }
 
@@ -309,8 +439,11 @@ mod tests
 	{
 		let mut map = Map::new();
 		
+		map.insert("achievementType".into(), "Progression".into());
+		map.insert("author".into(), "The author".into());
 		map.insert("awardedCasual".into(), 25.into());
 		map.insert("awardedHardcore".into(), 5.into());
+		map.insert("dateCreated".into(), "2013-12-03 05:24:51".into());
 		map.insert("description".into(), "The description".into());
 		map.insert("displayOrder".into(), 1.into());
 		map.insert("earnedTimestampCasual".into(), "The timestamp".into());
@@ -324,6 +457,7 @@ mod tests
 		map.insert("icon".into(), "The icon".into());
 		map.insert("name".into(), "The name".into());
 		map.insert("points".into(), 15.into());
+		map.insert("trueRatio".into(), 42.into());
 		
 		return map;
 	}
@@ -340,8 +474,11 @@ mod tests
 		assert_ne!(success, None);
 		
 		let achievement = success.unwrap();
+		assert_eq!(achievement.achievementType, Some(AchievementType::Progression));
+		assert_eq!(achievement.author, "The author".to_string());
 		assert_eq!(achievement.awardedCasual, 25);
 		assert_eq!(achievement.awardedHardcore, 5);
+		assert_eq!(achievement.dateCreated, Some("2013-12-03 05:24:51".to_string()));
 		assert_eq!(achievement.description, "The description".to_string());
 		assert_eq!(achievement.displayOrder, 1);
 		assert_eq!(achievement.earnedTimestampCasual, Some("The timestamp".to_string()));
@@ -350,5 +487,22 @@ mod tests
 		assert_eq!(achievement.id, 2);
 		assert_eq!(achievement.name, "The name".to_string());
 		assert_eq!(achievement.points, 15);
+		assert_eq!(achievement.trueRatio, 42);
+	}
+	
+	#[test]
+	fn earnedTimestamp()
+	{
+		let mut achievement = Achievement::default();
+		assert_eq!(achievement.earnedTimestamp(RetroAchievementsMode::Casual), None);
+		
+		achievement.earnedTimestampCasual = Some("2024-10-11 01:58:38".into());
+		achievement.earnedTimestampHardcore = Some("Not a timestamp".into());
+		
+		assert_eq!(
+			achievement.earnedTimestamp(RetroAchievementsMode::Casual),
+			DateTime::from_timestamp(1728611918, 0)
+		);
+		assert_eq!(achievement.earnedTimestamp(RetroAchievementsMode::Hardcore), None);
 	}
 }
@@ -0,0 +1,46 @@
+use serde::{Deserialize, Serialize};
+use strum_macros::Display;
+
+/**
+The role an achievement plays in a set, as assigned by the set's developers.
+
+Achievements without a type are neither required to beat the game nor at risk
+of being missed.
+*/
+#[derive(Clone, Copy, Debug, Deserialize, Display, Eq, Hash, PartialEq, PartialOrd, Ord, Serialize)]
+pub enum AchievementType
+{
+	/// Can be permanently locked out during a playthrough.
+	Missable,
+	
+	/// Required to beat the game.
+	Progression,
+	
+	/// Completes the game, once every progression achievement has been unlocked.
+	#[strum(to_string="Win Condition")]
+	WinCondition,
+}
+
+impl AchievementType
+{
+	/// Whether the achievement counts toward the Beaten award.
+	pub fn isProgression(&self) -> bool
+	{
+		return match self
+		{
+			Self::Missable => false,
+			Self::Progression | Self::WinCondition => true,
+		};
+	}
+	
+	pub fn parse(value: &str) -> Option<Self>
+	{
+		return match value
+		{
+			"missable" => Some(Self::Missable),
+			"progression" => Some(Self::Progression),
+			"win_condition" => Some(Self::WinCondition),
+			_ => None,
+		};
+	}
+}
@@ -0,0 +1,158 @@
+use data::format::truncateF32;
+use super::achievement::Achievement;
+use super::achievementtype::AchievementType;
+use super::mode::RetroAchievementsMode;
+
+/**
+Progress toward the Beaten award of a single game in a single mode.
+
+A game is beaten once every progression achievement, and at least one win
+condition achievement, has been unlocked. Progress can only be tracked for sets
+whose achievements have been assigned types.
+*/
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub struct BeatenProgress
+{
+	pub progressionTotal: u64,
+	pub progressionUnlocked: u64,
+	pub winConditionTotal: u64,
+	pub winConditionUnlocked: bool,
+}
+
+impl BeatenProgress
+{
+	pub fn new(achievements: &[Achievement], mode: RetroAchievementsMode) -> Self
+	{
+		let mut progress = Self::default();
+		
+		for achievement in achievements
+		{
+			match achievement.achievementType
+			{
+				Some(AchievementType::Progression) => {
+					progress.progressionTotal += 1;
+					if achievement.unlocked(mode)
+					{
+						progress.progressionUnlocked += 1;
+					}
+				},
+				
+				Some(AchievementType::WinCondition) => {
+					progress.winConditionTotal += 1;
+					progress.winConditionUnlocked |= achievement.unlocked(mode);
+				},
+				
+				_ => {},
+			}
+		}
+		
+		return progress;
+	}
+	
+	pub fn isBeaten(&self) -> bool
+	{
+		return self.isTracked() && self.completed() >= self.required();
+	}
+	
+	/// Whether the set contains any achievements which count toward the Beaten award.
+	pub fn isTracked(&self) -> bool
+	{
+		return self.required() > 0;
+	}
+	
+	pub fn percent(&self) -> f32
+	{
+		return match self.required()
+		{
+			0 => 0.0,
+			required => truncateF32(self.completed() as f32 / required as f32 * 100.0, 2),
+		};
+	}
+	
+	/// The progression achievements, plus a single win condition, which have been unlocked.
+	fn completed(&self) -> u64
+	{
+		return self.progressionUnlocked + self.winConditionUnlocked as u64;
+	}
+	
+	/// Every progression achievement is required, but only one win condition.
+	fn required(&self) -> u64
+	{
+		return self.progressionTotal + self.winConditionTotal.min(1);
+	}
+}
+
+#[cfg(test)]
+mod tests
+{
+	use serde_json::{Map, Value};
+	use super::*;
+	
+	const BeatenProgressJson: &str = r#"[
+		{ "id": 1, "name": "Untyped", "earnedTimestampCasual": "2024-10-11 01:58:38", "earnedTimestampHardcore": "2024-10-11 01:58:38" },
+		{ "id": 2, "name": "Missable", "achievementType": "Missable", "earnedTimestampCasual": "2024-10-11 01:58:38", "earnedTimestampHardcore": null },
+		{ "id": 3, "name": "Progression A", "achievementType": "Progression", "earnedTimestampCasual": "2024-10-11 01:58:38", "earnedTimestampHardcore": "2024-10-11 01:58:38" },
+		{ "id": 4, "name": "Progression B", "achievementType": "Progression", "earnedTimestampCasual": "2024-10-11 01:58:38", "earnedTimestampHardcore": null },
+		{ "id": 5, "name": "Progression C", "achievementType": "Progression", "earnedTimestampCasual": null, "earnedTimestampHardcore": null },
+		{ "id": 6, "name": "Win Condition A", "achievementType": "WinCondition", "earnedTimestampCasual": null, "earnedTimestampHardcore": null },
+		{ "id": 7, "name": "Win Condition B", "achievementType": "WinCondition", "earnedTimestampCasual": "2024-10-11 01:58:38", "earnedTimestampHardcore": null }
+	]"#;
+	
+	const BeatenJson: &str = r#"[
+		{ "id": 1, "name": "Progression", "achievementType": "Progression", "earnedTimestampCasual": "2024-10-11 01:58:38", "earnedTimestampHardcore": "2024-10-11 01:58:38" },
+		{ "id": 2, "name": "Win Condition", "achievementType": "WinCondition", "earnedTimestampCasual": "2024-10-11 01:58:38", "earnedTimestampHardcore": "2024-10-11 01:58:38" }
+	]"#;
+	
+	fn parseAchievements(json: &str) -> Vec<Achievement>
+	{
+		return serde_json::from_str::<Vec<Map<String, Value>>>(json)
+			.unwrap()
+			.iter()
+			.filter_map(Achievement::parseJsonMap)
+			.collect();
+	}
+	
+	#[test]
+	fn new()
+	{
+		let achievements = parseAchievements(BeatenProgressJson);
+		assert_eq!(achievements.len(), 7);
+		
+		let casual = BeatenProgress::new(&achievements, RetroAchievementsMode::Casual);
+		assert_eq!(casual, BeatenProgress
+		{
+			progressionTotal: 3,
+			progressionUnlocked: 2,
+			winConditionTotal: 2,
+			winConditionUnlocked: true,
+		});
+		assert!(!casual.isBeaten());
+		assert_eq!(casual.percent(), 75.0);
+		
+		let hardcore = BeatenProgress::new(&achievements, RetroAchievementsMode::Hardcore);
+		assert_eq!(hardcore.progressionUnlocked, 1);
+		assert!(!hardcore.winConditionUnlocked);
+		assert_eq!(hardcore.percent(), 25.0);
+	}
+	
+	#[test]
+	fn isBeaten()
+	{
+		let untyped = parseAchievements(BeatenProgressJson);
+		let untracked = BeatenProgress::new(&untyped[..1], RetroAchievementsMode::Casual);
+		assert!(!untracked.isTracked());
+		assert!(!untracked.isBeaten());
+		assert_eq!(untracked.percent(), 0.0);
+		
+		let achievements = parseAchievements(BeatenJson);
+		assert_eq!(achievements.len(), 2);
+		
+		let beaten = BeatenProgress::new(&achievements, RetroAchievementsMode::Hardcore);
+		assert!(beaten.isBeaten());
+		assert_eq!(beaten.percent(), 100.0);
+		
+		// Sets with only progression achievements are beaten once they are all unlocked
+		let progressionOnly = BeatenProgress::new(&achievements[..1], RetroAchievementsMode::Hardcore);
+		assert!(progressionOnly.isBeaten());
+	}
+}
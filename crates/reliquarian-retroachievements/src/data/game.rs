@@ -1,12 +1,19 @@
 use std::cmp::Ordering;
-use data::constants::TheString;
+use chrono::{DateTime, NaiveDateTime, Utc};
+use data::constants::{FileName_GameIcon, Path_Games, TheString};
+use data::enums::GamePlatforms;
 use data::filter::{FilterCriteria, Filterable};
 use data::format::truncateF32;
+use data::io::FileLocation;
+use data::unified::{Unifiable, UnifiedGame};
+use macros::{join, png};
 use serde::{Deserialize, Serialize};
 use serde_json::{Map, Value};
-use crate::api::{Payload_GetGameInfo, GameMetadata};
+use crate::api::{GameMetadata, Payload_GetGameInfo, RecentAchievementMetadata,
+	RecentlyPlayedMetadata, RetroAchievementsApi, WantToPlayMetadata};
 use super::makeRelative;
 use super::achievement::Achievement;
+use super::beaten::BeatenProgress;
 use super::kind::AwardKind;
 use super::mode::RetroAchievementsMode;
 use super::system::System;
@@ -56,6 +63,10 @@ pub struct Game
 	/// The GameID of the game.
 	pub id: u64,
 	
+	/// The timestamp when the user last played the game.
+	#[serde(default)]
+	pub lastPlayed: Option<String>,
+	
 	/// The timestamp of the most recently unlocked achievement.
 	#[serde(default)]
 	pub mostRecentTimestamp: Option<String>,
@@ -71,6 +82,10 @@ pub struct Game
 	/// The total number of achievements in the set.
 	#[serde(default)]
 	pub total: u64,
+	
+	/// Whether the game is on the user's Want to Play list.
+	#[serde(default)]
+	pub wantToPlay: bool,
 }
 
 impl Filterable<Achievement> for Game
@@ -81,7 +96,9 @@ impl Filterable<Achievement> for Game
 		
 		let caseSensitive = filter.caseSensitive;
 		let locked = filter.locked;
+		let missable = filter.missable;
 		let nameOnly = filter.nameOnly;
+		let progression = filter.progression;
 		
 		let search = match caseSensitive
 		{
@@ -96,6 +113,8 @@ impl Filterable<Achievement> for Game
 				true => !a.unlocked(RetroAchievementsMode::Casual)
 					&& !a.unlocked(RetroAchievementsMode::Hardcore),
 			})
+			.filter(|a| !missable || a.isMissable())
+			.filter(|a| !progression || a.isProgression())
 			.filter(|a| match caseSensitive
 			{
 				false => match nameOnly
@@ -143,6 +162,33 @@ impl From<Payload_GetGameInfo> for Game
 	}
 }
 
+impl From<RecentlyPlayedMetadata> for Game
+{
+	fn from(value: RecentlyPlayedMetadata) -> Self
+	{
+		let mut instance = Self::default();
+		instance.updateRecentlyPlayed(&value);
+		return instance;
+	}
+}
+
+impl From<WantToPlayMetadata> for Game
+{
+	fn from(value: WantToPlayMetadata) -> Self
+	{
+		return Self
+		{
+			icon: makeRelative(&value.ImageIcon),
+			id: value.ID,
+			name: value.Title.clone(),
+			system: value.clone().into(),
+			total: value.AchievementsPublished,
+			wantToPlay: true,
+			..Default::default()
+		};
+	}
+}
+
 impl PartialOrd for Game
 {
 	fn partial_cmp(&self, other: &Self) -> Option<Ordering>
@@ -160,6 +206,52 @@ impl PartialOrd for Game
 	}
 }
 
+impl Unifiable<UnifiedGame> for Game
+{
+	fn unify(&self) -> UnifiedGame
+	{
+		let mut game = UnifiedGame::new(
+			GamePlatforms::RetroAchievements,
+			self.id.to_string(),
+			self.name.clone(),
+			self.achievements.iter()
+				.map(|a| {
+					let mut achievement = a.unify();
+					if self.distinctPlayers > 0
+					{
+						achievement.rarity = Some(a.unlockedPercent(
+							RetroAchievementsMode::Casual,
+							self.distinctPlayers
+						));
+					}
+					achievement
+				})
+				.collect()
+		);
+		
+		// The progress counts are available before the achievements are retrieved
+		game.achievementsTotal = self.total;
+		game.achievementsUnlocked = self.awardedCasual.max(self.awardedHardcore);
+		
+		if game.lastUnlocked.is_none()
+		{
+			game.lastUnlocked = self.mostRecentTimestamp.as_ref()
+				.and_then(|ts| Self::parseTimestamp(ts));
+		}
+		
+		game.icon = Some(FileLocation
+		{
+			fileName: png!(FileName_GameIcon),
+			group: join!(Path_Games, self.id),
+			platform: RetroAchievementsApi::Platform.to_lowercase(),
+		});
+		
+		game.system = Some(self.system.name.clone());
+		
+		return game;
+	}
+}
+
 impl Game
 {
 	pub fn parseJsonMap(map: &Map<String, Value>) -> Option<Self>
@@ -268,6 +360,18 @@ impl Game
 			}
 		}
 		
+		if let Some((_, value)) = map.iter()
+			.find(|(key, _)| key.as_str() == "lastPlayed")
+		{
+			if let Value::String(string) = value
+			{
+				if !string.is_empty()
+				{
+					game.lastPlayed = Some(string.clone());
+				}
+			}
+		}
+		
 		if let Some((_, value)) = map.iter()
 			.find(|(key, _)| key.as_str() == "system")
 		{
@@ -292,6 +396,15 @@ impl Game
 			}
 		}
 		
+		if let Some((_, value)) = map.iter()
+			.find(|(key, _)| key.as_str() == "wantToPlay")
+		{
+			if let Value::Bool(inner) = value
+			{
+				game.wantToPlay = *inner;
+			}
+		}
+		
 		if let Some((_, value)) = map.iter()
 			.find(|(key, _)| key.as_str() == "mostRecentTimestamp")
 		{
@@ -341,6 +454,74 @@ impl Game
 		};
 	}
 	
+	/**
+	Record an achievement which the user has recently unlocked.
+	
+	Returns `false` if the achievement has not yet been retrieved, in which case
+	the game's info must be refreshed instead.
+	*/
+	pub fn applyRecentAchievement(&mut self, recent: &RecentAchievementMetadata) -> bool
+	{
+		let hardcore = recent.HardcoreMode > 0;
+		
+		return match self.achievements.iter_mut()
+			.find(|a| a.id == recent.AchievementID)
+		{
+			None => false,
+			
+			Some(achievement) => {
+				// Unlocking in Hardcore mode also unlocks in Casual mode
+				if achievement.earnedTimestampCasual.is_none()
+				{
+					achievement.earnedTimestampCasual = Some(recent.Date.clone());
+					self.awardedCasual += 1;
+				}
+				
+				if hardcore && achievement.earnedTimestampHardcore.is_none()
+				{
+					achievement.earnedTimestampHardcore = Some(recent.Date.clone());
+					self.awardedHardcore += 1;
+				}
+				
+				// Stored in the same format as the user's progress reports it
+				if let Some(earned) = Self::parseTimestamp(&recent.Date)
+				{
+					if self.mostRecentTimestamp.as_ref()
+						.and_then(Self::parseTimestamp)
+						.is_none_or(|ts| ts < earned)
+					{
+						self.mostRecentTimestamp = Some(earned.to_rfc3339());
+					}
+				}
+				
+				true
+			},
+		};
+	}
+	
+	/**
+	Progress toward the Beaten award in the given `mode`.
+	
+	Only available once the game's achievements have been retrieved.
+	*/
+	pub fn beatenProgress(&self, mode: RetroAchievementsMode) -> BeatenProgress
+	{
+		return BeatenProgress::new(&self.achievements, mode);
+	}
+	
+	/**
+	The points earned from achievements unlocked in the given `mode`.
+	
+	Only available once the game's achievements have been retrieved.
+	*/
+	pub fn points(&self, mode: RetroAchievementsMode) -> u64
+	{
+		return self.achievements.iter()
+			.filter(|a| a.unlocked(mode))
+			.map(|a| a.points)
+			.sum();
+	}
+	
 	pub fn percentUnlocked(&self, mode: RetroAchievementsMode) -> f32
 	{
 		return truncateF32((match mode
@@ -354,6 +535,64 @@ impl Game
 		);
 	}
 	
+	/**
+	The time of the most recent unlock, in either mode, among the achievements
+	which have been retrieved.
+	*/
+	pub fn latestUnlock(&self) -> Option<DateTime<Utc>>
+	{
+		return self.achievements.iter()
+			.flat_map(|a| [
+				a.earnedTimestamp(RetroAchievementsMode::Casual),
+				a.earnedTimestamp(RetroAchievementsMode::Hardcore),
+			])
+			.flatten()
+			.max();
+	}
+	
+	/**
+	Parse a timestamp as reported by the RetroAchievements API.
+	
+	Most endpoints report timestamps as `YYYY-MM-DD HH:MM:SS` in UTC, but the
+	user completion progress endpoint reports them as ISO-8601 with an offset.
+	*/
+	pub fn parseTimestamp(value: &String) -> Option<DateTime<Utc>>
+	{
+		return match DateTime::parse_from_rfc3339(value)
+		{
+			Ok(dateTime) => Some(dateTime.with_timezone(&Utc)),
+			Err(_) => NaiveDateTime::parse_from_str(value, "%Y-%m-%d %H:%M:%S")
+				.ok()
+				.map(|dateTime| dateTime.and_utc()),
+		};
+	}
+	
+	/// The RetroPoints earned from achievements unlocked in Hardcore mode.
+	pub fn retroPointsEarned(&self) -> u64
+	{
+		return self.achievements.iter()
+			.filter(|a| a.unlocked(RetroAchievementsMode::Hardcore))
+			.map(|a| a.trueRatio)
+			.sum();
+	}
+	
+	/**
+	The RetroPoints available from every achievement in the set.
+	
+	Only available once the game's achievements have been retrieved.
+	*/
+	pub fn retroPointsPossible(&self) -> u64
+	{
+		return self.achievements.iter()
+			.map(|a| a.trueRatio)
+			.sum();
+	}
+	
+	pub fn retroPointsRemaining(&self) -> u64
+	{
+		return self.retroPointsPossible() - self.retroPointsEarned();
+	}
+	
 	pub fn sortName(&self) -> String
 	{
 		return match self.name.starts_with(TheString)
@@ -413,6 +652,24 @@ impl Game
 		self.system = game.clone().into();
 		self.total = game.NumAchievements;
 	}
+	
+	pub fn updateRecentlyPlayed(&mut self, game: &RecentlyPlayedMetadata)
+	{
+		self.awardedCasual = game.NumAchieved;
+		self.awardedHardcore = game.NumAchievedHardcore;
+		self.icon = makeRelative(&game.ImageIcon);
+		self.id = game.GameID;
+		
+		self.lastPlayed = match game.LastPlayed.is_empty()
+		{
+			false => Some(game.LastPlayed.clone()),
+			true => None,
+		};
+		
+		self.name = game.Title.clone();
+		self.system = game.clone().into();
+		self.total = game.AchievementsTotal;
+	}
 }
 
 #[cfg(test)]
@@ -453,6 +710,7 @@ mod tests
 		map.insert("highestAward".into(), (AwardKind::BeatenHardcore as u64).into());
 		map.insert("highestAwardedTimestamp".into(), "The timestamp".into());
 		map.insert("icon".into(), "The icon".into());
+		map.insert("lastPlayed".into(), "2024-10-11 01:58:38".into());
 		
 		if successful
 		{
@@ -463,6 +721,7 @@ mod tests
 		map.insert("total".into(), 27.into());
 		map.insert("mostRecentTimestamp".into(), Value::Null);
 		map.insert("name".into(), "The name".into());
+		map.insert("wantToPlay".into(), true.into());
 		
 		return map;
 	}
@@ -489,9 +748,11 @@ mod tests
 		assert_eq!(game.highestAwardedTimestamp, Some("The timestamp".to_string()));
 		assert_eq!(game.icon, "The icon".to_string());
 		assert_eq!(game.id, 9);
+		assert_eq!(game.lastPlayed, Some("2024-10-11 01:58:38".to_string()));
 		assert_eq!(game.mostRecentTimestamp, None);
 		assert_eq!(game.name, "The name".to_string());
 		assert_eq!(game.system, System { id: 12, name: "The system".into() });
+		assert!(game.wantToPlay);
 		
 		assert!(game.achievements.iter().any(|a| a.id == 1));
 		let firstAchievement = game.achievements.iter()
@@ -505,4 +766,168 @@ mod tests
 			.unwrap();
 		assert_eq!(lastAchievement.name, "Last achievement".to_string());
 	}
+	
+	#[test]
+	fn unifyLastUnlocked()
+	{
+		let mut map = buildMap(true);
+		map.remove("achievements");
+		map.insert("mostRecentTimestamp".into(), "2024-10-11T01:58:38+00:00".into());
+		
+		let game = Game::parseJsonMap(&map).unwrap();
+		let expected = DateTime::parse_from_rfc3339("2024-10-11T01:58:38Z")
+			.unwrap()
+			.with_timezone(&Utc);
+		
+		assert_eq!(game.unify().lastUnlocked, Some(expected));
+		assert_eq!(Game::parseTimestamp(&"2024-10-11 01:58:38".into()), Some(expected));
+		assert_eq!(Game::parseTimestamp(&"The timestamp".into()), None);
+	}
+	
+	fn parseGame(json: &str) -> Game
+	{
+		let map = serde_json::from_str::<Map<String, Value>>(json).unwrap();
+		return Game::parseJsonMap(&map).unwrap();
+	}
+	
+	const FilterByTypeJson: &str = r#"{
+	"id": 9,
+	"achievements": [
+		{ "id": 1, "name": "Achievement 1" },
+		{ "id": 2, "name": "Achievement 2", "achievementType": "Missable" },
+		{ "id": 3, "name": "Achievement 3", "achievementType": "Progression" },
+		{ "id": 4, "name": "Achievement 4", "achievementType": "WinCondition" }
+	]
+}"#;
+	
+	#[test]
+	fn filterByType()
+	{
+		let game = parseGame(FilterByTypeJson);
+		
+		let ids = |criteria: FilterCriteria| game.filter(criteria)
+			.iter()
+			.map(|a| a.id)
+			.collect::<Vec<_>>();
+		
+		assert_eq!(ids(FilterCriteria::default()), vec![1, 2, 3, 4]);
+		assert_eq!(ids(FilterCriteria { missable: true, ..Default::default() }), vec![2]);
+		assert_eq!(ids(FilterCriteria { progression: true, ..Default::default() }), vec![3, 4]);
+		assert!(ids(FilterCriteria { missable: true, progression: true, ..Default::default() }).is_empty());
+	}
+	
+	const RetroPointsJson: &str = r#"{
+	"id": 9,
+	"achievements": [
+		{ "id": 1, "name": "First", "trueRatio": 12, "earnedTimestampCasual": "2024-10-11 01:58:38", "earnedTimestampHardcore": "2024-10-11 01:58:38" },
+		{ "id": 2, "name": "Second", "trueRatio": 30, "earnedTimestampCasual": "2024-10-11 01:58:38", "earnedTimestampHardcore": null },
+		{ "id": 3, "name": "Third", "trueRatio": 105, "earnedTimestampCasual": "2024-10-11 01:58:38", "earnedTimestampHardcore": "2024-10-11 01:58:38" }
+	]
+}"#;
+	
+	#[test]
+	fn retroPoints()
+	{
+		let game = parseGame(RetroPointsJson);
+		assert_eq!(game.achievements.len(), 3);
+		
+		assert_eq!(game.retroPointsEarned(), 117);
+		assert_eq!(game.retroPointsPossible(), 147);
+		assert_eq!(game.retroPointsRemaining(), 30);
+	}
+	
+	const RecentAchievementsJson: &str = r#"[
+	{
+		"Date": "2024-10-12 20:15:00",
+		"HardcoreMode": 1,
+		"AchievementID": 1,
+		"Title": "First achievement",
+		"Description": "The description",
+		"BadgeName": "12345",
+		"Points": 5,
+		"TrueRatio": 7,
+		"Type": "progression",
+		"Author": "The author",
+		"AuthorULID": "00003EMFWR7XB8SDPEHB3K56ZQ",
+		"GameTitle": "The name",
+		"GameIcon": "/Images/000001.png",
+		"GameID": 9,
+		"ConsoleName": "The system",
+		"BadgeURL": "/Badge/12345.png",
+		"GameURL": "/game/9"
+	},
+	{
+		"Date": "2024-10-12 19:00:00",
+		"HardcoreMode": 0,
+		"AchievementID": 3,
+		"Title": "Last achievement",
+		"Description": "The description",
+		"BadgeName": "12346",
+		"Points": 10,
+		"TrueRatio": 12,
+		"Type": null,
+		"Author": "The author",
+		"AuthorULID": "00003EMFWR7XB8SDPEHB3K56ZQ",
+		"GameTitle": "The name",
+		"GameIcon": "/Images/000001.png",
+		"GameID": 9,
+		"ConsoleName": "The system",
+		"BadgeURL": "/Badge/12346.png",
+		"GameURL": "/game/9"
+	},
+	{
+		"Date": "2024-10-12 21:30:00",
+		"HardcoreMode": 1,
+		"AchievementID": 5,
+		"Title": "Unknown achievement",
+		"Description": "The description",
+		"BadgeName": "12347",
+		"Points": 25,
+		"TrueRatio": 40,
+		"Type": null,
+		"Author": "The author",
+		"AuthorULID": "00003EMFWR7XB8SDPEHB3K56ZQ",
+		"GameTitle": "The name",
+		"GameIcon": "/Images/000001.png",
+		"GameID": 9,
+		"ConsoleName": "The system",
+		"BadgeURL": "/Badge/12347.png",
+		"GameURL": "/game/9"
+	}
+]"#;
+	
+	#[test]
+	fn applyRecentAchievement()
+	{
+		let mut map = buildMap(true);
+		map.insert("awardedHardcore".into(), 0.into());
+		map.insert("mostRecentTimestamp".into(), "2024-10-11T01:58:38+00:00".into());
+		
+		let mut game = Game::parseJsonMap(&map).unwrap();
+		let recent = serde_json::from_str::<Vec<RecentAchievementMetadata>>(RecentAchievementsJson).unwrap();
+		
+		assert!(game.applyRecentAchievement(&recent[0]));
+		assert!(game.applyRecentAchievement(&recent[1]));
+		assert!(!game.applyRecentAchievement(&recent[2]));
+		
+		// Applying the same unlock again should not count it twice
+		assert!(game.applyRecentAchievement(&recent[0]));
+		
+		assert_eq!(game.awardedCasual, 2);
+		assert_eq!(game.awardedHardcore, 1);
+		let firstAchievement = game.achievements.iter()
+			.find(|a| a.id == 1)
+			.unwrap();
+		assert!(firstAchievement.unlocked(RetroAchievementsMode::Hardcore));
+		
+		let lastAchievement = game.achievements.iter()
+			.find(|a| a.id == 3)
+			.unwrap();
+		assert!(lastAchievement.unlocked(RetroAchievementsMode::Casual));
+		assert!(!lastAchievement.unlocked(RetroAchievementsMode::Hardcore));
+		
+		// The most recent unlock is stored in the same format as the user's progress reports it
+		assert_eq!(game.mostRecentTimestamp, Some("2024-10-12T20:15:00+00:00".to_string()));
+		assert_eq!(game.latestUnlock(), Game::parseTimestamp(&"2024-10-12 20:15:00".into()));
+	}
 }
@@ -1,7 +1,7 @@
 use std::io::ErrorKind;
 use anyhow::{Result, anyhow};
-use data::io::{getDataDir, getSettingsDir, readDataFromFile,
-	readRawDataFromFile, writeDataToFile};
+use data::io::{getSettingsDir, readDataFromFile, writeDataToFile};
+use data::storage::{loadUser, saveUser};
 use tracing::warn;
 use crate::data::settings::RetroAchievementsSettings;
 use crate::data::user::RetroAchievementsUser;
@@ -17,13 +17,11 @@ pub fn loadSettings_RetroAchievements() -> RetroAchievementsSettings
 
 pub fn loadUserData_RetroAchievements() -> RetroAchievementsUser
 {
-	return match loadUserData()
+	return match loadUser()
 	{
 		Err(e) => {
 			warn!("Failed loading RetroAchievements user data: {:?}", e);
-			warn!("Attempting RetroAchievements user data lossy load");
-			loadUserData_lossy()
-				.unwrap_or_default()
+			RetroAchievementsUser::default()
 		},
 		Ok(user) => user,
 	};
@@ -41,33 +39,6 @@ fn loadSettings() -> Result<RetroAchievementsSettings>
 	};
 }
 
-/**
-Read the RetroAchievements API user data from file.
-*/
-fn loadUserData() -> Result<RetroAchievementsUser>
-{
-	return match getDataDir(false)
-	{
-		None => Err(anyhow!(ErrorKind::NotFound)),
-		Some(dir) => readDataFromFile(dir, RetroAchievementsUser::FileName.into()),
-	};
-}
-
-/**
-Read the RetroAchievements API user data from file.
-*/
-fn loadUserData_lossy() -> Result<RetroAchievementsUser>
-{
-	return match getDataDir(false)
-	{
-		None => Err(anyhow!(ErrorKind::NotFound)),
-		Some(dir) => {
-			let json = readRawDataFromFile(dir, RetroAchievementsUser::FileName.into())?;
-			RetroAchievementsUser::parseJsonLossy(json)
-		},
-	};
-}
-
 /**
 Write the RetroAchievements settings data to file.
 */
@@ -82,9 +53,5 @@ pub fn saveSettings(auth: &RetroAchievementsSettings) -> Result<()>
 
 pub fn saveUserData(user: &RetroAchievementsUser) -> Result<()>
 {
-	return match getDataDir(true)
-	{
-		None => Err(anyhow!(ErrorKind::NotFound)),
-		Some(dir) => writeDataToFile(dir, RetroAchievementsUser::FileName.into(), user),
-	};
+	return saveUser(user);
 }
@@ -1,4 +1,6 @@
 pub mod achievement;
+pub mod achievementtype;
+pub mod beaten;
 pub mod game;
 pub mod io;
 pub mod kind;
@@ -8,6 +10,8 @@ pub mod progress;
 pub mod rank;
 pub mod result;
 pub mod settings;
+pub mod sort;
+pub mod stats;
 pub mod system;
 pub mod user;
 
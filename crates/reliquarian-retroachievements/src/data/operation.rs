@@ -3,14 +3,19 @@ use anyhow::{Error, anyhow};
 use data::enums::GamePlatforms;
 use net::{DataOperation, DataRequest};
 use strum_macros::{AsRefStr, EnumString};
+use crate::api::RetroAchievementsApi;
 use crate::data::progress::RetroAchievementsProgressState;
 
 #[derive(AsRefStr, Clone, Debug, EnumString, Eq, PartialEq, PartialOrd, Ord)]
 pub enum RetroAchievementsOperation
 {
 	GetGameInfo(u64),
+	GetRecentAchievements(Option<u64>),
+	GetRecentlyPlayed,
 	GetUserProfile,
 	GetUserProgress(RetroAchievementsProgressState),
+	GetUserProgressIncremental(RetroAchievementsProgressState),
+	GetWantToPlayList(RetroAchievementsProgressState),
 	SaveToFile,
 }
 
@@ -27,6 +32,19 @@ impl From<RetroAchievementsOperation> for DataOperation
 					gameId
 				),
 			
+			RetroAchievementsOperation::GetRecentAchievements(minutes)
+				=> DataOperation::PlatformOptionalInt(
+					GamePlatforms::RetroAchievements,
+					RetroAchievementsOperation::GetRecentAchievements(None).as_ref().to_string(),
+					minutes
+				),
+			
+			RetroAchievementsOperation::GetRecentlyPlayed
+				=> DataOperation::Platform(
+					GamePlatforms::RetroAchievements,
+					RetroAchievementsOperation::GetRecentlyPlayed.as_ref().to_string()
+				),
+			
 			RetroAchievementsOperation::GetUserProfile
 				=> DataOperation::Platform(
 					GamePlatforms::RetroAchievements,
@@ -42,6 +60,24 @@ impl From<RetroAchievementsOperation> for DataOperation
 					progress.total
 				),
 			
+			RetroAchievementsOperation::GetUserProgressIncremental(progress)
+				=> DataOperation::PlatformThreeInt(
+					GamePlatforms::RetroAchievements,
+					RetroAchievementsOperation::GetUserProgressIncremental(Default::default()).as_ref().to_string(),
+					progress.offset,
+					progress.received,
+					progress.total
+				),
+			
+			RetroAchievementsOperation::GetWantToPlayList(progress)
+				=> DataOperation::PlatformThreeInt(
+					GamePlatforms::RetroAchievements,
+					RetroAchievementsOperation::GetWantToPlayList(Default::default()).as_ref().to_string(),
+					progress.offset,
+					progress.received,
+					progress.total
+				),
+			
 			RetroAchievementsOperation::SaveToFile => DataOperation::PlatformSaveToFile(GamePlatforms::RetroAchievements),
 		};
 	}
@@ -85,6 +121,17 @@ impl TryFrom<DataOperation> for RetroAchievementsOperation
 					_ => Err(anyhow!("Invalid Retro Achievement operation")),
 				}
 			
+			DataOperation::PlatformOptionalInt(platform, operationName, minutes)
+				=> match platform
+				{
+					GamePlatforms::RetroAchievements => match RetroAchievementsOperation::from_str(&operationName)?
+					{
+						Self::GetRecentAchievements(_) => Ok(Self::GetRecentAchievements(minutes)),
+						_ => Err(anyhow!("Invalid Retro Achievement operation")),
+					},
+					_ => Err(anyhow!("Invalid Retro Achievement operation")),
+				}
+			
 			DataOperation::PlatformSaveToFile(platform)
 				=> match platform
 				{
@@ -109,6 +156,21 @@ impl TryFrom<DataOperation> for RetroAchievementsOperation
 							received,
 							total,
 						})),
+						
+						Self::GetUserProgressIncremental(_) => Ok(Self::GetUserProgressIncremental(RetroAchievementsProgressState
+						{
+							offset,
+							received,
+							total,
+						})),
+						
+						Self::GetWantToPlayList(_) => Ok(Self::GetWantToPlayList(RetroAchievementsProgressState
+						{
+							offset,
+							received,
+							total,
+						})),
+						
 						_ => Err(anyhow!("Invalid Retro Achievement operation")),
 					},
 					_ => Err(anyhow!("Invalid Retro Achievement operation")),
@@ -118,3 +180,68 @@ impl TryFrom<DataOperation> for RetroAchievementsOperation
 		};
 	}
 }
+
+impl RetroAchievementsOperation
+{
+	/**
+	The operations which refresh the user's profile and progress.
+	
+	An incremental refresh stops retrieving the user's progress once it reaches
+	a game which has not changed since the last refresh, but also retrieves the
+	details of every game which has changed. The achievements unlocked within
+	the last day are requested before the user's progress. Requests of the same
+	kind are processed in the order they are queued, so the games the recent
+	unlocks bring up to date do not need their details retrieved.
+	*/
+	pub fn refreshRequests(incremental: bool) -> Vec<DataRequest>
+	{
+		let mut requests = vec![Self::GetUserProfile.into()];
+		
+		match incremental
+		{
+			false => requests.push(Self::GetUserProgress(Default::default()).into()),
+			true => {
+				requests.push(Self::GetRecentAchievements(Some(RetroAchievementsApi::GetUserRecentAchievements_Minutes)).into());
+				requests.push(Self::GetUserProgressIncremental(Default::default()).into());
+			},
+		}
+		
+		requests.push(Self::GetRecentlyPlayed.into());
+		requests.push(Self::GetWantToPlayList(Default::default()).into());
+		
+		return requests;
+	}
+}
+
+#[cfg(test)]
+mod tests
+{
+	use super::*;
+	
+	fn operations(requests: Vec<DataRequest>) -> Vec<RetroAchievementsOperation>
+	{
+		return requests.into_iter()
+			.map(|request| RetroAchievementsOperation::try_from(request.operation).unwrap())
+			.collect();
+	}
+	
+	#[test]
+	fn refreshRequests()
+	{
+		assert_eq!(operations(RetroAchievementsOperation::refreshRequests(false)), vec![
+			RetroAchievementsOperation::GetUserProfile,
+			RetroAchievementsOperation::GetUserProgress(Default::default()),
+			RetroAchievementsOperation::GetRecentlyPlayed,
+			RetroAchievementsOperation::GetWantToPlayList(Default::default()),
+		]);
+		
+		// The recent unlocks must be applied before the progress is compared
+		assert_eq!(operations(RetroAchievementsOperation::refreshRequests(true)), vec![
+			RetroAchievementsOperation::GetUserProfile,
+			RetroAchievementsOperation::GetRecentAchievements(Some(RetroAchievementsApi::GetUserRecentAchievements_Minutes)),
+			RetroAchievementsOperation::GetUserProgressIncremental(Default::default()),
+			RetroAchievementsOperation::GetRecentlyPlayed,
+			RetroAchievementsOperation::GetWantToPlayList(Default::default()),
+		]);
+	}
+}
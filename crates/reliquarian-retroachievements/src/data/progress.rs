@@ -1,5 +1,3 @@
-use crate::api::RetroAchievementsApi;
-
 #[derive(Clone, Copy, Debug, Default, Eq, PartialEq, PartialOrd, Ord)]
 pub struct RetroAchievementsProgressState
 {
@@ -10,10 +8,14 @@ pub struct RetroAchievementsProgressState
 
 impl RetroAchievementsProgressState
 {
-	pub fn reachedEnd(&self) -> bool
+	/**
+	Whether every page has been received, given the number of entries the
+	endpoint returns per page.
+	*/
+	pub fn reachedEnd(&self, pageSize: u64) -> bool
 	{
 		return self.received <= 0
 			|| self.received >= self.total
-			|| self.received % RetroAchievementsApi::GetUserGameCompletion_Count != 0;
+			|| self.received % pageSize != 0;
 	}
 }
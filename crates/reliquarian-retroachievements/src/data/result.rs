@@ -4,6 +4,8 @@ use crate::data::user::RetroAchievementsUser;
 #[derive(Clone, Debug)]
 pub struct RetroAchievementsResult
 {
+	/// Whether the operation failed to retrieve the data it was meant to refresh.
+	pub failed: bool,
 	pub user: RetroAchievementsUser,
 	pub requests: Vec<DataRequest>,
 }
@@ -14,8 +16,23 @@ impl From<RetroAchievementsUser> for RetroAchievementsResult
 	{
 		return Self
 		{
+			failed: false,
 			user: value,
 			requests: Default::default(),
 		};
 	}
 }
+
+impl RetroAchievementsResult
+{
+	/// A result which leaves the user unchanged and marks the operation as failed.
+	pub fn failure(user: RetroAchievementsUser) -> Self
+	{
+		return Self
+		{
+			failed: true,
+			user,
+			requests: Default::default(),
+		};
+	}
+}
@@ -1,10 +1,15 @@
 use data::enums::GamePlatforms;
+use data::migration::Versioned;
 use freya::radio::RadioChannel;
 use serde::{Deserialize, Serialize};
 
 #[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
 pub struct RetroAchievementsSettings
 {
+	/// Only retrieve the details of games whose progress changed since the last refresh.
+	#[serde(default)]
+	pub incrementalRefresh: bool,
+	
 	#[serde(default)]
 	pub showGameAwardBadges: bool,
 }
@@ -15,6 +20,7 @@ impl Default for RetroAchievementsSettings
 	{
 		return Self
 		{
+			incrementalRefresh: false,
 			showGameAwardBadges: true,
 		};
 	}
@@ -22,6 +28,8 @@ impl Default for RetroAchievementsSettings
 
 impl RadioChannel<RetroAchievementsSettings> for GamePlatforms {}
 
+impl Versioned for RetroAchievementsSettings {}
+
 impl RetroAchievementsSettings
 {
 	/// The filename to be used when this struct is read from, or written to, the file system.
@@ -0,0 +1,84 @@
+use std::cmp::{Ordering, Reverse};
+use strum_macros::{Display, EnumIter};
+use super::game::Game;
+use super::mode::RetroAchievementsMode;
+
+/**
+The orderings available when displaying the list of RetroAchievements games.
+*/
+#[derive(Clone, Copy, Debug, Default, Display, EnumIter, Eq, Hash, PartialEq)]
+pub enum RetroAchievementsSort
+{
+	#[strum(to_string="Beaten Progress")]
+	BeatenProgress,
+	#[strum(to_string="Completion")]
+	Completion,
+	#[default]
+	#[strum(to_string="Name")]
+	Name,
+	#[strum(to_string="Recently Played")]
+	RecentlyPlayed,
+	#[strum(to_string="Weighted Points Remaining")]
+	WeightedPointsRemaining,
+}
+
+impl RetroAchievementsSort
+{
+	/**
+	Order `games`, which are expected to already be ordered by name.
+	
+	Progress is ordered descending, preferring Hardcore mode and falling back
+	to Casual mode when tied. Games whose Beaten progress cannot be tracked are
+	placed last.
+	
+	Recently played games are ordered first, followed by games which the user has
+	not played since the list was last retrieved.
+	
+	Weighted points remaining orders the sets with the most unearned RetroPoints
+	first.
+	*/
+	pub fn sort(&self, games: &mut [Game])
+	{
+		match self
+		{
+			Self::BeatenProgress => games.sort_by(|a, b| {
+				let keyA = beatenKey(a);
+				let keyB = beatenKey(b);
+				keyB.partial_cmp(&keyA).unwrap_or(Ordering::Equal)
+			}),
+			
+			Self::Completion => games.sort_by(|a, b| {
+				let keyA = completionKey(a);
+				let keyB = completionKey(b);
+				keyB.partial_cmp(&keyA).unwrap_or(Ordering::Equal)
+			}),
+			
+			Self::Name => {},
+			
+			// Timestamps are formatted such that they are ordered chronologically as strings
+			Self::RecentlyPlayed => games.sort_by(|a, b| b.lastPlayed.cmp(&a.lastPlayed)),
+			
+			Self::WeightedPointsRemaining => games.sort_by_key(|g| Reverse(g.retroPointsRemaining())),
+		}
+	}
+}
+
+fn beatenKey(game: &Game) -> (bool, f32, f32)
+{
+	let hardcore = game.beatenProgress(RetroAchievementsMode::Hardcore);
+	let casual = game.beatenProgress(RetroAchievementsMode::Casual);
+	return (hardcore.isTracked(), hardcore.percent(), casual.percent());
+}
+
+fn completionKey(game: &Game) -> (f32, f32)
+{
+	// Games whose achievement count is not yet known would otherwise produce NaN
+	return match game.total
+	{
+		0 => (0.0, 0.0),
+		_ => (
+			game.percentUnlocked(RetroAchievementsMode::Hardcore),
+			game.percentUnlocked(RetroAchievementsMode::Casual)
+		),
+	};
+}
@@ -0,0 +1,49 @@
+use super::game::Game;
+use super::kind::AwardKind;
+use super::mode::RetroAchievementsMode;
+use super::system::System;
+
+/**
+Totals across every game the user has played on a single system.
+*/
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
+pub struct SystemStats
+{
+	pub beatenCasual: usize,
+	pub beatenHardcore: usize,
+	pub completed: usize,
+	pub games: usize,
+	pub mastered: usize,
+	
+	/// The points earned in Hardcore mode, from games whose achievements have been retrieved.
+	pub pointsHardcore: u64,
+	
+	pub system: System,
+}
+
+impl SystemStats
+{
+	pub fn new(system: System) -> Self
+	{
+		return Self
+		{
+			system,
+			..Default::default()
+		};
+	}
+	
+	pub fn add(&mut self, game: &Game)
+	{
+		self.games += 1;
+		self.pointsHardcore += game.points(RetroAchievementsMode::Hardcore);
+		
+		match game.highestAward
+		{
+			None => {},
+			Some(AwardKind::BeatenCasual) => self.beatenCasual += 1,
+			Some(AwardKind::BeatenHardcore) => self.beatenHardcore += 1,
+			Some(AwardKind::Completed) => self.completed += 1,
+			Some(AwardKind::Mastered) => self.mastered += 1,
+		}
+	}
+}
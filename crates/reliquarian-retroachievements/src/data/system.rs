@@ -2,7 +2,8 @@ use std::cmp::Ordering;
 use data::constants::TheString;
 use serde::{Deserialize, Serialize};
 use serde_json::{Map, Value};
-use crate::api::{Payload_GetGameInfo, GameMetadata};
+use crate::api::{Payload_GetGameInfo, GameMetadata, RecentlyPlayedMetadata,
+	WantToPlayMetadata};
 
 #[derive(Clone, Debug, Default, Deserialize, Eq, Hash, PartialEq, Ord, Serialize)]
 pub struct System
@@ -37,6 +38,30 @@ impl From<Payload_GetGameInfo> for System
 	}
 }
 
+impl From<RecentlyPlayedMetadata> for System
+{
+	fn from(value: RecentlyPlayedMetadata) -> Self
+	{
+		return Self
+		{
+			id: value.ConsoleID,
+			name: value.ConsoleName.clone(),
+		};
+	}
+}
+
+impl From<WantToPlayMetadata> for System
+{
+	fn from(value: WantToPlayMetadata) -> Self
+	{
+		return Self
+		{
+			id: value.ConsoleID,
+			name: value.ConsoleName.clone(),
+		};
+	}
+}
+
 impl PartialOrd for System
 {
 	fn partial_cmp(&self, other: &Self) -> Option<Ordering>
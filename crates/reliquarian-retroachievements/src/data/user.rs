@@ -1,15 +1,24 @@
+use std::cmp::Ordering;
 use anyhow::Result;
+use chrono::{DateTime, Utc};
 use data::enums::GamePlatforms;
 use data::filter::{FilterCriteria, Filterable};
+use data::migration::Versioned;
+use data::storage::Storable;
+use data::unified::{Unifiable, UnifiedGame};
 use freya::radio::RadioChannel;
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
-use crate::api::{Payload_GetUserCompletionProgress, Payload_GetUserProfile};
+use crate::api::{Payload_GetUserCompletionProgress, Payload_GetUserProfile,
+	Payload_GetUserRecentAchievements, Payload_GetUserRecentlyPlayedGames,
+	Payload_GetUserWantToPlayList};
 use crate::data::kind::AwardKind;
 use super::makeRelative;
 use super::achievement::Achievement;
 use super::mode::RetroAchievementsMode;
 use super::rank::RankData;
+use super::sort::RetroAchievementsSort;
+use super::stats::SystemStats;
 use super::game::Game;
 
 #[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
@@ -67,6 +76,7 @@ impl Filterable<Game> for RetroAchievementsUser
 		
 		let caseSensitive = filter.caseSensitive;
 		let nameOnly = filter.nameOnly;
+		let wantToPlay = filter.wantToPlay;
 		let search = match caseSensitive
 		{
 			false => filter.text.to_lowercase(),
@@ -74,6 +84,7 @@ impl Filterable<Game> for RetroAchievementsUser
 		};
 		
 		let mut games = self.games.iter()
+			.filter(|g| !wantToPlay || g.wantToPlay)
 			.filter(|g| match caseSensitive
 			{
 				false => g.name.to_lowercase().contains(&search)
@@ -93,6 +104,29 @@ impl Filterable<Game> for RetroAchievementsUser
 
 impl RadioChannel<RetroAchievementsUser> for GamePlatforms {}
 
+impl Storable for RetroAchievementsUser
+{
+	const DataFileName: &'static str = RetroAchievementsUser::FileName;
+	const Platform: GamePlatforms = GamePlatforms::RetroAchievements;
+	
+	fn parseLossy(json: String) -> Result<Self>
+	{
+		return RetroAchievementsUser::parseJsonLossy(json);
+	}
+}
+
+impl Unifiable<Vec<UnifiedGame>> for RetroAchievementsUser
+{
+	fn unify(&self) -> Vec<UnifiedGame>
+	{
+		return self.games.iter()
+			.map(|g| g.unify())
+			.collect();
+	}
+}
+
+impl Versioned for RetroAchievementsUser {}
+
 impl RetroAchievementsUser
 {
 	pub const FileName: &str = "retroAchievements.json";
@@ -106,6 +140,28 @@ impl RetroAchievementsUser
 			.count();
 	}
 	
+	/**
+	Filter the user's games, optionally limited to those played on the system
+	with the given `systemId`, and then order them by `sort`.
+	*/
+	pub fn filterAndSort(
+		&self,
+		filter: impl Into<FilterCriteria>,
+		systemId: Option<u64>,
+		sort: RetroAchievementsSort
+	) -> Vec<Game>
+	{
+		let mut games = self.filter(filter);
+		
+		if let Some(systemId) = systemId
+		{
+			games.retain(|g| g.system.id == systemId);
+		}
+		
+		sort.sort(&mut games);
+		return games;
+	}
+	
 	pub fn getAchievement(&self, gameId: impl Into<u64>, achievementId: impl Into<u64>) -> Option<Achievement>
 	{
 		let achievementId = achievementId.into();
@@ -137,6 +193,31 @@ impl RetroAchievementsUser
 			.cloned();
 	}
 	
+	/// The totals for each system on which the user has played games, ordered by the systems' names.
+	pub fn systemStats(&self) -> Vec<SystemStats>
+	{
+		let mut stats: Vec<SystemStats> = vec![];
+		
+		for game in self.games.iter()
+		{
+			match stats.iter_mut()
+				.find(|s| s.system.id == game.system.id)
+			{
+				None => {
+					let mut systemStats = SystemStats::new(game.system.clone());
+					systemStats.add(game);
+					stats.push(systemStats);
+				},
+				
+				Some(systemStats) => systemStats.add(game),
+			}
+		}
+		
+		stats.sort_by(|a, b| a.system.partial_cmp(&b.system).unwrap_or(Ordering::Equal));
+		
+		return stats;
+	}
+	
 	/**
 	Parse a JSON string which does not strictly conform to the expected `User`
 	data structure.
@@ -251,15 +332,81 @@ impl RetroAchievementsUser
 		};
 	}
 	
-	pub fn processUserCompletionProgress(&mut self, payload: &Payload_GetUserCompletionProgress)
+	/**
+	Update the user's games with their latest progress.
+	
+	Returns the IDs of the games with an unlock more recent than any among
+	their retrieved achievements, including games whose achievements have not
+	been retrieved at all. The achievements are compared rather than
+	`mostRecentTimestamp`, as that is updated here whether or not the game's
+	details are retrieved afterward.
+	*/
+	pub fn processUserCompletionProgress(&mut self, payload: &Payload_GetUserCompletionProgress) -> Vec<u64>
 	{
+		let mut changed = vec![];
+		
 		for metadata in payload.Results.iter()
+		{
+			match self.games.iter_mut()
+				.find(|g| g.id == metadata.GameID)
+			{
+				None => {
+					if metadata.MostRecentAwardedDate.is_some()
+					{
+						changed.push(metadata.GameID);
+					}
+					
+					self.games.push(metadata.clone().into());
+				},
+				
+				Some(game) => {
+					let awarded = metadata.MostRecentAwardedDate.as_ref()
+						.and_then(Game::parseTimestamp);
+					
+					if awarded.is_some_and(|award| game.latestUnlock().is_none_or(|unlock| unlock < award))
+					{
+						changed.push(metadata.GameID);
+					}
+					
+					game.update(&metadata);
+				},
+			}
+		}
+		
+		return changed;
+	}
+	
+	/**
+	Record the achievements the user has unlocked since `since`.
+	
+	Unlocks are only applied to games whose achievements were already up to
+	date at `since`, as any unlock before then would otherwise be missed. Every
+	other game is left for the user's progress to refresh.
+	*/
+	pub fn processRecentAchievements(&mut self, payload: &Payload_GetUserRecentAchievements, since: DateTime<Utc>)
+	{
+		for recent in payload.iter()
+		{
+			if let Some(game) = self.games.iter_mut()
+				.find(|g| g.id == recent.GameID)
+			{
+				if game.latestUnlock().is_some_and(|ts| ts >= since)
+				{
+					game.applyRecentAchievement(recent);
+				}
+			}
+		}
+	}
+	
+	pub fn processRecentlyPlayedGames(&mut self, payload: &Payload_GetUserRecentlyPlayedGames)
+	{
+		for metadata in payload.iter()
 		{
 			match self.games.iter_mut()
 				.find(|g| g.id == metadata.GameID)
 			{
 				None => self.games.push(metadata.clone().into()),
-				Some(game) => game.update(&metadata),
+				Some(game) => game.updateRecentlyPlayed(&metadata),
 			}
 		}
 	}
@@ -284,6 +431,32 @@ impl RetroAchievementsUser
 		
 		self.username = payload.User.clone();
 	}
+	
+	/**
+	Flag the games on the user's Want to Play list, adding any which the user
+	has not yet played.
+	
+	Set `reset` when processing the first page of the list, so games which have
+	since been removed from it are no longer flagged.
+	*/
+	pub fn processWantToPlayList(&mut self, payload: &Payload_GetUserWantToPlayList, reset: bool)
+	{
+		if reset
+		{
+			self.games.iter_mut()
+				.for_each(|g| g.wantToPlay = false);
+		}
+		
+		for metadata in payload.Results.iter()
+		{
+			match self.games.iter_mut()
+				.find(|g| g.id == metadata.ID)
+			{
+				None => self.games.push(metadata.clone().into()),
+				Some(game) => game.wantToPlay = true,
+			}
+		}
+	}
 }
 
 #[cfg(test)]
@@ -328,4 +501,358 @@ mod tests
 		assert_eq!(achievement.id, 4);
 		assert_eq!(achievement.name, "Successful parse!".to_string());
 	}
+	
+	const FilterAndSortJson: &str = r#"{
+	"username": "Test User",
+	"games": [
+		{ "id": 1, "name": "Alpha", "awardedHardcore": 5, "total": 10 },
+		
+		{
+			"id": 2,
+			"name": "Bravo",
+			"awardedHardcore": 0,
+			"total": 0,
+			"achievements": [
+				{ "id": 20, "name": "Unlocked by few", "trueRatio": 50 }
+			]
+		},
+		
+		{ "id": 3, "name": "Charlie", "awardedHardcore": 10, "total": 10 }
+	]
+}"#;
+	
+	#[test]
+	fn filterAndSort()
+	{
+		let user = RetroAchievementsUser::parseJsonLossy(FilterAndSortJson.into()).unwrap();
+		assert_eq!(user.games.len(), 3);
+		
+		let ids = |sort| user.filterAndSort(FilterCriteria::default(), None, sort)
+			.iter()
+			.map(|g| g.id)
+			.collect::<Vec<_>>();
+		
+		assert_eq!(ids(RetroAchievementsSort::Name), vec![1, 2, 3]);
+		assert_eq!(ids(RetroAchievementsSort::Completion), vec![3, 1, 2]);
+		assert_eq!(ids(RetroAchievementsSort::WeightedPointsRemaining), vec![2, 1, 3]);
+	}
+	
+	const CompletionProgressJson: &str = r#"{
+	"username": "Test User",
+	"games": [
+		{
+			"id": 1,
+			"achievements": [
+				{ "id": 10, "name": "First", "earnedTimestampHardcore": "2024-10-11 01:58:38" }
+			]
+		},
+		
+		{
+			"id": 2,
+			"achievements": [
+				{ "id": 20, "name": "First", "earnedTimestampCasual": "2024-09-01 12:00:00" }
+			]
+		},
+		
+		{
+			"id": 5,
+			"mostRecentTimestamp": "2024-10-05T08:30:00+00:00",
+			"achievements": [
+				{ "id": 50, "name": "First", "earnedTimestampCasual": "2024-08-20 17:45:00" }
+			]
+		}
+	]
+}"#;
+	
+	const CompletionProgressPayload: &str = r#"{
+	"Count": 5,
+	"Total": 5,
+	"Results": [
+		{
+			"GameID": 3,
+			"Title": "New game",
+			"ImageIcon": "/Images/000003.png",
+			"ConsoleID": 3,
+			"ConsoleName": "SNES/Super Famicom",
+			"MaxPossible": 20,
+			"NumAwarded": 5,
+			"NumAwardedHardcore": 5,
+			"MostRecentAwardedDate": "2024-10-12T20:15:00+00:00",
+			"HighestAwardKind": null,
+			"HighestAwardDate": null
+		},
+		{
+			"GameID": 1,
+			"Title": "Changed game",
+			"ImageIcon": "/Images/000001.png",
+			"ConsoleID": 3,
+			"ConsoleName": "SNES/Super Famicom",
+			"MaxPossible": 20,
+			"NumAwarded": 5,
+			"NumAwardedHardcore": 5,
+			"MostRecentAwardedDate": "2024-10-12T19:00:00+00:00",
+			"HighestAwardKind": "beaten-hardcore",
+			"HighestAwardDate": "2024-10-12T19:00:00+00:00"
+		},
+		{
+			"GameID": 5,
+			"Title": "Out of date game",
+			"ImageIcon": "/Images/000005.png",
+			"ConsoleID": 3,
+			"ConsoleName": "SNES/Super Famicom",
+			"MaxPossible": 20,
+			"NumAwarded": 5,
+			"NumAwardedHardcore": 5,
+			"MostRecentAwardedDate": "2024-10-05T08:30:00+00:00",
+			"HighestAwardKind": null,
+			"HighestAwardDate": null
+		},
+		{
+			"GameID": 2,
+			"Title": "Unchanged game",
+			"ImageIcon": "/Images/000002.png",
+			"ConsoleID": 3,
+			"ConsoleName": "SNES/Super Famicom",
+			"MaxPossible": 20,
+			"NumAwarded": 5,
+			"NumAwardedHardcore": 5,
+			"MostRecentAwardedDate": "2024-09-01T12:00:00+00:00",
+			"HighestAwardKind": null,
+			"HighestAwardDate": null
+		},
+		{
+			"GameID": 4,
+			"Title": "Unplayed game",
+			"ImageIcon": "/Images/000004.png",
+			"ConsoleID": 3,
+			"ConsoleName": "SNES/Super Famicom",
+			"MaxPossible": 20,
+			"NumAwarded": 0,
+			"NumAwardedHardcore": 0,
+			"MostRecentAwardedDate": null,
+			"HighestAwardKind": null,
+			"HighestAwardDate": null
+		}
+	]
+}"#;
+	
+	#[test]
+	fn processUserCompletionProgress()
+	{
+		let mut user = RetroAchievementsUser::parseJsonLossy(CompletionProgressJson.into()).unwrap();
+		let mut payload = serde_json::from_str::<Payload_GetUserCompletionProgress>(CompletionProgressPayload).unwrap();
+		assert!(payload.isSortedByMostRecentAward());
+		
+		// A previous refresh which recorded the most recent unlock without retrieving the achievements must not hide the change
+		let changed = user.processUserCompletionProgress(&payload);
+		assert_eq!(changed, vec![3, 1, 5]);
+		assert_eq!(user.games.len(), 5);
+		
+		let changed = user.processUserCompletionProgress(&payload);
+		assert_eq!(changed, vec![3, 1, 5]);
+		
+		payload.Results.reverse();
+		assert!(!payload.isSortedByMostRecentAward());
+	}
+	
+	const RecentAchievementsJson: &str = r#"{
+	"username": "Test User",
+	"games": [
+		{
+			"id": 1,
+			"achievements": [
+				{ "id": 10, "name": "Up to date", "earnedTimestampCasual": "2024-10-12 10:00:00" },
+				{ "id": 11, "name": "Recently unlocked" }
+			]
+		},
+		
+		{
+			"id": 2,
+			"achievements": [
+				{ "id": 20, "name": "Out of date", "earnedTimestampCasual": "2024-10-01 10:00:00" },
+				{ "id": 21, "name": "Recently unlocked" }
+			]
+		}
+	]
+}"#;
+	
+	const RecentAchievementsPayload: &str = r#"[
+	{
+		"Date": "2024-10-12 20:15:00",
+		"HardcoreMode": 0,
+		"AchievementID": 11,
+		"Title": "Recently unlocked",
+		"Description": "The description",
+		"BadgeName": "12345",
+		"Points": 5,
+		"TrueRatio": 7,
+		"Type": null,
+		"Author": "The author",
+		"AuthorULID": "00003EMFWR7XB8SDPEHB3K56ZQ",
+		"GameTitle": "Up to date",
+		"GameIcon": "/Images/000001.png",
+		"GameID": 1,
+		"ConsoleName": "The system",
+		"BadgeURL": "/Badge/12345.png",
+		"GameURL": "/game/1"
+	},
+	{
+		"Date": "2024-10-12 19:00:00",
+		"HardcoreMode": 0,
+		"AchievementID": 21,
+		"Title": "Recently unlocked",
+		"Description": "The description",
+		"BadgeName": "12346",
+		"Points": 5,
+		"TrueRatio": 7,
+		"Type": null,
+		"Author": "The author",
+		"AuthorULID": "00003EMFWR7XB8SDPEHB3K56ZQ",
+		"GameTitle": "Out of date",
+		"GameIcon": "/Images/000002.png",
+		"GameID": 2,
+		"ConsoleName": "The system",
+		"BadgeURL": "/Badge/12346.png",
+		"GameURL": "/game/2"
+	}
+]"#;
+	
+	#[test]
+	fn processRecentAchievements()
+	{
+		let mut user = RetroAchievementsUser::parseJsonLossy(RecentAchievementsJson.into()).unwrap();
+		let payload = serde_json::from_str::<Payload_GetUserRecentAchievements>(RecentAchievementsPayload).unwrap();
+		let since = Game::parseTimestamp(&"2024-10-12 00:00:00".into()).unwrap();
+		
+		user.processRecentAchievements(&payload, since);
+		
+		// Only the game which was up to date when the payload begins can be updated
+		let upToDate = user.getAchievement(1u64, 11u64).unwrap();
+		assert!(upToDate.unlocked(RetroAchievementsMode::Casual));
+		
+		let outOfDate = user.getAchievement(2u64, 21u64).unwrap();
+		assert!(!outOfDate.unlocked(RetroAchievementsMode::Casual));
+	}
+	
+	const WantToPlayJson: &str = r#"{
+	"username": "Test User",
+	"games": [
+		{ "id": 1 },
+		{ "id": 2, "wantToPlay": true }
+	]
+}"#;
+	
+	const WantToPlayFirstPage: &str = r#"{
+	"Count": 2,
+	"Total": 3,
+	"Results": [
+		{
+			"ID": 1,
+			"Title": "Existing game",
+			"ImageIcon": "/Images/000001.png",
+			"ConsoleID": 1,
+			"ConsoleName": "Genesis/Mega Drive",
+			"PointsTotal": 400,
+			"AchievementsPublished": 30
+		},
+		{
+			"ID": 3,
+			"Title": "New game",
+			"ImageIcon": "/Images/000003.png",
+			"ConsoleID": 3,
+			"ConsoleName": "SNES/Super Famicom",
+			"PointsTotal": 1000,
+			"AchievementsPublished": 60
+		}
+	]
+}"#;
+	
+	const WantToPlaySecondPage: &str = r#"{
+	"Count": 1,
+	"Total": 3,
+	"Results": [
+		{
+			"ID": 2,
+			"Title": "Previously wanted game",
+			"ImageIcon": "/Images/000002.png",
+			"ConsoleID": 7,
+			"ConsoleName": "NES/Famicom",
+			"PointsTotal": 250,
+			"AchievementsPublished": 20
+		}
+	]
+}"#;
+	
+	#[test]
+	fn processWantToPlayList()
+	{
+		let mut user = RetroAchievementsUser::parseJsonLossy(WantToPlayJson.into()).unwrap();
+		
+		let firstPage = serde_json::from_str::<Payload_GetUserWantToPlayList>(WantToPlayFirstPage).unwrap();
+		user.processWantToPlayList(&firstPage, true);
+		
+		let wanted = user.games.iter()
+			.filter(|g| g.wantToPlay)
+			.map(|g| g.id)
+			.collect::<Vec<_>>();
+		assert_eq!(wanted, vec![1, 3]);
+		
+		// Subsequent pages should not clear the games flagged by previous pages
+		let secondPage = serde_json::from_str::<Payload_GetUserWantToPlayList>(WantToPlaySecondPage).unwrap();
+		user.processWantToPlayList(&secondPage, false);
+		assert_eq!(user.games.iter().filter(|g| g.wantToPlay).count(), 3);
+	}
+	
+	const SystemStatsJson: &str = r#"{
+	"username": "Test User",
+	"games": [
+		{
+			"id": 1,
+			"name": "Mastered game",
+			"highestAward": 3,
+			"system": { "id": 2, "name": "SNES" },
+			"achievements": [
+				{ "id": 1, "name": "Unlocked", "points": 10, "earnedTimestampHardcore": "2024-10-11 01:58:38" },
+				{ "id": 2, "name": "Locked", "points": 25 }
+			]
+		},
+		
+		{
+			"id": 2,
+			"name": "Beaten game",
+			"highestAward": 1,
+			"system": { "id": 2, "name": "SNES" }
+		},
+		
+		{
+			"id": 3,
+			"name": "Unplayed game",
+			"system": { "id": 7, "name": "NES" }
+		}
+	]
+}"#;
+	
+	#[test]
+	fn systemStats()
+	{
+		let user = RetroAchievementsUser::parseJsonLossy(SystemStatsJson.into()).unwrap();
+		
+		let stats = user.systemStats();
+		assert_eq!(stats.len(), 2);
+		assert_eq!(stats[0].system.name, "NES".to_string());
+		assert_eq!(stats[0].games, 1);
+		
+		let snes = &stats[1];
+		assert_eq!(snes.games, 2);
+		assert_eq!(snes.mastered, 1);
+		assert_eq!(snes.beatenHardcore, 1);
+		assert_eq!(snes.completed, 0);
+		assert_eq!(snes.pointsHardcore, 10);
+		
+		let ids = user.filterAndSort(FilterCriteria::default(), Some(2), RetroAchievementsSort::Name)
+			.iter()
+			.map(|g| g.id)
+			.collect::<Vec<_>>();
+		assert_eq!(ids, vec![2, 1]);
+	}
 }
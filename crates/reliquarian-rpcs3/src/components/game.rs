@@ -1,8 +1,10 @@
 use std::path::PathBuf;
 use components::button::icon::IconButton;
+use components::export::promptExport;
 use components::input::filter::AchievementsFilter;
 use data::constants::{Path_Games, TextColor};
 use data::enums::GamePlatforms;
+use data::export::AchievementExport;
 use data::filter::{FilterCriteria, Filterable};
 use data::io::{FileLocation, filePathExists, getImagePath};
 use freya::icons::lucide;
@@ -45,14 +47,16 @@ impl Component for GameElement
 			.getGame(self.npCommId.clone())
 			.unwrap_or_default();
 		
-		let trophies = game.filter(FilterCriteria
+		let criteria = FilterCriteria
 		{
 			caseSensitive: caseSensitive(),
 			locked: locked(),
 			nameOnly: nameOnly(),
 			text: search.read().clone(),
 			..Default::default()
-		});
+		};
+		
+		let trophies = game.filter(criteria.clone());
 		let trophiesLength = trophies.len();
 		
 		let iconPath = getImagePath(&FileLocation
@@ -109,6 +113,21 @@ impl Component for GameElement
 							.width(Size::flex(1.0))
 					)
 					
+					.child(
+						IconButton::new(lucide::download())
+							.alt("Export")
+							.onPress({
+								let npCommId = npCommId.clone();
+								move |_| if let Some(game) = user.read().getGame(npCommId.clone())
+								{
+									let export = AchievementExport::fromFilteredAchievements(&game, criteria.clone());
+									spawn(async move {
+										promptExport(export, &game.name).await;
+									});
+								}
+							})
+					)
+					
 					.child(
 						IconButton::new(lucide::refresh_ccw())
 							.alt("Refresh")
@@ -118,7 +137,7 @@ impl Component for GameElement
 									user.read().clone(),
 									settings.read().clone()
 								).await;
-								**user.write() = refreshedData;
+								**user.write() = refreshedData.user;
 							}))
 					)
 			)
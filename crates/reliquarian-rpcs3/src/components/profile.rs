@@ -80,7 +80,7 @@ impl Component for Rpcs3ProfileElement
 									user.read().clone(),
 									settings.read().clone()
 								).await;
-								**user.write() = refreshedData;
+								**user.write() = refreshedData.user;
 							}))
 					)
 			)
@@ -1,11 +1,21 @@
 use tracing::{info, warn};
 use crate::api::api::Rpcs3Api;
 use crate::data::io::saveUserData;
+use crate::data::result::Rpcs3OperationResult;
 use crate::data::settings::Rpcs3Settings;
 use crate::data::user::Rpcs3User;
 
-pub async fn refreshUserData(mut user: Rpcs3User, settings: Rpcs3Settings) -> Rpcs3User
+/**
+Read the user's trophy data from the RPCS3 installation and save it.
+
+The refresh has failed when the installation is not configured, or the game
+list could not be read or saved. The RPCN ID is optional, so failing to read it
+does not fail the refresh.
+*/
+pub async fn refreshUserData(mut user: Rpcs3User, settings: Rpcs3Settings) -> Rpcs3OperationResult
 {
+	let mut failed = true;
+	
 	if !settings.appDataDirectory.is_empty()
 	{
 		let api = Rpcs3Api::from(settings.clone());
@@ -15,6 +25,8 @@ pub async fn refreshUserData(mut user: Rpcs3User, settings: Rpcs3Settings) -> Rp
 			Err(e) => warn!("[RPCS3] Error generating game list: {:?}", e),
 			
 			Ok(games) => {
+				failed = false;
+				
 				for npCommId in games.iter().cloned().map(|g| g.npCommId)
 				{
 					if let Err(e) = api.cacheGameIcons(&npCommId)
@@ -46,10 +58,18 @@ pub async fn refreshUserData(mut user: Rpcs3User, settings: Rpcs3Settings) -> Rp
 		
 		match saveUserData(&user)
 		{
-			Err(e) => warn!("[RPCS3] Error saving user data: {:?}", e),
+			Err(e) => {
+				warn!("[RPCS3] Error saving user data: {:?}", e);
+				failed = true;
+			}
+			
 			Ok(_) => info!("[RPCS3] Saved user data"),
 		}
 	}
 	
-	return user;
+	return Rpcs3OperationResult
+	{
+		failed,
+		user,
+	};
 }
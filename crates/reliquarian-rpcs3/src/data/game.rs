@@ -1,7 +1,13 @@
 use std::cmp::Ordering;
+use data::constants::Path_Games;
+use data::enums::GamePlatforms;
 use data::filter::{FilterCriteria, Filterable};
+use data::io::FileLocation;
+use data::unified::{Unifiable, UnifiedGame};
+use macros::join;
 use serde::{Deserialize, Serialize};
 use serde_json::{Map, Value};
+use crate::api::api::Rpcs3Api;
 use crate::api::data::conf::TrophyConf;
 use super::trophy::Trophy;
 
@@ -102,6 +108,30 @@ impl PartialOrd for Game
 	}
 }
 
+impl Unifiable<UnifiedGame> for Game
+{
+	fn unify(&self) -> UnifiedGame
+	{
+		let mut game = UnifiedGame::new(
+			GamePlatforms::Rpcs3,
+			self.npCommId.clone(),
+			self.name.clone(),
+			self.trophies.iter()
+				.map(|t| t.unify())
+				.collect()
+		);
+		
+		game.icon = Some(FileLocation
+		{
+			fileName: Rpcs3Api::GameIconFileName.into(),
+			group: join!(Path_Games, self.npCommId),
+			platform: Rpcs3Api::Platform.to_lowercase(),
+		});
+		
+		return game;
+	}
+}
+
 impl Game
 {
 	#[allow(unused)]
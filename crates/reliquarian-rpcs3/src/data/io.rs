@@ -1,7 +1,7 @@
 use std::io::ErrorKind;
 use anyhow::{Result, anyhow};
-use data::io::{getDataDir, getSettingsDir, readDataFromFile,
-	readRawDataFromFile, writeDataToFile};
+use data::io::{getSettingsDir, readDataFromFile, writeDataToFile};
+use data::storage::{loadUser, saveUser};
 use tracing::warn;
 use crate::data::settings::Rpcs3Settings;
 use crate::data::user::Rpcs3User;
@@ -13,14 +13,12 @@ pub fn loadSettings_Rpcs3() -> Rpcs3Settings
 }
 
 pub fn loadUserData_Rpcs3() -> Rpcs3User
-{	
-	return match loadUserData()
+{
+	return match loadUser()
 	{
 		Err(e) => {
 			warn!("Failed loading RPCS3 user data: {:?}", e);
-			warn!("Attempting RPCS3 user data lossy load");
-			loadUserData_lossy()
-				.unwrap_or_default()
+			Rpcs3User::default()
 		},
 		Ok(user) => user,
 	};
@@ -41,40 +39,6 @@ fn loadSettings() -> Result<Rpcs3Settings>
 	};
 }
 
-/**
-Read the RPCS3 user data from file.
-*/
-fn loadUserData() -> Result<Rpcs3User>
-{
-	return match getDataDir(false)
-	{
-		None => Err(anyhow!(ErrorKind::NotFound)),
-		Some(dir) => readDataFromFile(
-			dir,
-			Rpcs3User::FileName.into()
-		),
-	};
-}
-
-/**
-Read the RPCS3 API user data from file.
-*/
-fn loadUserData_lossy() -> Result<Rpcs3User>
-{
-	return match getDataDir(false)
-	{
-		None => Err(anyhow!(ErrorKind::NotFound)),
-		Some(dir) => {
-			let json = readRawDataFromFile(
-				dir,
-				Rpcs3User::FileName.into()
-			)?;
-			
-			Rpcs3User::parseJsonLossy(json)
-		},
-	};
-}
-
 /**
 Write the RPCS3 settings data to file.
 */
@@ -93,13 +57,5 @@ pub fn saveSettings(settings: &Rpcs3Settings) -> Result<()>
 
 pub fn saveUserData(user: &Rpcs3User) -> Result<()>
 {
-	return match getDataDir(true)
-	{
-		None => Err(anyhow!(ErrorKind::NotFound)),
-		Some(dir) => writeDataToFile(
-			dir,
-			Rpcs3User::FileName.into(),
-			user
-		),
-	};
+	return saveUser(user);
 }
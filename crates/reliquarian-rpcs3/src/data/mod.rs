@@ -1,5 +1,6 @@
 pub mod game;
 pub mod io;
+pub mod result;
 pub mod settings;
 pub mod trophy;
 pub mod user;
@@ -0,0 +1,9 @@
+use super::user::Rpcs3User;
+
+#[derive(Clone, Debug)]
+pub struct Rpcs3OperationResult
+{
+	/// Whether the refresh failed to read or save the user's trophy data.
+	pub failed: bool,
+	pub user: Rpcs3User,
+}
@@ -1,4 +1,5 @@
 use data::enums::GamePlatforms;
+use data::migration::Versioned;
 use freya::radio::RadioChannel;
 use serde::{Deserialize, Serialize};
 
@@ -15,6 +16,8 @@ pub struct Rpcs3Settings
 
 impl RadioChannel<Rpcs3Settings> for GamePlatforms {}
 
+impl Versioned for Rpcs3Settings {}
+
 impl Rpcs3Settings
 {
 	pub const FileName: &str = "rpcs3.json";
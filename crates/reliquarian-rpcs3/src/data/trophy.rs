@@ -1,7 +1,10 @@
 use std::cmp::Ordering;
 use anyhow::{anyhow, Result};
-use chrono::{TimeZone, Utc, offset::LocalResult};
+use chrono::{DateTime, TimeZone, Utc, offset::LocalResult};
 use data::constants::Format_ChronoDateTime;
+use data::enums::GamePlatforms;
+use data::unified::{PsnExtension, PsnGrade, Unifiable, UnifiedAchievement,
+	UnifiedExtension};
 use serde::{Deserialize, Serialize};
 use serde_json::{Map, Value};
 use crate::api::data::conf::TrophyMetadata;
@@ -68,6 +71,30 @@ impl PartialOrd for Trophy
 	}
 }
 
+impl Unifiable<UnifiedAchievement> for Trophy
+{
+	fn unify(&self) -> UnifiedAchievement
+	{
+		return UnifiedAchievement
+		{
+			description: self.detail.clone(),
+			extension: UnifiedExtension::Psn(PsnExtension
+			{
+				grade: self.grade.into(),
+				platinumRelevance: self.platinumRelevance,
+			}),
+			hidden: self.hidden,
+			id: self.id.to_string(),
+			name: self.name.clone(),
+			platform: GamePlatforms::Rpcs3,
+			rarity: None,
+			unlocked: self.unlocked,
+			unlockedTimestamp: self.unlockedTimestamp
+				.and_then(|ts| DateTime::from_timestamp_micros(ts as i64)),
+		};
+	}
+}
+
 impl Trophy
 {
 	pub fn formatUnlockedTimestamp(&self) -> Result<String>
@@ -251,6 +278,21 @@ impl From<&str> for TrophyGrade
 	}
 }
 
+impl From<TrophyGrade> for PsnGrade
+{
+	fn from(value: TrophyGrade) -> Self
+	{
+		return match value
+		{
+			TrophyGrade::Bronze => Self::Bronze,
+			TrophyGrade::Gold => Self::Gold,
+			TrophyGrade::Platinum => Self::Platinum,
+			TrophyGrade::Silver => Self::Silver,
+			TrophyGrade::Unknown => Self::Unknown,
+		};
+	}
+}
+
 impl TrophyGrade
 {
 	pub fn points(&self) -> u64
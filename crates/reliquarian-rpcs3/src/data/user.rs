@@ -1,5 +1,8 @@
 use anyhow::Result;
 use data::{enums::GamePlatforms, filter::{FilterCriteria, Filterable}};
+use data::migration::Versioned;
+use data::storage::Storable;
+use data::unified::{Unifiable, UnifiedGame};
 use freya::radio::RadioChannel;
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
@@ -50,6 +53,30 @@ impl Filterable<Game> for Rpcs3User
 
 impl RadioChannel<Rpcs3User> for GamePlatforms {}
 
+impl Storable for Rpcs3User
+{
+	const DataFileName: &'static str = Rpcs3User::FileName;
+	const GameIdKey: &'static str = "npCommId";
+	const Platform: GamePlatforms = GamePlatforms::Rpcs3;
+	
+	fn parseLossy(json: String) -> Result<Self>
+	{
+		return Rpcs3User::parseJsonLossy(json);
+	}
+}
+
+impl Unifiable<Vec<UnifiedGame>> for Rpcs3User
+{
+	fn unify(&self) -> Vec<UnifiedGame>
+	{
+		return self.games.iter()
+			.map(|g| g.unify())
+			.collect();
+	}
+}
+
+impl Versioned for Rpcs3User {}
+
 impl Rpcs3User
 {
 	pub const FileName: &str = "rpcs3.json";
@@ -56,7 +56,6 @@ impl SteamApi
 	const Endpoint_GetOwnedGames: &str = "GetOwnedGames/v0001";
 	const Endpoint_GetPlayerAchievements: &str = "GetPlayerAchievements/v0001";
 	const Endpoint_GetPlayerSummaries: &str = "GetPlayerSummaries/v0002";
-	#[allow(unused)]
 	const Endpoint_GetRecentlyPlayedGames: &str = "GetRecentlyPlayedGames/v0001";
 	const Endpoint_GetSchemaForGame: &str = "GetSchemaForGame/v0002";
 	const Endpoint_GetSharedLibraryApps: &str = "GetSharedLibraryApps/v1";
@@ -479,7 +478,6 @@ impl SteamApi
 		- playtime_forever - The total number of minutes played "on record", since Steam began tracking total playtime in early 2009.
 		- img_icon_url, img_logo_url - These are the filenames of various images for the game. To construct the URL to the image, use this format: `http://media.steampowered.com/steamcommunity/public/images/apps/{appid}/{hash}.jpg`. For example, the TF2 logo is returned as `07385eb55b5ba974aebbe74d3c99626bda7920b8`, which maps to the URL: `http://media.steampowered.com/steamcommunity/public/images/apps/440/07385eb55b5ba974aebbe74d3c99626bda7920b8.jpg`
 	*/
-	#[allow(unused)]
 	pub async fn getRecentlyPlayedGames(&self) -> Result<Payload_GetRecentlyPlayedGames>
 	{
 		let auth = getSteamAuth()?;
@@ -632,11 +630,65 @@ impl SteamApi
 		return Ok(response);
 	}
 	
+	/**
+	The Web API language code for `language`, as listed in the
+	[Steamworks documentation](https://partner.steamgames.com/doc/store/localization/languages).
+	*/
 	fn evaluateLanguage(language: Language) -> String
 	{
 		return match language
 		{
-			Language::English => "en".to_string(),
-		};
+			Language::Arabic => "ar",
+			Language::Bulgarian => "bg",
+			Language::Czech => "cs",
+			Language::Danish => "da",
+			Language::Dutch => "nl",
+			Language::English => "en",
+			Language::Finnish => "fi",
+			Language::French => "fr",
+			Language::German => "de",
+			Language::Greek => "el",
+			Language::Hungarian => "hu",
+			Language::Indonesian => "id",
+			Language::Italian => "it",
+			Language::Japanese => "ja",
+			Language::Korean => "ko",
+			Language::Norwegian => "no",
+			Language::Polish => "pl",
+			Language::Portuguese => "pt",
+			Language::PortugueseBrazil => "pt-BR",
+			Language::Romanian => "ro",
+			Language::Russian => "ru",
+			Language::SimplifiedChinese => "zh-CN",
+			Language::SpanishLatinAmerica => "es-419",
+			Language::SpanishSpain => "es",
+			Language::Swedish => "sv",
+			Language::Thai => "th",
+			Language::TraditionalChinese => "zh-TW",
+			Language::Turkish => "tr",
+			Language::Ukrainian => "uk",
+			Language::Vietnamese => "vn",
+		}.to_string();
+	}
+}
+
+#[cfg(test)]
+mod tests
+{
+	use std::collections::HashSet;
+	use strum::IntoEnumIterator;
+	use super::*;
+	
+	#[test]
+	fn evaluateLanguage()
+	{
+		assert_eq!(SteamApi::evaluateLanguage(Language::English), "en".to_string());
+		assert_eq!(SteamApi::evaluateLanguage(Language::SpanishLatinAmerica), "es-419".to_string());
+		
+		let codes = Language::iter()
+			.map(|l| SteamApi::evaluateLanguage(l))
+			.collect::<HashSet<_>>();
+		
+		assert_eq!(codes.len(), Language::iter().count());
 	}
 }
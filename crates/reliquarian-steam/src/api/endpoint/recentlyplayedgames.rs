@@ -13,7 +13,11 @@ pub struct Payload_GetRecentlyPlayedGames
 #[derive(Clone, Debug, Default, Deserialize, PartialEq)]
 pub struct RecentlyPlayedGames
 {
+	/// Omitted, along with `games`, when nothing has been played recently.
+	#[serde(default)]
 	pub total_count: u64,
+	
+	#[serde(default)]
 	pub games: Vec<RecentlyPlayed>,
 }
 
@@ -11,6 +11,6 @@ pub use endpoint::{
 	ownedgames::{GameInfo, Payload_GetOwnedGames},
 	playerachievements::{PlayerAchievement, Payload_GetPlayerAchievements},
 	playersummaries::Payload_GetPlayerSummaries,
-	recentlyplayedgames::Payload_GetRecentlyPlayedGames,
+	recentlyplayedgames::{Payload_GetRecentlyPlayedGames, RecentlyPlayed},
 	sharedlibraryapps::{AppInfo, Payload_GetSharedLibraryApps},
 };
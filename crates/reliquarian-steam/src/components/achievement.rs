@@ -1,11 +1,12 @@
 use std::path::PathBuf;
 use data::constants::{BorderColor, CornerRadius, Icon_Locked, Path_Games};
-use data::enums::GamePlatforms;
+use data::enums::{DataChannel, GamePlatforms};
 use data::io::{FileLocation, filePathExists, getImagePath};
-use freya::prelude::{Alignment, Border, BorderAlignment, ChildrenExt, Component,
-	ContainerExt, ContainerSizeExt, ContainerWithContentExt, Content, Direction,
-	Gaps, ImageViewer, IntoElement, Size, StyleExt, TextAlign, TextStyleExt,
-	label, rect};
+use data::settings::AppSettings;
+use freya::prelude::{Alignment, Border, BorderAlignment, ChildrenExt, Color,
+	Component, ContainerExt, ContainerSizeExt, ContainerWithContentExt, Content,
+	Direction, Gaps, ImageViewer, IntoElement, Size, Span, StyleExt, TextAlign,
+	TextStyleExt, label, paragraph, rect};
 use freya::radio::use_radio;
 use macros::{join, jpg, jpgAlt};
 use crate::api::SteamApi;
@@ -22,6 +23,7 @@ impl Component for AchievementElement
 {
 	fn render(&self) -> impl IntoElement
 	{
+		let appSettings = use_radio::<AppSettings, DataChannel>(DataChannel::Settings);
 		let user = use_radio::<SteamUser, GamePlatforms>(GamePlatforms::Steam);
 		
 		let achievement = user.read()
@@ -42,6 +44,8 @@ impl Component for AchievementElement
 		let timestamp = achievement.formatTimestamp()
 			.unwrap_or_default();
 		
+		let rarity = achievement.rarity(&appSettings.read().rarity);
+		
 		let globalPercentage = match achievement.globalPercentage
 		{
 			None => Default::default(),
@@ -116,11 +120,23 @@ impl Component for AchievementElement
 									)
 									
 									.child(
-										label()
-											.font_size(10.0)
+										paragraph()
 											.text_align(TextAlign::End)
-											.text(globalPercentage)
 											.width(Size::flex(0.5))
+											
+											.spans_iter(
+												rarity.map(|tier|
+													Span::new(format!("{} ", tier))
+														.color(tier.color())
+														.font_size(10.0)
+												).into_iter()
+											)
+											
+											.span(
+												Span::new(globalPercentage)
+													.color(rarity.map(|tier| tier.color()).unwrap_or(Color::GRAY))
+													.font_size(10.0)
+											)
 									)
 							)
 					)
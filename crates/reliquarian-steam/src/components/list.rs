@@ -1,23 +1,30 @@
 use std::path::PathBuf;
+use components::button::icon::IconButton;
+use components::export::promptExport;
 use components::extensions::PressableExt;
 use components::input::filter::GamesFilter;
 use data::constants::{BorderColor, ButtonBackgroundColor, CornerRadius,
 	FileName_GameHeader, Path_Games, RetroAchievementsProgressColorBackground,
 	SteamContrast};
 use data::enums::{DataChannel, GamePlatforms};
+use data::export::AchievementExport;
 use data::filter::{FilterCriteria, Filterable};
 use data::io::{FileLocation, filePathExists, getImagePath};
 use data::settings::AppSettings;
+use freya::icons::lucide;
 use freya::prelude::{Alignment, Border, BorderAlignment, ChildrenExt, Code,
 	Color, Component, ContainerExt, ContainerSizeExt, ContainerWithContentExt,
 	Content, Direction, Event, EventHandlersExt, FontWeight, Gaps, ImageViewer,
 	IntoElement, KeyboardEventData, ProgressBar, ProgressBarThemePartialExt,
 	ScrollConfig, ScrollPosition, Size, Span, StyleExt, TextAlign, TextStyleExt,
-	VirtualScrollView, label, paragraph, rect, use_scroll_controller, use_state};
+	VirtualScrollView, WritableUtils, label, paragraph, rect, spawn,
+	use_scroll_controller, use_state};
 use freya::radio::use_radio;
 use macros::{join, jpg};
 use crate::api::SteamApi;
 use crate::data::user::SteamUser;
+use super::rarest::RarestAchievements;
+use super::stats::PlaytimeStatistics;
 
 #[derive(Clone, PartialEq)]
 pub struct GameList;
@@ -33,14 +40,17 @@ impl Component for GameList
 		let caseSensitive = use_state(bool::default);
 		let search = use_state(String::default);
 		let showAll = use_state(|| appSettings.read().displayGamesWithoutAchievements);
+		let mut view = use_state(ListView::default);
 		
-		let games = user.read().filter(FilterCriteria
+		let criteria = FilterCriteria
 		{
 			caseSensitive: caseSensitive(),
 			showAll: showAll(),
 			text: search.read().clone(),
 			..Default::default()
-		});
+		};
+		
+		let games = user.read().filter(criteria.clone());
 		
 		let gamesLength = games.len();
 		
@@ -69,13 +79,45 @@ impl Component for GameList
 			)
 			
 			.child(
-				GamesFilter::new(caseSensitive, search)
+				rect()
+					.content(Content::Flex)
+					.cross_align(Alignment::Center)
+					.direction(Direction::Horizontal)
 					.margin(Gaps::new(5.0, 0.0, 0.0, 0.0))
-					.showAll(showAll)
+					.spacing(10.0)
 					.width(Size::percent(50.0))
+					
+					.child(
+						GamesFilter::new(caseSensitive, search)
+							.showAll(showAll)
+							.width(Size::flex(1.0))
+					)
+					
+					.child(
+						IconButton::new(lucide::gem())
+							.alt("Rarest Achievements")
+							.onPress(move |_| view.set(view().toggle(ListView::Rarest)))
+					)
+					
+					.child(
+						IconButton::new(lucide::chart_bar())
+							.alt("Playtime Statistics")
+							.onPress(move |_| view.set(view().toggle(ListView::Statistics)))
+					)
+					
+					.child(
+						IconButton::new(lucide::download())
+							.alt("Export")
+							.onPress(move |_| {
+								let export = AchievementExport::fromFilteredGames(&*user.read(), criteria.clone());
+								spawn(async move {
+									promptExport(export, "Steam").await;
+								});
+							})
+					)
 			)
 			
-			.child(
+			.maybe_child((view() == ListView::Games).then(||
 				VirtualScrollView::new_controlled(
 					move |i, _| {
 						let game = &games[i];
@@ -88,7 +130,15 @@ impl Component for GameList
 					.item_size(105.0)
 					.length(gamesLength)
 					.scroll_with_arrows(true)
-			);
+			))
+			
+			.maybe_child((view() == ListView::Rarest).then(||
+				RarestAchievements::new()
+			))
+			
+			.maybe_child((view() == ListView::Statistics).then(||
+				PlaytimeStatistics::new()
+			));
 	}
 }
 
@@ -100,6 +150,28 @@ impl GameList
 	}
 }
 
+/// The content displayed beneath the filter.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+enum ListView
+{
+	#[default]
+	Games,
+	Rarest,
+	Statistics,
+}
+
+impl ListView
+{
+	/// Switch to `view`, or back to the list of games if it is already displayed.
+	fn toggle(self, view: Self) -> Self
+	{
+		return match self == view
+		{
+			false => view,
+			true => Self::Games,
+		};
+	}
+}
 
 #[derive(Clone, PartialEq)]
 pub struct GameListNode
@@ -3,5 +3,7 @@ pub mod content;
 pub mod game;
 pub mod list;
 pub mod profile;
+pub mod rarest;
 pub mod refresh;
 pub mod settings;
+pub mod stats;
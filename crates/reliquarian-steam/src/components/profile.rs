@@ -78,15 +78,9 @@ impl Component for SteamProfile
 							.width(Size::px(32.0))
 							.onPress(move |_| {
 								spawn(async move {
-									rateLimiter.read().pushAll(vec![
-										SteamOperation::GetPlayerSummary.into(),
-										match settings.read().enableSteamFamilyLibrary
-										{
-											false => SteamOperation::GetGameList.into(),
-											true => SteamOperation::GetSharedLibraryApps.into(),
-										},
-										SteamOperation::SaveToFile.into(),
-									]).await;
+									let mut requests = SteamOperation::refreshRequests(&settings.read(), &user.read());
+									requests.push(SteamOperation::SaveToFile.into());
+									rateLimiter.read().pushAll(requests).await;
 									
 									**requestEvent.write() = RequestEvent::Added;
 								});
@@ -0,0 +1,63 @@
+use data::enums::GamePlatforms;
+use freya::prelude::{Alignment, ChildrenExt, Color, Component, ContainerExt,
+	ContainerSizeExt, ContainerWithContentExt, Direction, IntoElement,
+	ScrollView, Size, TextStyleExt, label, rect};
+use freya::radio::use_radio;
+use crate::data::user::SteamUser;
+use super::achievement::AchievementElement;
+
+/// The number of achievements displayed in the list of rarest unlocks.
+const RarestCount: usize = 25;
+
+/**
+The rarest achievements the user has unlocked across every game, labeled with
+the name of the game to which each belongs.
+*/
+#[derive(Clone, PartialEq)]
+pub struct RarestAchievements;
+
+impl Component for RarestAchievements
+{
+	fn render(&self) -> impl IntoElement
+	{
+		let user = use_radio::<SteamUser, GamePlatforms>(GamePlatforms::Steam);
+		
+		let unlocks = user.read().rarestUnlocks(RarestCount);
+		
+		return ScrollView::new()
+			.height(Size::flex(1.0))
+			
+			.children(
+				unlocks.into_iter().map(|(gameId, achievement)| {
+					let gameName = user.read().getGame(gameId)
+						.map(|g| g.name)
+						.unwrap_or_default();
+					
+					rect()
+						.cross_align(Alignment::Center)
+						.direction(Direction::Vertical)
+						.width(Size::Fill)
+						
+						.child(
+							label()
+								.color(Color::GRAY)
+								.font_size(12.0)
+								.min_width(Size::px(540.0))
+								.width(Size::percent(50.0))
+								.text(gameName)
+						)
+						
+						.child(AchievementElement::new(gameId, achievement.id))
+						.into()
+				})
+			);
+	}
+}
+
+impl RarestAchievements
+{
+	pub fn new() -> Self
+	{
+		return Self {};
+	}
+}
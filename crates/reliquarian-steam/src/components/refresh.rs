@@ -20,17 +20,17 @@ pub async fn handleSteamOperation(mut user: SteamUser, dataOperation: DataOperat
 		Ok(operation) => match operation
 		{
 			SteamOperation::GetGameList => {
-				let user = refreshGameList(user).await;
+				let result = refreshGameList(user).await;
 				info!("[Steam API] Refreshed game list");
 				
-				Some(user.into())
+				Some(result)
 			}
 			
 			SteamOperation::GetGlobalPercentages(id) => {
-				let user = refreshGlobalPercentages(user, id).await;
+				let result = refreshGlobalPercentages(user, id).await;
 				info!("[Steam API] Refreshed global percentages for app id {}", id);
 				
-				Some(user.into())
+				Some(result)
 			}
 			
 			SteamOperation::GetGameImage(id, force) => {
@@ -41,10 +41,10 @@ pub async fn handleSteamOperation(mut user: SteamUser, dataOperation: DataOperat
 			}
 			
 			SteamOperation::GetPlayerAchievements(id) => {
-				let user = refreshGameAchievements(user, id, language).await;
+				let result = refreshGameAchievements(user, id, language).await;
 				info!("[Steam API] Refreshed achievements for app id {}", id);
 				
-				Some(user.into())
+				Some(result)
 			}
 			
 			SteamOperation::GetPlayerSummary => {
@@ -54,6 +54,13 @@ pub async fn handleSteamOperation(mut user: SteamUser, dataOperation: DataOperat
 				Some(result)
 			}
 			
+			SteamOperation::GetRecentlyPlayedGames => {
+				let result = refreshRecentlyPlayedGames(user).await;
+				info!("[Steam API] Refreshed recently played games");
+				
+				Some(result)
+			}
+			
 			SteamOperation::GetSchemaForGame(id)  => {
 				let result = refreshGameSchema(user, id, language).await;
 				info!("[Steam API] Refreshed schema for app id {}", id);
@@ -93,89 +100,129 @@ pub async fn handleSteamOperation(mut user: SteamUser, dataOperation: DataOperat
 
 async fn refreshGameList(mut user: SteamUser) -> SteamOperationResult
 {
+	let mut failed = true;
 	let mut requests = vec![];
 	if getSteamAuth().is_ok_and(|a| a.validate())
 	{
 		let api = SteamApi::default();
-		if let Ok(payload) = api.getOwnedGames().await
+		match api.getOwnedGames().await
 		{
-			user.processOwnedGames(payload);
-			
-			// Cache game images
-			for game in user.games.iter()
-			{
-				requests.push(SteamOperation::GetGameImage(game.id, false).into());
+			Err(e) => warn!("[Steam API] Error retrieving owned games: {:?}", e),
+			Ok(payload) => {
+				failed = false;
+				user.processOwnedGames(payload);
+				
+				requests.append(&mut gameImageRequests(&user));
 			}
 		}
 	}
 	
 	return SteamOperationResult
 	{
+		failed,
 		user,
 		requests,
 	};
 }
 
-/*
-if let Ok(payload) = api.getRecentlyPlayedGames().await
+/**
+Update the recently played games and queue the operations which refresh their
+achievements. Only games which have not yet been loaded need their schema.
+*/
+async fn refreshRecentlyPlayedGames(mut user: SteamUser) -> SteamOperationResult
 {
-	if !payload.response.games.is_empty()
+	let mut failed = true;
+	let mut requests = vec![];
+	if getSteamAuth().is_ok_and(|a| a.validate())
 	{
-		// Good for play time in past 2 weeks
-		// Will require update to the Game struct
-		// Should Option<> and only titles returned from this have a value
-		//	when processing from here, first step is to clear all existing values
+		let api = SteamApi::default();
+		match api.getRecentlyPlayedGames().await
+		{
+			Err(e) => warn!("[Steam API] Error retrieving recently played games: {:?}", e),
+			Ok(payload) => {
+				failed = false;
+				
+				for id in user.processRecentlyPlayedGames(payload)
+				{
+					if let Some(game) = user.getGame(id)
+					{
+						if !game.loaded
+						{
+							requests.push(SteamOperation::GetGameImage(id, false).into());
+							requests.push(SteamOperation::GetSchemaForGame(id).into());
+						}
+						
+						requests.push(SteamOperation::GetPlayerAchievements(id).into());
+						requests.push(SteamOperation::GetGlobalPercentages(id).into());
+					}
+				}
+				
+				requests.push(SteamOperation::SaveToFile.into());
+			}
+		}
 	}
+	
+	return SteamOperationResult
+	{
+		failed,
+		user,
+		requests,
+	};
 }
-*/
 
 async fn refreshGameSchema(mut user: SteamUser, id: u64, language: Language) -> SteamOperationResult
 {
+	let mut failed = true;
 	let mut requests = vec![];
 	if getSteamAuth().is_ok_and(|a| a.validate())
 	{
 		let api = SteamApi::default();
-		if let Ok(payload) = api.getSchemaForGame(id, language).await
+		match api.getSchemaForGame(id, language).await
 		{
-			if let Some(game) = user.games.iter_mut()
-				.find(|g| g.id == id)
-			{
-				game.updateAchievementsMetadata(&payload);
-			}
-			
-			// Cache achievement icons
-			if let Some(achievements) = payload.game.availableGameStats.achievements
-			{
-				let group = join!(Path_Games, id);
-				let platform = SteamApi::Platform.to_string();
+			Err(e) => warn!("[Steam API] Error retrieving schema for app id {}: {:?}", id, e),
+			Ok(payload) => {
+				failed = false;
 				
-				for achievement in achievements
+				if let Some(game) = user.games.iter_mut()
+					.find(|g| g.id == id)
 				{
-					//Unlocked
-					requests.push(DataRequest
-					{
-						destination: Some(FileLocation
-						{
-							fileName: jpg!(achievement.name),
-							group: group.clone(),
-							platform: platform.clone(),
-						}),
-						operation: DataOperation::CacheImage(false),
-						url: Some(achievement.icon),
-					});
+					game.updateAchievementsMetadata(&payload);
+				}
+				
+				// Cache achievement icons
+				if let Some(achievements) = payload.game.availableGameStats.achievements
+				{
+					let group = join!(Path_Games, id);
+					let platform = SteamApi::Platform.to_string();
 					
-					//Locked
-					requests.push(DataRequest
+					for achievement in achievements
 					{
-						destination: Some(FileLocation
+						//Unlocked
+						requests.push(DataRequest
 						{
-							fileName: jpgAlt!(achievement.name, Icon_Locked),
-							group: group.clone(),
-							platform: platform.clone(),
-						}),
-						operation: DataOperation::CacheImage(false),
-						url: Some(achievement.icongray),
-					});
+							destination: Some(FileLocation
+							{
+								fileName: jpg!(achievement.name),
+								group: group.clone(),
+								platform: platform.clone(),
+							}),
+							operation: DataOperation::CacheImage(false),
+							url: Some(achievement.icon),
+						});
+						
+						//Locked
+						requests.push(DataRequest
+						{
+							destination: Some(FileLocation
+							{
+								fileName: jpgAlt!(achievement.name, Icon_Locked),
+								group: group.clone(),
+								platform: platform.clone(),
+							}),
+							operation: DataOperation::CacheImage(false),
+							url: Some(achievement.icongray),
+						});
+					}
 				}
 			}
 		}
@@ -183,45 +230,76 @@ async fn refreshGameSchema(mut user: SteamUser, id: u64, language: Language) ->
 	
 	return SteamOperationResult
 	{
+		failed,
 		user,
 		requests,
 	};
 }
 
-async fn refreshGameAchievements(mut user: SteamUser, id: u64, language: Language) -> SteamUser
+async fn refreshGameAchievements(mut user: SteamUser, id: u64, language: Language) -> SteamOperationResult
 {
+	let mut failed = true;
 	if getSteamAuth().is_ok_and(|a| a.validate())
 	{
 		let api = SteamApi::default();
-		if let Ok(payload) = api.getPlayerAchievements(id, language).await
+		match api.getPlayerAchievements(id, language).await
 		{
-			if let Some(game) = user.games.iter_mut()
-				.find(|g| g.id == id)
-			{
-				game.updateAchievementsState(&payload);
+			Err(e) => {
+				warn!("[Steam API] Error retrieving achievements for app id {}: {:?}", id, e);
+				failed = hasAchievements(&user, id);
+			}
+			
+			Ok(payload) => {
+				failed = false;
+				
+				if let Some(game) = user.games.iter_mut()
+					.find(|g| g.id == id)
+				{
+					game.updateAchievementsState(&payload);
+				}
 			}
 		}
 	}
 	
-	return user;
+	return SteamOperationResult
+	{
+		failed,
+		user,
+		requests: vec![],
+	};
 }
 
-async fn refreshGlobalPercentages(mut user: SteamUser, id: u64) -> SteamUser
+async fn refreshGlobalPercentages(mut user: SteamUser, id: u64) -> SteamOperationResult
 {
+	let mut failed = true;
 	if getSteamAuth().is_ok_and(|a| a.validate())
 	{
 		let api = SteamApi::default();
-		if let Ok(payload) = api.getGlobalPercentages(id).await
+		match api.getGlobalPercentages(id).await
 		{
-			if let Some(game) = user.games.iter_mut()
-				.find(|g| g.id == id)
-			{
-				game.updateGlobalPercentages(&payload);
+			Err(e) => {
+				warn!("[Steam API] Error retrieving global percentages for app id {}: {:?}", id, e);
+				failed = hasAchievements(&user, id);
+			}
+			
+			Ok(payload) => {
+				failed = false;
+				
+				if let Some(game) = user.games.iter_mut()
+					.find(|g| g.id == id)
+				{
+					game.updateGlobalPercentages(&payload);
+				}
 			}
 		}
 	}
 	
-	return user;
+	return SteamOperationResult
+	{
+		failed,
+		user,
+		requests: vec![],
+	};
 }
 
 async fn refreshImages(user: SteamUser, id: u64, force: bool) -> SteamOperationResult
@@ -256,8 +334,10 @@ async fn refreshImages(user: SteamUser, id: u64, force: bool) -> SteamOperationR
 		}
 	}
 	
+	// Missing images do not leave any achievement data out of date
 	return SteamOperationResult
 	{
+		failed: false,
 		user,
 		requests,
 	};
@@ -265,15 +345,19 @@ async fn refreshImages(user: SteamUser, id: u64, force: bool) -> SteamOperationR
 
 async fn refreshPlayerSummary(mut user: SteamUser) -> SteamOperationResult
 {
+	let mut failed = true;
 	let mut requests = vec![];
 	if getSteamAuth().is_ok_and(|a| a.validate())
 	{
 		let api = SteamApi::default();
 		
-		if let Ok(payload) = api.getPlayerSummaries().await
+		match api.getPlayerSummaries().await
 		{
-			if let Some(profile) = payload.response.players.first()
+			Err(e) => warn!("[Steam API] Error retrieving player summary: {:?}", e),
+			Ok(payload) => if let Some(profile) = payload.response.players.first()
 			{
+				failed = false;
+				
 				user.update(
 					&profile.steamid,
 					&profile.personaname,
@@ -306,6 +390,7 @@ async fn refreshPlayerSummary(mut user: SteamUser) -> SteamOperationResult
 	
 	return SteamOperationResult
 	{
+		failed,
 		user,
 		requests
 	};
@@ -313,26 +398,51 @@ async fn refreshPlayerSummary(mut user: SteamUser) -> SteamOperationResult
 
 async fn refreshSharedLibrary(mut user: SteamUser) -> SteamOperationResult
 {
+	let mut failed = true;
 	let mut requests = vec![];
 	
 	if getSteamWebToken().is_ok_and(|t| !t.is_empty())
 	{
 		let api = SteamApi::default();
-		if let Ok(payload) = api.getSharedLibraryApps().await
+		match api.getSharedLibraryApps().await
 		{
-			user.processSharedGames(payload);
-			
-			// Cache game images
-			for game in user.games.iter()
-			{
-				requests.push(SteamOperation::GetGameImage(game.id, false).into());
+			Err(e) => warn!("[Steam API] Error retrieving shared library games: {:?}", e),
+			Ok(payload) => {
+				failed = false;
+				user.processSharedGames(payload);
+				
+				requests.append(&mut gameImageRequests(&user));
 			}
 		}
 	}
 	
 	return SteamOperationResult
 	{
+		failed,
 		user,
 		requests,
 	};
 }
+
+/**
+Whether the game is known to have achievements.
+
+Steam rejects the achievement endpoints for games without any, so a failure
+only matters when there is something to refresh.
+*/
+fn hasAchievements(user: &SteamUser, id: u64) -> bool
+{
+	return user.getGame(id)
+		.is_some_and(|g| !g.achievements.is_empty());
+}
+
+/// Cache game images, starting with the most recently played games.
+fn gameImageRequests(user: &SteamUser) -> Vec<DataRequest>
+{
+	let mut games = user.games.iter().collect::<Vec<_>>();
+	games.sort_by(|a, b| b.lastPlayed.cmp(&a.lastPlayed));
+	
+	return games.iter()
+		.map(|g| SteamOperation::GetGameImage(g.id, false).into())
+		.collect();
+}
@@ -33,6 +33,7 @@ impl Component for SteamSettingsElement
 		let mut apiKey = use_state(String::default);
 		let mut familyToken = use_state(String::default);
 		let mut id = use_state(String::default);
+		let mut quickRefresh = use_state(|| settings.read().quickRefresh);
 		let mut toggleSharedLibrary = use_state(|| settings.read().enableSteamFamilyLibrary);
 		
 		let inputModeApiKey = use_state(|| InputMode::Hidden(InputModeHiddenChar));
@@ -56,11 +57,13 @@ impl Component for SteamSettingsElement
 			_ = setSteamApiKey(apiKey.read().clone());
 			_ = setSteamId(id.read().clone());
 			
+			let quick = quickRefresh();
 			let toggle = toggleSharedLibrary();
 			
-			if settings.read().enableSteamFamilyLibrary != toggle
+			if settings.read().enableSteamFamilyLibrary != toggle || settings.read().quickRefresh != quick
 			{
 				settings.write().enableSteamFamilyLibrary = toggle;
+				settings.write().quickRefresh = quick;
 				match saveSettings(&settings.read())
 				{
 					Err(e) => warn!("[Steam] Error saving settings to file: {:?}", e),
@@ -165,6 +168,30 @@ impl Component for SteamSettingsElement
 					.child(InputModeSwitch(inputModeApiKey))
 			)
 			
+			.child(
+				rect()
+					.content(Content::Flex)
+					.cross_align(Alignment::Center)
+					.direction(Direction::Horizontal)
+					.main_align(Alignment::Center)
+					.spacing(10.0)
+					.width(Size::percent(75.0))
+					
+					.child(
+						label()
+							.min_width(Size::px(102.0))
+							.text_align(TextAlign::End)
+							.width(self.labelWidth.clone())
+							.text("Quick Refresh")
+					)
+					
+					.child(
+						Switch::new()
+							.toggled(quickRefresh())
+							.on_toggle(move |_| quickRefresh.set(!quickRefresh()))
+					)
+			)
+			
 			.child(
 				rect()
 					.content(Content::Flex)
@@ -0,0 +1,222 @@
+use data::constants::{BorderColor, CornerRadius,
+	RetroAchievementsProgressColorBackground, SteamContrast};
+use data::enums::GamePlatforms;
+use freya::prelude::{Alignment, Border, BorderAlignment, ChildrenExt, Color,
+	Component, ContainerExt, ContainerSizeExt, ContainerWithContentExt, Content,
+	Direction, FontWeight, Gaps, IntoElement, ProgressBar,
+	ProgressBarThemePartialExt, ScrollView, Size, Span, StyleExt, TextAlign,
+	TextStyleExt, label, paragraph, rect};
+use freya::radio::use_radio;
+use crate::data::stats::{PlaytimeStats, hours};
+use crate::data::user::SteamUser;
+
+/// The number of games displayed in the list of most played games.
+const TopGamesCount: usize = 10;
+
+/**
+Playtime totals across the user's library, charting the time played on each
+operating system and the most played games.
+*/
+#[derive(Clone, PartialEq)]
+pub struct PlaytimeStatistics;
+
+impl Component for PlaytimeStatistics
+{
+	fn render(&self) -> impl IntoElement
+	{
+		let user = use_radio::<SteamUser, GamePlatforms>(GamePlatforms::Steam);
+		
+		let stats = PlaytimeStats::new(&user.read().games, TopGamesCount);
+		let unknown = stats.unknownPlatform();
+		
+		// Chart the most played games relative to the first, so the longest bar is always full
+		let mostPlayed = stats.topGames.first()
+			.map(|g| g.total)
+			.unwrap_or_default();
+		
+		let platforms = [
+			("Linux", stats.playtime.linux),
+			("Windows", stats.playtime.windows),
+			("macOS", stats.playtime.mac),
+		];
+		
+		return ScrollView::new()
+			.height(Size::flex(1.0))
+			
+			.child(
+				rect()
+					.cross_align(Alignment::Center)
+					.direction(Direction::Vertical)
+					.spacing(10.0)
+					.width(Size::Fill)
+					
+					.child(
+						rect()
+							.content(Content::Flex)
+							.direction(Direction::Horizontal)
+							.min_width(Size::px(540.0))
+							.spacing(10.0)
+							.width(Size::percent(50.0))
+							
+							.child(summaryElement(hours(stats.playtime.total).to_string(), "Hours Played"))
+							.child(summaryElement(stats.gamesPlayed.to_string(), "Games Played"))
+							.child(summaryElement(
+								match stats.hoursPerAchievement()
+								{
+									None => "N/A".to_string(),
+									Some(value) => value.to_string(),
+								},
+								"Hours per Achievement"
+							))
+					)
+					
+					.child(headingElement("Playtime by Platform"))
+					
+					.children(
+						platforms.into_iter()
+							.map(|(name, minutes)| barElement(
+								name,
+								minutes,
+								stats.share(minutes),
+								stats.share(minutes)
+							).into())
+					)
+					
+					.maybe_child((unknown > 0).then(||
+						barElement(
+							"Unknown",
+							unknown,
+							stats.share(unknown),
+							stats.share(unknown)
+						)
+					))
+					
+					.maybe_child((stats.playtime.offline > 0).then(||
+						label()
+							.color(Color::GRAY)
+							.font_size(12.0)
+							.min_width(Size::px(540.0))
+							.width(Size::percent(50.0))
+							.text(format!(
+								"Including {} hours played offline ({}%)",
+								hours(stats.playtime.offline),
+								stats.share(stats.playtime.offline)
+							))
+					))
+					
+					.child(headingElement("Most Played"))
+					
+					.children(
+						stats.topGames.iter()
+							.map(|g| barElement(
+								g.name.clone(),
+								g.total,
+								match mostPlayed
+								{
+									0 => 0.0,
+									_ => g.total as f32 / mostPlayed as f32 * 100.0,
+								},
+								stats.share(g.total)
+							).into())
+					)
+			);
+	}
+}
+
+impl PlaytimeStatistics
+{
+	pub fn new() -> Self
+	{
+		return Self {};
+	}
+}
+
+/**
+A single bar of a chart, labeled with the hours played and the share of the
+total playtime they represent.
+*/
+fn barElement(name: impl Into<String>, minutes: u64, length: f32, share: f32) -> impl IntoElement
+{
+	return rect()
+		.direction(Direction::Vertical)
+		.min_width(Size::px(540.0))
+		.spacing(5.0)
+		.width(Size::percent(50.0))
+		
+		.child(
+			rect()
+				.direction(Direction::Horizontal)
+				.main_align(Alignment::SpaceBetween)
+				.width(Size::Fill)
+				
+				.child(label().text(name.into()))
+				
+				.child(
+					paragraph()
+						.text_align(TextAlign::End)
+						
+						.span(
+							Span::new(format!("{} hours ", hours(minutes)))
+								.font_size(12.0)
+						)
+						
+						.span(
+							Span::new(format!("({}%)", share))
+								.color(Color::GRAY)
+								.font_size(12.0)
+						)
+				)
+		)
+		
+		.child(
+			ProgressBar::new(length)
+				.background(RetroAchievementsProgressColorBackground)
+				.color(SteamContrast)
+				.height(8.0)
+				.progress_background(SteamContrast)
+				.width(Size::Fill)
+		);
+}
+
+fn headingElement(text: impl Into<String>) -> impl IntoElement
+{
+	return label()
+		.font_weight(FontWeight::BOLD)
+		.margin(Gaps::new(10.0, 0.0, 0.0, 0.0))
+		.min_width(Size::px(540.0))
+		.width(Size::percent(50.0))
+		.text(text.into());
+}
+
+fn summaryElement(value: impl Into<String>, caption: impl Into<String>) -> impl IntoElement
+{
+	return rect()
+		.border(Some(
+			Border::new()
+				.alignment(BorderAlignment::Center)
+				.fill(BorderColor)
+				.width(1.0)
+		))
+		.corner_radius(CornerRadius)
+		.cross_align(Alignment::Center)
+		.direction(Direction::Vertical)
+		.padding(Gaps::new_all(10.0))
+		.spacing(5.0)
+		.width(Size::flex(1.0))
+		
+		.child(
+			label()
+				.font_size(24.0)
+				.font_weight(FontWeight::BOLD)
+				.text_align(TextAlign::Center)
+				.text(value.into())
+		)
+		
+		.child(
+			label()
+				.color(Color::GRAY)
+				.font_size(12.0)
+				.text_align(TextAlign::Center)
+				.text(caption.into())
+		);
+}
@@ -1,11 +1,14 @@
 use std::cmp::Ordering;
-use chrono::{MappedLocalTime, TimeZone, Utc};
+use chrono::{DateTime, MappedLocalTime, TimeZone, Utc};
 use data::constants::Format_ChronoDateTime;
+use data::enums::{GamePlatforms, RarityTier};
+use data::settings::RarityThresholds;
+use data::unified::{Unifiable, UnifiedAchievement, UnifiedExtension};
 use serde::{Deserialize, Serialize};
 use serde_json::{Map, Value};
 use crate::api::{GameAchievement, PlayerAchievement};
 
-#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Ord, Serialize)]
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
 pub struct SteamAchievement
 {
 	/// The human-readable description of the achievement.
@@ -14,7 +17,7 @@ pub struct SteamAchievement
 	
 	/// The percentage of users on the platform who have unlocked the achievement.
 	#[serde(default)]
-	pub globalPercentage: Option<String>,
+	pub globalPercentage: Option<f32>,
 	
 	/// Flag denoting whether or not the details of the achievement are meant to be hidden.
 	#[serde(default)]
@@ -40,6 +43,18 @@ pub struct SteamAchievement
 	pub timestamp: Option<u64>,
 }
 
+/// Global percentages are never `NaN`, so equality is total.
+impl Eq for SteamAchievement {}
+
+impl Ord for SteamAchievement
+{
+	fn cmp(&self, other: &Self) -> Ordering
+	{
+		return self.partial_cmp(other)
+			.unwrap_or(Ordering::Equal);
+	}
+}
+
 impl PartialOrd for SteamAchievement
 {
 	fn partial_cmp(&self, other: &Self) -> Option<Ordering>
@@ -83,10 +98,28 @@ impl From<PlayerAchievement> for SteamAchievement
 {
 	fn from(value: PlayerAchievement) -> Self
 	{
-		return Self
+		let mut instance = Self::default();
+		instance.updateState(&value);
+		return instance;
+	}
+}
+
+impl Unifiable<UnifiedAchievement> for SteamAchievement
+{
+	fn unify(&self) -> UnifiedAchievement
+	{
+		return UnifiedAchievement
 		{
-			timestamp: Some(value.unlocktime),
-			..Default::default()
+			description: self.description.clone(),
+			extension: UnifiedExtension::None,
+			hidden: self.hidden,
+			id: self.id.clone(),
+			name: self.name.clone(),
+			platform: GamePlatforms::Steam,
+			rarity: self.globalPercentage.map(f64::from),
+			unlocked: self.unlocked(),
+			unlockedTimestamp: self.timestamp
+				.and_then(|ts| DateTime::from_timestamp_millis(ts as i64)),
 		};
 	}
 }
@@ -122,12 +155,10 @@ impl SteamAchievement
 		if let Some((_, value)) = map.iter()
 			.find(|(key, _)| key.as_str() == "globalPercentage")
 		{
-			if let Value::String(inner) = value
+			if let Value::Number(inner) = value
 			{
-				if !inner.is_empty()
-				{
-					achievement.globalPercentage = Some(inner.clone());
-				}
+				achievement.globalPercentage = inner.as_f64()
+					.map(|number| number as f32);
 			}
 		}
 		
@@ -195,6 +226,23 @@ impl SteamAchievement
 		};
 	}
 	
+	/**
+	Parse the global unlock percentage, which the Steam Web API reports as a
+	string.
+	*/
+	pub fn parseGlobalPercentage(percent: &str) -> Option<f32>
+	{
+		return percent.parse::<f32>()
+			.ok()
+			.filter(|p| p.is_finite());
+	}
+	
+	pub fn rarity(&self, thresholds: &RarityThresholds) -> Option<RarityTier>
+	{
+		return self.globalPercentage
+			.map(|percent| thresholds.tier(percent));
+	}
+	
 	pub fn unlocked(&self) -> bool
 	{
 		return self.timestamp.is_some();
@@ -233,7 +281,7 @@ mod tests
 	{
 		let mut map = Map::new();
 		map.insert("description".into(), "The description".into());
-		map.insert("globalPercentage".into(), "The percent".into());
+		map.insert("globalPercentage".into(), 12.5.into());
 		map.insert("hidden".into(), true.into());
 		map.insert("iconLockedUrl".into(), "The icon locked url".into());
 		map.insert("iconUrl".into(), "The icon url".into());
@@ -262,7 +310,7 @@ mod tests
 		
 		let achievement = success.unwrap();
 		assert_eq!(achievement.description, "The description".to_string());
-		assert_eq!(achievement.globalPercentage, Some("The percent".to_string()));
+		assert_eq!(achievement.globalPercentage, Some(12.5));
 		assert_eq!(achievement.hidden, true);
 		assert_eq!(achievement.iconLockedUrl, "The icon locked url".to_string());
 		assert_eq!(achievement.iconUrl, "The icon url".to_string());
@@ -270,4 +318,23 @@ mod tests
 		assert_eq!(achievement.name, "The name".to_string());
 		assert_eq!(achievement.timestamp, Some(1029384756));
 	}
+	
+	#[test]
+	fn rarity()
+	{
+		let thresholds = RarityThresholds::default();
+		
+		assert_eq!(SteamAchievement::parseGlobalPercentage("The percent"), None);
+		assert_eq!(SteamAchievement::parseGlobalPercentage("NaN"), None);
+		assert_eq!(SteamAchievement::parseGlobalPercentage("3.2000000476837158"), Some(3.2));
+		
+		let mut achievement = SteamAchievement::default();
+		assert_eq!(achievement.rarity(&thresholds), None);
+		
+		achievement.globalPercentage = Some(3.2);
+		assert_eq!(achievement.rarity(&thresholds), Some(RarityTier::UltraRare));
+		
+		achievement.globalPercentage = Some(64.5);
+		assert_eq!(achievement.rarity(&thresholds), Some(RarityTier::Common));
+	}
 }
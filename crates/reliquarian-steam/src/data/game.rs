@@ -1,11 +1,16 @@
 use std::cmp::Ordering;
-use data::constants::TheString;
+use data::constants::{FileName_GameHeader, Path_Games, TheString};
+use data::enums::GamePlatforms;
 use data::filter::{FilterCriteria, Filterable};
 use data::format::truncateF32;
+use data::io::FileLocation;
+use data::unified::{Unifiable, UnifiedGame};
+use macros::{join, jpg};
 use serde::{Deserialize, Serialize};
 use serde_json::{Map, Value};
 use crate::api::{AppInfo, GameInfo, Payload_GetGlobalPercentages,
-	Payload_GetPlayerAchievements, Payload_GetSchemaForGame};
+	Payload_GetPlayerAchievements, Payload_GetSchemaForGame, RecentlyPlayed,
+	SteamApi};
 use super::achievement::SteamAchievement;
 use super::playtime::Playtime;
 
@@ -45,6 +50,13 @@ pub struct Game
 	/// The amount of time played across platforms and offline.
 	#[serde(default)]
 	pub playtime: Playtime,
+	
+	/**
+	The number of minutes played in the two weeks before the last refresh of
+	recently played games, or `None` if the game was not played in that time.
+	*/
+	#[serde(default)]
+	pub recentPlaytime: Option<u64>,
 }
 
 impl Filterable<SteamAchievement> for Game
@@ -114,6 +126,16 @@ impl From<GameInfo> for Game
 	}
 }
 
+impl From<RecentlyPlayed> for Game
+{
+	fn from(value: RecentlyPlayed) -> Self
+	{
+		let mut instance = Self::default();
+		instance.updateRecentlyPlayed(&value);
+		return instance;
+	}
+}
+
 impl PartialOrd for Game
 {
 	fn partial_cmp(&self, other: &Self) -> Option<Ordering>
@@ -191,6 +213,30 @@ impl PartialOrd for Game
 	}
 }
 
+impl Unifiable<UnifiedGame> for Game
+{
+	fn unify(&self) -> UnifiedGame
+	{
+		let mut game = UnifiedGame::new(
+			GamePlatforms::Steam,
+			self.id.to_string(),
+			self.name.clone(),
+			self.achievements.iter()
+				.map(|a| a.unify())
+				.collect()
+		);
+		
+		game.icon = Some(FileLocation
+		{
+			fileName: jpg!(FileName_GameHeader),
+			group: join!(Path_Games, self.id),
+			platform: SteamApi::Platform.to_lowercase(),
+		});
+		
+		return game;
+	}
+}
+
 impl Game
 {
 	pub fn parseJsonMap(map: &Map<String, Value>) -> Option<Self>
@@ -287,6 +333,15 @@ impl Game
 			}
 		}
 		
+		if let Some((_, value)) = map.iter()
+			.find(|(key, _)| key.as_str() == "recentPlaytime")
+		{
+			if let Value::Number(inner) = value
+			{
+				game.recentPlaytime = inner.as_u64();
+			}
+		}
+		
 		return match game.id
 		{
 			0 => None,
@@ -345,6 +400,20 @@ impl Game
 		self.playtime.updateShared(info);
 	}
 	
+	pub fn updateRecentlyPlayed(&mut self, info: &RecentlyPlayed)
+	{
+		self.id = info.appid;
+		
+		if !info.img_icon_url.is_empty()
+		{
+			self.iconHash = info.img_icon_url.clone();
+		}
+		
+		self.name = info.name.clone();
+		self.playtime.updateRecent(info);
+		self.recentPlaytime = Some(info.playtime_2weeks);
+	}
+	
 	pub fn updateGlobalPercentages(&mut self, payload: &Payload_GetGlobalPercentages)
 	{
 		for gp in &payload.achievementpercentages.achievements
@@ -352,11 +421,7 @@ impl Game
 			if let Some(achievement) = self.achievements.iter_mut()
 				.find(|a| a.id == gp.name)
 			{
-				achievement.globalPercentage = match gp.percent.is_empty()
-				{
-					true => None,
-					false => Some(gp.percent.clone()),
-				};
+				achievement.globalPercentage = SteamAchievement::parseGlobalPercentage(&gp.percent);
 			}
 		}
 	}
@@ -439,6 +504,7 @@ mod tests
 		map.insert("loaded".into(), true.into());
 		map.insert("name".into(), "The name".into());
 		map.insert("playtime".into(), playtime.into());
+		map.insert("recentPlaytime".into(), 95.into());
 		
 		return map;
 	}
@@ -468,6 +534,7 @@ mod tests
 			total: 21101,
 			windows: 100,
 		});
+		assert_eq!(game.recentPlaytime, Some(95));
 		
 		let firstId = "First achievement".to_string();
 		assert!(game.achievements.iter().any(|t| t.id == firstId));
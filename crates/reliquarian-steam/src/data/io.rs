@@ -1,7 +1,7 @@
 use std::io::ErrorKind;
 use anyhow::{Result, anyhow};
-use data::io::{getDataDir, getSettingsDir, readDataFromFile,
-	readRawDataFromFile, writeDataToFile};
+use data::io::{getSettingsDir, readDataFromFile, writeDataToFile};
+use data::storage::{loadUser, saveUser};
 use tracing::warn;
 use crate::data::settings::SteamSettings;
 use crate::data::user::SteamUser;
@@ -27,42 +27,13 @@ fn loadSettings() -> Result<SteamSettings>
 	};
 }
 
-/**
-Read the Steam API user data from file.
-*/
-fn loadUserData() -> Result<SteamUser>
-{
-	return match getDataDir(false)
-	{
-		None => Err(anyhow!(ErrorKind::NotFound)),
-		Some(dir) => readDataFromFile(dir, SteamUser::FileName.into()),
-	};
-}
-
-/**
-Read the Steam API user data from file.
-*/
-fn loadUserData_lossy() -> Result<SteamUser>
-{
-	return match getDataDir(false)
-	{
-		None => Err(anyhow!(ErrorKind::NotFound)),
-		Some(dir) => {
-			let json = readRawDataFromFile(dir, SteamUser::FileName.into())?;
-			SteamUser::parseJsonLossy(json)
-		},
-	};
-}
-
 pub fn loadUserData_Steam() -> SteamUser
 {
-	return match loadUserData()
+	return match loadUser()
 	{
 		Err(e) => {
 			warn!("Failed loading Steam user data: {:?}", e);
-			warn!("Attempting Steam user data lossy load");
-			loadUserData_lossy()
-				.unwrap_or_default()
+			SteamUser::default()
 		},
 		Ok(user) => user,
 	};
@@ -81,13 +52,9 @@ pub fn saveSettings(settings: &SteamSettings) -> Result<()>
 }
 
 /**
-Write the Steam user data to file.
+Write the Steam user data to storage.
 */
 pub fn saveUserData(user: &SteamUser) -> Result<()>
 {
-	return match getDataDir(true)
-	{
-		None => Err(anyhow!(ErrorKind::NotFound)),
-		Some(dir) => writeDataToFile(dir, SteamUser::FileName.into(), user),
-	};
+	return saveUser(user);
 }
@@ -5,4 +5,5 @@ pub mod operation;
 pub mod playtime;
 pub mod result;
 pub mod settings;
+pub mod stats;
 pub mod user;
@@ -3,6 +3,8 @@ use anyhow::{Error, anyhow};
 use data::enums::GamePlatforms;
 use net::{DataOperation, DataRequest};
 use strum_macros::{AsRefStr, EnumString};
+use super::settings::SteamSettings;
+use super::user::SteamUser;
 
 #[derive(AsRefStr, Clone, Copy, Debug, Eq, EnumString, PartialEq, PartialOrd, Ord)]
 pub enum SteamOperation
@@ -12,6 +14,7 @@ pub enum SteamOperation
 	GetGameImage(u64, bool),
 	GetPlayerAchievements(u64),
 	GetPlayerSummary,
+	GetRecentlyPlayedGames,
 	GetSchemaForGame(u64),
 	GetSharedLibraryApps,
 	SaveToFile,
@@ -26,6 +29,7 @@ impl From<SteamOperation> for DataOperation
 		{
 			SteamOperation::GetGameList
 				| SteamOperation::GetPlayerSummary
+				| SteamOperation::GetRecentlyPlayedGames
 				| SteamOperation::GetSharedLibraryApps
 			=> DataOperation::Platform(
 				GamePlatforms::Steam,
@@ -67,6 +71,66 @@ impl From<SteamOperation> for DataRequest
 	}
 }
 
+impl SteamOperation
+{
+	/**
+	The operations which refresh the user's Steam data.
+	
+	A quick refresh only retrieves the recently played games, which queue the
+	operations needed to refresh each of them in turn. A full refresh retrieves
+	the entire library and refreshes the achievements of every game which has
+	already been loaded, ordered by the time they were last played so the most
+	relevant games are refreshed first.
+	*/
+	pub fn refreshRequests(settings: &SteamSettings, user: &SteamUser) -> Vec<DataRequest>
+	{
+		let mut requests = vec![Self::GetPlayerSummary.into()];
+		
+		if settings.quickRefresh
+		{
+			requests.push(Self::GetRecentlyPlayedGames.into());
+		}
+		else
+		{
+			requests.push(match settings.enableSteamFamilyLibrary
+			{
+				false => Self::GetGameList.into(),
+				true => Self::GetSharedLibraryApps.into(),
+			});
+			
+			let mut games = user.games.iter()
+				.filter(|g| g.loaded)
+				.collect::<Vec<_>>();
+			
+			games.sort_by(|a, b| b.lastPlayed.cmp(&a.lastPlayed));
+			
+			for game in games
+			{
+				requests.push(Self::GetPlayerAchievements(game.id).into());
+				requests.push(Self::GetGlobalPercentages(game.id).into());
+			}
+		}
+		
+		return requests;
+	}
+	
+	/**
+	The operations which retrieve the achievement names and descriptions of
+	every loaded game again, such as after the language has changed.
+	*/
+	pub fn schemaRequests(user: &SteamUser) -> Vec<DataRequest>
+	{
+		let mut requests = user.games.iter()
+			.filter(|g| g.loaded)
+			.map(|g| Self::GetSchemaForGame(g.id).into())
+			.collect::<Vec<_>>();
+		
+		requests.push(Self::SaveToFile.into());
+		
+		return requests;
+	}
+}
+
 impl TryFrom<DataOperation> for SteamOperation
 {
 	type Error = Error;
@@ -82,6 +146,7 @@ impl TryFrom<DataOperation> for SteamOperation
 					{
 						Self::GetGameList => Ok(Self::GetGameList),
 						Self::GetPlayerSummary => Ok(Self::GetPlayerSummary),
+						Self::GetRecentlyPlayedGames => Ok(Self::GetRecentlyPlayedGames),
 						Self::GetSharedLibraryApps => Ok(Self::GetSharedLibraryApps),
 						_ => Err(anyhow!("Invalid Steam operation")),
 					},
@@ -126,3 +191,51 @@ impl TryFrom<DataOperation> for SteamOperation
 		}
 	}
 }
+
+#[cfg(test)]
+mod tests
+{
+	use super::*;
+	use crate::data::game::Game;
+	
+	fn operations(requests: Vec<DataRequest>) -> Vec<SteamOperation>
+	{
+		return requests.into_iter()
+			.filter_map(|r| r.operation.try_into().ok())
+			.collect();
+	}
+	
+	#[test]
+	fn refreshRequests()
+	{
+		let mut user = SteamUser::default();
+		user.games.push(Game { id: 1, lastPlayed: 100, loaded: true, ..Default::default() });
+		user.games.push(Game { id: 2, lastPlayed: 300, loaded: true, ..Default::default() });
+		user.games.push(Game { id: 3, lastPlayed: 500, ..Default::default() });
+		
+		let mut settings = SteamSettings::default();
+		let full = operations(SteamOperation::refreshRequests(&settings, &user));
+		assert_eq!(full, vec![
+			SteamOperation::GetPlayerSummary,
+			SteamOperation::GetGameList,
+			SteamOperation::GetPlayerAchievements(2),
+			SteamOperation::GetGlobalPercentages(2),
+			SteamOperation::GetPlayerAchievements(1),
+			SteamOperation::GetGlobalPercentages(1),
+		]);
+		
+		let schemas = operations(SteamOperation::schemaRequests(&user));
+		assert_eq!(schemas, vec![
+			SteamOperation::GetSchemaForGame(1),
+			SteamOperation::GetSchemaForGame(2),
+			SteamOperation::SaveToFile,
+		]);
+		
+		settings.quickRefresh = true;
+		let quick = operations(SteamOperation::refreshRequests(&settings, &user));
+		assert_eq!(quick, vec![
+			SteamOperation::GetPlayerSummary,
+			SteamOperation::GetRecentlyPlayedGames,
+		]);
+	}
+}
@@ -1,8 +1,8 @@
 use serde::{Deserialize, Serialize};
 use serde_json::{Map, Value};
-use crate::api::{AppInfo, GameInfo};
+use crate::api::{AppInfo, GameInfo, RecentlyPlayed};
 
-#[derive(Clone, Copy, Debug, Default, Deserialize, Eq, PartialEq, PartialOrd, Ord, Serialize)]
+#[derive(Clone, Copy, Debug, Default, Deserialize, Eq, Hash, PartialEq, PartialOrd, Ord, Serialize)]
 pub struct Playtime
 {
 	#[serde(default)]
@@ -23,6 +23,15 @@ pub struct Playtime
 
 impl Playtime
 {
+	pub fn add(&mut self, other: &Self)
+	{
+		self.linux += other.linux;
+		self.mac += other.mac;
+		self.offline += other.offline;
+		self.total += other.total;
+		self.windows += other.windows;
+	}
+	
 	pub fn parseJsonMap(map: &Map<String, Value>) -> Self
 	{
 		let mut playtime = Self::default();
@@ -103,6 +112,15 @@ impl Playtime
 	{
 		self.total = info.rt_playtime;
 	}
+	
+	/// Recently played games do not report the time played while offline.
+	pub fn updateRecent(&mut self, info: &RecentlyPlayed)
+	{
+		self.linux = info.playtime_linux_forever;
+		self.mac = info.playtime_mac_forever;
+		self.total = info.playtime_forever;
+		self.windows = info.playtime_windows_forever;
+	}
 }
 
 #[cfg(test)]
@@ -4,6 +4,8 @@ use super::user::SteamUser;
 #[derive(Clone, Debug)]
 pub struct SteamOperationResult
 {
+	/// Whether the operation failed to retrieve the data it was meant to refresh.
+	pub failed: bool,
 	pub user: SteamUser,
 	pub requests: Vec<DataRequest>,
 }
@@ -14,8 +16,23 @@ impl From<SteamUser> for SteamOperationResult
 	{
 		return Self
 		{
+			failed: false,
 			user: value,
 			requests: Default::default(),
 		};
 	}
 }
+
+impl SteamOperationResult
+{
+	/// A result which leaves the user unchanged and marks the operation as failed.
+	pub fn failure(user: SteamUser) -> Self
+	{
+		return Self
+		{
+			failed: true,
+			user,
+			requests: Default::default(),
+		};
+	}
+}
@@ -1,4 +1,5 @@
 use data::enums::GamePlatforms;
+use data::migration::Versioned;
 use freya::radio::RadioChannel;
 use serde::{Deserialize, Serialize};
 
@@ -7,10 +8,16 @@ pub struct SteamSettings
 {
 	#[serde(default)]
 	pub enableSteamFamilyLibrary: bool,
+	
+	/// Only refresh the achievements of games played in the last two weeks.
+	#[serde(default)]
+	pub quickRefresh: bool,
 }
 
 impl RadioChannel<SteamSettings> for GamePlatforms {}
 
+impl Versioned for SteamSettings {}
+
 impl SteamSettings
 {
 	/// The filename to be used when this struct is read from, or written to, the file system.
@@ -0,0 +1,186 @@
+use std::cmp::Reverse;
+use data::format::truncateF32;
+use super::game::Game;
+use super::playtime::Playtime;
+
+/**
+Playtime and achievement totals across the user's Steam library.
+*/
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
+pub struct PlaytimeStats
+{
+	/// The number of achievements unlocked across every loaded game.
+	pub achievementsUnlocked: usize,
+	
+	/// The number of games with any recorded playtime.
+	pub gamesPlayed: usize,
+	
+	pub playtime: Playtime,
+	
+	/// The games with the most playtime, ordered descending.
+	pub topGames: Vec<GamePlaytime>,
+}
+
+/// The playtime of a single game, as displayed in the list of most played games.
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
+pub struct GamePlaytime
+{
+	pub id: u64,
+	pub name: String,
+	pub total: u64,
+}
+
+impl PlaytimeStats
+{
+	pub fn new(games: &[Game], topCount: usize) -> Self
+	{
+		let mut stats = Self::default();
+		
+		for game in games
+		{
+			if game.playtime.total > 0
+			{
+				stats.gamesPlayed += 1;
+			}
+			
+			stats.achievementsUnlocked += game.achievements.iter()
+				.filter(|a| a.unlocked())
+				.count();
+			
+			stats.playtime.add(&game.playtime);
+		}
+		
+		let mut played = games.iter()
+			.filter(|g| g.playtime.total > 0)
+			.collect::<Vec<_>>();
+		
+		played.sort_by_key(|g| Reverse(g.playtime.total));
+		
+		stats.topGames = played.into_iter()
+			.take(topCount)
+			.map(|g| GamePlaytime
+			{
+				id: g.id,
+				name: g.name.clone(),
+				total: g.playtime.total,
+			})
+			.collect();
+		
+		return stats;
+	}
+	
+	pub fn hoursPerAchievement(&self) -> Option<f32>
+	{
+		return match self.achievementsUnlocked
+		{
+			0 => None,
+			count => Some(truncateF32(hours(self.playtime.total) / count as f32, 2)),
+		};
+	}
+	
+	/// The percentage of the total playtime represented by `minutes`.
+	pub fn share(&self, minutes: u64) -> f32
+	{
+		return match self.playtime.total
+		{
+			0 => 0.0,
+			total => truncateF32(minutes as f32 / total as f32 * 100.0, 2),
+		};
+	}
+	
+	/**
+	Playtime which is not attributed to any operating system.
+	
+	Games shared via Steam Family only report their total playtime.
+	*/
+	pub fn unknownPlatform(&self) -> u64
+	{
+		return self.playtime.total.saturating_sub(
+			self.playtime.linux + self.playtime.mac + self.playtime.windows
+		);
+	}
+}
+
+/// Convert the minutes reported by the Steam Web API into hours.
+pub fn hours(minutes: u64) -> f32
+{
+	return truncateF32(minutes as f32 / 60.0, 1);
+}
+
+#[cfg(test)]
+mod tests
+{
+	use super::*;
+	use crate::data::achievement::SteamAchievement;
+	
+	fn game(id: u64, linux: u64, windows: u64, total: u64, unlocked: usize) -> Game
+	{
+		let mut achievements = vec![];
+		for i in 0..unlocked
+		{
+			achievements.push(SteamAchievement
+			{
+				id: i.to_string(),
+				timestamp: Some(1728611918000),
+				..Default::default()
+			});
+		}
+		
+		achievements.push(SteamAchievement
+		{
+			id: "locked".into(),
+			..Default::default()
+		});
+		
+		return Game
+		{
+			achievements,
+			id,
+			name: id.to_string(),
+			playtime: Playtime
+			{
+				linux,
+				total,
+				windows,
+				..Default::default()
+			},
+			..Default::default()
+		};
+	}
+	
+	#[test]
+	fn new()
+	{
+		let games = vec![
+			game(1, 600, 0, 600, 3),
+			game(2, 0, 0, 0, 0),
+			game(3, 120, 240, 360, 1),
+			// Shared library games only report their total
+			game(4, 0, 0, 240, 0),
+		];
+		
+		let stats = PlaytimeStats::new(&games, 2);
+		assert_eq!(stats.achievementsUnlocked, 4);
+		assert_eq!(stats.gamesPlayed, 3);
+		assert_eq!(stats.playtime.linux, 720);
+		assert_eq!(stats.playtime.windows, 240);
+		assert_eq!(stats.playtime.total, 1200);
+		assert_eq!(stats.unknownPlatform(), 240);
+		assert_eq!(stats.share(stats.playtime.linux), 60.0);
+		assert_eq!(stats.hoursPerAchievement(), Some(5.0));
+		
+		let top = stats.topGames.iter()
+			.map(|g| g.id)
+			.collect::<Vec<_>>();
+		assert_eq!(top, vec![1, 3]);
+	}
+	
+	#[test]
+	fn empty()
+	{
+		let stats = PlaytimeStats::new(&[], 10);
+		assert_eq!(stats.hoursPerAchievement(), None);
+		assert_eq!(stats.share(0), 0.0);
+		assert!(stats.topGames.is_empty());
+	}
+}
@@ -1,10 +1,15 @@
+use std::cmp::Ordering;
 use anyhow::Result;
 use data::enums::GamePlatforms;
 use data::filter::{FilterCriteria, Filterable};
+use data::migration::{MigrationRegistry, Versioned};
+use data::storage::Storable;
+use data::unified::{Unifiable, UnifiedGame};
 use freya::radio::RadioChannel;
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
-use crate::api::{Payload_GetOwnedGames, Payload_GetSharedLibraryApps};
+use crate::api::{Payload_GetOwnedGames, Payload_GetRecentlyPlayedGames,
+	Payload_GetSharedLibraryApps};
 use super::achievement::SteamAchievement;
 use super::game::Game;
 
@@ -65,6 +70,39 @@ impl Filterable<Game> for SteamUser
 
 impl RadioChannel<SteamUser> for GamePlatforms {}
 
+impl Storable for SteamUser
+{
+	const DataFileName: &'static str = SteamUser::FileName;
+	const Platform: GamePlatforms = GamePlatforms::Steam;
+	
+	fn parseLossy(json: String) -> Result<Self>
+	{
+		return SteamUser::parseJsonLossy(json);
+	}
+}
+
+impl Unifiable<Vec<UnifiedGame>> for SteamUser
+{
+	fn unify(&self) -> Vec<UnifiedGame>
+	{
+		return self.games.iter()
+			.map(|g| g.unify())
+			.collect();
+	}
+}
+
+impl Versioned for SteamUser
+{
+	const Version: u32 = 3;
+	
+	fn migrations() -> MigrationRegistry
+	{
+		return MigrationRegistry::new()
+			.register(1, migrateVersion1)
+			.register(2, migrateVersion2);
+	}
+}
+
 impl SteamUser
 {
 	pub const FileName: &str = "steam.json";
@@ -168,6 +206,27 @@ impl SteamUser
 		};
 	}
 	
+	/**
+	The unlocked achievements with the lowest global unlock percentage across
+	every game, paired with the app id of their game and ordered rarest first.
+	*/
+	pub fn rarestUnlocks(&self, count: usize) -> Vec<(u64, SteamAchievement)>
+	{
+		let mut unlocks = self.games.iter()
+			.flat_map(|g| g.achievements.iter()
+				.filter(|a| a.unlocked())
+				.filter_map(|a| a.globalPercentage.map(|p| (p, g.id, a)))
+			)
+			.collect::<Vec<_>>();
+		
+		unlocks.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap_or(Ordering::Equal));
+		
+		return unlocks.into_iter()
+			.take(count)
+			.map(|(_, gameId, achievement)| (gameId, achievement.clone()))
+			.collect();
+	}
+	
 	pub fn processOwnedGames(&mut self, payload: Payload_GetOwnedGames)
 	{
 		for game in payload.response.games
@@ -181,6 +240,37 @@ impl SteamUser
 		}
 	}
 	
+	/**
+	Update the playtime of each game played in the last two weeks, adding any
+	which are not yet in the library.
+	
+	Returns the app ids of the recently played games, ordered as they were
+	returned by the API.
+	*/
+	pub fn processRecentlyPlayedGames(&mut self, payload: Payload_GetRecentlyPlayedGames) -> Vec<u64>
+	{
+		// Games which are no longer in the list have not been played recently
+		for game in self.games.iter_mut()
+		{
+			game.recentPlaytime = None;
+		}
+		
+		let mut ids = vec![];
+		for recent in payload.response.games
+		{
+			match self.games.iter_mut()
+				.find(|g| g.id == recent.appid)
+			{
+				None => self.games.push(recent.clone().into()),
+				Some(g) => g.updateRecentlyPlayed(&recent),
+			}
+			
+			ids.push(recent.appid);
+		}
+		
+		return ids;
+	}
+	
 	pub fn processSharedGames(&mut self, payload: Payload_GetSharedLibraryApps)
 	{
 		for app in payload.response.apps.iter()
@@ -203,11 +293,281 @@ impl SteamUser
 	}
 }
 
+/**
+Any achievement timestamp below this value is assumed to be in seconds, rather
+than milliseconds. In milliseconds it would be a date in early 1973.
+*/
+const SecondsThreshold: u64 = 100_000_000_000;
+
+/**
+Version 1 stored the unlock time reported by the API, in seconds, when an
+achievement's state was first retrieved and in milliseconds whenever it was
+updated afterwards. Locked achievements could also be given a timestamp of `0`,
+incorrectly marking them as unlocked.
+
+Convert every timestamp to milliseconds and clear the zeroes.
+*/
+fn migrateVersion1(mut value: Value) -> Result<Value>
+{
+	if let Some(Value::Array(games)) = value.get_mut("games")
+	{
+		for game in games
+		{
+			if let Some(Value::Array(achievements)) = game.get_mut("achievements")
+			{
+				for achievement in achievements
+				{
+					if let Some(timestamp) = achievement.get_mut("timestamp")
+					{
+						*timestamp = match timestamp.as_u64()
+						{
+							Some(0) => Value::Null,
+							Some(seconds) if seconds < SecondsThreshold => Value::from(seconds * 1000),
+							_ => continue,
+						};
+					}
+				}
+			}
+		}
+	}
+	
+	return Ok(value);
+}
+
+/**
+Version 2 stored each achievement's global percentage as the string reported by
+the API.
+
+Parse every percentage into a number, clearing any which are not valid.
+*/
+fn migrateVersion2(mut value: Value) -> Result<Value>
+{
+	if let Some(Value::Array(games)) = value.get_mut("games")
+	{
+		for game in games
+		{
+			if let Some(Value::Array(achievements)) = game.get_mut("achievements")
+			{
+				for achievement in achievements
+				{
+					if let Some(percent) = achievement.get_mut("globalPercentage")
+					{
+						*percent = match percent
+						{
+							Value::Number(_) => continue,
+							Value::String(inner) => SteamAchievement::parseGlobalPercentage(inner)
+								.map(Value::from)
+								.unwrap_or_default(),
+							_ => Value::Null,
+						};
+					}
+				}
+			}
+		}
+	}
+	
+	return Ok(value);
+}
+
 #[cfg(test)]
 mod tests
 {
+	use data::migration::Envelope;
 	use super::*;
 	
+	/// Written before versioning was introduced, so it has no envelope.
+	const Version1Json: &str = r#"{
+	"games": [
+		{
+			"id": 73,
+			"name": "First game",
+			"achievements": [
+				{ "id": "seconds", "globalPercentage": "3.2000000476837158", "timestamp": 1728611918 },
+				{ "id": "milliseconds", "globalPercentage": "64.5", "timestamp": 1728611918000 },
+				{ "id": "locked", "globalPercentage": "Not a number", "timestamp": 0 },
+				{ "id": "never", "timestamp": null }
+			]
+		}
+	],
+	"id": "The id",
+	"name": "The name"
+}"#;
+	
+	const Version2Json: &str = r#"{
+	"data": {
+		"games": [
+			{
+				"id": 73,
+				"name": "First game",
+				"achievements": [
+					{ "id": "seconds", "globalPercentage": "3.2000000476837158", "timestamp": 1728611918000 },
+					{ "id": "milliseconds", "globalPercentage": "64.5", "timestamp": 1728611918000 },
+					{ "id": "locked", "globalPercentage": null, "timestamp": null },
+					{ "id": "never", "timestamp": null }
+				]
+			}
+		],
+		"id": "The id",
+		"name": "The name"
+	},
+	"version": 2
+}"#;
+	
+	const Version3Json: &str = r#"{
+	"data": {
+		"games": [
+			{
+				"id": 73,
+				"name": "First game",
+				"achievements": [
+					{ "id": "seconds", "globalPercentage": 3.2, "timestamp": 1728611918000 },
+					{ "id": "milliseconds", "globalPercentage": 64.5, "timestamp": 1728611918000 },
+					{ "id": "locked", "globalPercentage": null, "timestamp": null },
+					{ "id": "never", "timestamp": null }
+				]
+			}
+		],
+		"id": "The id",
+		"name": "The name"
+	},
+	"version": 3
+}"#;
+	
+	fn upgrade(json: &str) -> SteamUser
+	{
+		let value = serde_json::from_str::<Value>(json).unwrap();
+		let data = Envelope::fromValue(value)
+			.upgrade::<SteamUser>()
+			.unwrap();
+		
+		return serde_json::from_value(data).unwrap();
+	}
+	
+	#[test]
+	fn migrations()
+	{
+		let expected = vec![
+			("seconds".to_string(), Some(3.2), Some(1728611918000)),
+			("milliseconds".to_string(), Some(64.5), Some(1728611918000)),
+			("locked".to_string(), None, None),
+			("never".to_string(), None, None),
+		];
+		
+		for json in [Version1Json, Version2Json, Version3Json]
+		{
+			let user = upgrade(json);
+			assert_eq!(user.id, "The id".to_string());
+			assert_eq!(user.games.len(), 1);
+			
+			let achievements = user.games[0].achievements.iter()
+				.map(|a| (a.id.clone(), a.globalPercentage, a.timestamp))
+				.collect::<Vec<_>>();
+			
+			assert_eq!(achievements, expected);
+		}
+		
+		// The current version must round trip through the envelope unchanged
+		let user = upgrade(Version3Json);
+		let json = serde_json::to_string(&Envelope::wrap(&user)).unwrap();
+		assert_eq!(upgrade(&json), user);
+	}
+	
+	#[test]
+	fn processRecentlyPlayedGames()
+	{
+		let mut user = SteamUser::default();
+		user.games.push(Game { id: 10, name: "Stale".into(), recentPlaytime: Some(30), ..Default::default() });
+		user.games.push(Game { id: 20, name: "Existing".into(), ..Default::default() });
+		
+		let payload = serde_json::from_str::<Payload_GetRecentlyPlayedGames>(r#"{
+	"response": {
+		"total_count": 2,
+		"games": [
+			{
+				"appid": 30, "name": "New", "playtime_2weeks": 45, "playtime_forever": 600,
+				"img_icon_url": "icon", "playtime_windows_forever": 0,
+				"playtime_mac_forever": 0, "playtime_linux_forever": 600
+			},
+			{
+				"appid": 20, "name": "Existing", "playtime_2weeks": 15, "playtime_forever": 90,
+				"img_icon_url": "", "playtime_windows_forever": 90,
+				"playtime_mac_forever": 0, "playtime_linux_forever": 0
+			}
+		]
+	}
+}"#).unwrap();
+		
+		let ids = user.processRecentlyPlayedGames(payload);
+		assert_eq!(ids, vec![30, 20]);
+		assert_eq!(user.games.len(), 3);
+		
+		assert_eq!(user.getGame(10).unwrap().recentPlaytime, None);
+		
+		let existing = user.getGame(20).unwrap();
+		assert_eq!(existing.recentPlaytime, Some(15));
+		assert_eq!(existing.playtime.total, 90);
+		assert_eq!(existing.playtime.windows, 90);
+		
+		let new = user.getGame(30).unwrap();
+		assert_eq!(new.iconHash, "icon".to_string());
+		assert_eq!(new.playtime.linux, 600);
+		assert_eq!(new.recentPlaytime, Some(45));
+	}
+	
+	#[test]
+	fn processRecentlyPlayedGamesEmpty()
+	{
+		let mut user = SteamUser::default();
+		user.games.push(Game { id: 10, name: "Stale".into(), recentPlaytime: Some(30), ..Default::default() });
+		
+		// Steam omits the game list entirely when nothing has been played recently
+		let payload = serde_json::from_str::<Payload_GetRecentlyPlayedGames>(r#"{"response":{"total_count":0}}"#).unwrap();
+		
+		let ids = user.processRecentlyPlayedGames(payload);
+		assert!(ids.is_empty());
+		assert_eq!(user.getGame(10).unwrap().recentPlaytime, None);
+	}
+	
+	const RarestUnlocksJson: &str = r#"{
+	"games": [
+		{
+			"id": 1,
+			"achievements": [
+				{ "id": "common", "globalPercentage": 80.1, "timestamp": 1728611918000 },
+				{ "id": "locked", "globalPercentage": 0.1 },
+				{ "id": "rare", "globalPercentage": 7.5, "timestamp": 1728611918000 }
+			]
+		},
+		
+		{
+			"id": 2,
+			"achievements": [
+				{ "id": "ultra", "globalPercentage": 1.25, "timestamp": 1728611918000 },
+				{ "id": "unknown", "timestamp": 1728611918000 }
+			]
+		}
+	],
+	"id": "The id",
+	"name": "The name"
+}"#;
+	
+	#[test]
+	fn rarestUnlocks()
+	{
+		let user = SteamUser::parseJsonLossy(RarestUnlocksJson.into()).unwrap();
+		
+		let rarest = user.rarestUnlocks(2).into_iter()
+			.map(|(gameId, a)| (gameId, a.id))
+			.collect::<Vec<_>>();
+		
+		assert_eq!(rarest, vec![
+			(2, "ultra".to_string()),
+			(1, "rare".to_string()),
+		]);
+		
+		assert_eq!(user.rarestUnlocks(10).len(), 3);
+	}
+	
 	const PartialJson: &str = r#"{
 	"games": [
 		{
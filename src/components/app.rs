@@ -47,9 +47,12 @@ use steam::data::settings::SteamSettings;
 use steam::data::user::SteamUser;
 use tracing::{info, warn};
 use crate::components::ProfileState;
+use crate::components::library::AllPlatformsContent;
 use crate::components::nav::NavBar;
 use crate::components::profile::ProfileElement;
 use crate::components::settings::AppSettingsElement;
+use crate::components::sync::useSyncSchedule;
+use crate::components::timeline::TimelineContent;
 
 pub struct LocalAchievementsApp;
 
@@ -126,6 +129,8 @@ impl App for LocalAchievementsApp
 			steamUser.clone(),
 		));
 		
+		useSyncSchedule();
+		
 		return rect()
 			.background(BackgroundColor)
 			.color(TextColor)
@@ -161,6 +166,7 @@ fn getActiveElement(active: ActiveContent) -> Element
 {
 	return match active
 	{
+		ActiveContent::AllPlatforms => AllPlatformsContent::new().into(),
 		ActiveContent::BattleNet => BattleNetContentElement::new().into(),
 		ActiveContent::EpicGamesStore => EgsContentElement::new().into(),
 		ActiveContent::Gog => GogContentElement::new().into(),
@@ -168,6 +174,7 @@ fn getActiveElement(active: ActiveContent) -> Element
 		ActiveContent::Rpcs3 => Rpcs3ContentElement::new().into(),
 		ActiveContent::Settings => AppSettingsElement::new().into(),
 		ActiveContent::Steam => SteamContent::new().into(),
+		ActiveContent::Timeline => TimelineContent::new().into(),
 	};
 }
 
@@ -185,6 +192,11 @@ async fn processBattleNetResult(
 		operation
 	).await
 	{
+		if result.failed
+		{
+			rateLimiter.read().reportFailure(GamePlatforms::BattleNet).await;
+		}
+		
 		**userRadio.write() = result.user.clone();
 		rateLimiter.read().pushAll(result.requests).await;
 	}
@@ -202,6 +214,11 @@ async fn processEgsResult(
 		operation
 	).await
 	{
+		if result.failed
+		{
+			rateLimiter.read().reportFailure(GamePlatforms::EpicGamesStore).await;
+		}
+		
 		**userRadio.write() = result.user.clone();
 		rateLimiter.read().pushAll(result.requests).await;
 	}
@@ -219,6 +236,11 @@ async fn processGogResult(
 		operation
 	).await
 	{
+		if result.failed
+		{
+			rateLimiter.read().reportFailure(GamePlatforms::Gog).await;
+		}
+		
 		**userRadio.write() = result.user.clone();
 		rateLimiter.read().pushAll(result.requests).await;
 	}
@@ -236,6 +258,11 @@ async fn processRetroAchievementsResult(
 		operation
 	).await
 	{
+		if result.failed
+		{
+			rateLimiter.read().reportFailure(GamePlatforms::RetroAchievements).await;
+		}
+		
 		**userRadio.write() = result.user.clone();
 		rateLimiter.read().pushAll(result.requests).await;
 	}
@@ -255,6 +282,11 @@ async fn processSteamResult(
 		language
 	).await
 	{
+		if result.failed
+		{
+			rateLimiter.read().reportFailure(GamePlatforms::Steam).await;
+		}
+		
 		**userRadio.write() = result.user.clone();
 		rateLimiter.read().pushAll(result.requests).await;
 	}
@@ -373,6 +405,12 @@ fn processRateLimiter(
 								_ => {}
 							}
 							
+							DataOperation::PlatformGameIdPage(platform, _, _, _) => match platform
+							{
+								GamePlatforms::Gog => processGogResult(request.operation, gogUser, rateLimiter).await,
+								_ => {}
+							}
+							
 							DataOperation::PlatformGameIdString(platform, _, _) => match platform
 							{
 								GamePlatforms::EpicGamesStore => processEgsResult(request.operation, egsUser, rateLimiter).await,
@@ -382,6 +420,7 @@ fn processRateLimiter(
 							DataOperation::PlatformOptionalInt(platform, _, _) => match platform
 							{
 								GamePlatforms::Gog => processGogResult(request.operation, gogUser, rateLimiter).await,
+								GamePlatforms::RetroAchievements => processRetroAchievementsResult(request.operation, retroAchievementsUser, rateLimiter).await,
 								_ => {}
 							}
 							
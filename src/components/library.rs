@@ -0,0 +1,403 @@
+use std::path::PathBuf;
+use battlenet::data::user::BattleNetUser;
+use components::input::filter::GamesFilter;
+use data::constants::{BorderColor, CornerRadius, GogProgressColor,
+	RetroAchievementsProgressColorBackground,
+	RetroAchievementsProgressColorHardcore, SteamContrast};
+use data::enums::{DataChannel, GamePlatforms};
+use data::filter::FilterCriteria;
+use data::io::{filePathExists, getImagePath};
+use data::settings::AppSettings;
+use data::unified::{Unifiable, UnifiedGame, UnifiedLibrary, UnifiedSort};
+use epicgamesstore::data::user::EgsUser;
+use freya::prelude::{Alignment, Border, BorderAlignment, ChildrenExt, Code,
+	Color, Component, ContainerExt, ContainerSizeExt, ContainerWithContentExt,
+	Content, Direction, Event, EventHandlersExt, FontWeight, Gaps, ImageViewer,
+	IntoElement, KeyboardEventData, MenuItem, ProgressBar,
+	ProgressBarThemePartialExt, ScrollConfig, ScrollPosition, Select, Size,
+	Span, StyleExt, TextAlign, TextStyleExt, VirtualScrollView, WritableUtils,
+	label, paragraph, rect, use_scroll_controller, use_state};
+use freya::radio::use_radio;
+use gog::data::user::GogUser;
+use retroachievements::data::user::RetroAchievementsUser;
+use rpcs3::data::user::Rpcs3User;
+use steam::data::user::SteamUser;
+use strum::IntoEnumIterator;
+
+/**
+Every game from every enabled platform, displayed in a single list.
+*/
+#[derive(Clone, PartialEq)]
+pub struct AllPlatformsContent;
+
+impl Component for AllPlatformsContent
+{
+	fn render(&self) -> impl IntoElement
+	{
+		let appSettings = use_radio::<AppSettings, DataChannel>(DataChannel::Settings);
+		let library = useUnifiedLibrary();
+		
+		let mut scrollController = use_scroll_controller(ScrollConfig::default);
+		let caseSensitive = use_state(bool::default);
+		let mut platform = use_state(|| None::<GamePlatforms>);
+		let search = use_state(String::default);
+		let showAll = use_state(|| appSettings.read().displayGamesWithoutAchievements);
+		let mut sort = use_state(UnifiedSort::default);
+		
+		let games = library.filterAndSort(
+			FilterCriteria
+			{
+				caseSensitive: caseSensitive(),
+				showAll: showAll(),
+				text: search.read().clone(),
+				..Default::default()
+			},
+			platform(),
+			sort()
+		);
+		
+		let gamesLength = games.len();
+		
+		let platforms = GamePlatforms::iter()
+			.filter(|p| library.games.iter().any(|g| &g.platform == p))
+			.collect::<Vec<_>>();
+		
+		return rect()
+			.content(Content::Flex)
+			.cross_align(Alignment::Center)
+			.direction(Direction::Vertical)
+			.margin(Gaps::new(10.0, 0.0, 5.0, 0.0))
+			.spacing(10.0)
+			.width(Size::Fill)
+			
+			.on_global_key_up(move |e: Event<KeyboardEventData>| match e.code
+			{
+				Code::Home => scrollController.scroll_to(ScrollPosition::Start, Direction::Vertical),
+				Code::End => scrollController.scroll_to(ScrollPosition::End, Direction::Vertical),
+				_ => {},
+			})
+			
+			.child(
+				label()
+					.font_size(32.0)
+					.font_weight(FontWeight::BOLD)
+					.text_align(TextAlign::Center)
+					.width(Size::percent(100.0))
+					.text("All Platforms")
+			)
+			
+			.child(
+				GamesFilter::new(caseSensitive, search)
+					.margin(Gaps::new(5.0, 0.0, 0.0, 0.0))
+					.showAll(showAll)
+					.width(Size::percent(50.0))
+			)
+			
+			.child(
+				rect()
+					.cross_align(Alignment::Center)
+					.direction(Direction::Horizontal)
+					.main_align(Alignment::Center)
+					.spacing(10.0)
+					.width(Size::percent(50.0))
+					
+					.child("Platform")
+					
+					.child(
+						Select::new()
+							.selected_item(match platform()
+							{
+								None => "All".to_string(),
+								Some(p) => p.as_ref().to_string(),
+							})
+							
+							.child(
+								MenuItem::new()
+									.selected(platform().is_none())
+									.on_press(move |_| platform.set(None))
+									.child("All")
+							)
+							
+							.children(
+								platforms.into_iter().map(|p| {
+									MenuItem::new()
+										.selected(platform() == Some(p))
+										.on_press(move |_| platform.set(Some(p)))
+										.child(p.as_ref().to_string())
+										.into()
+								})
+							)
+					)
+					
+					.child("Sort By")
+					
+					.child(
+						Select::new()
+							.selected_item(sort().to_string())
+							
+							.children(
+								UnifiedSort::iter().map(|s| {
+									MenuItem::new()
+										.selected(s == sort())
+										.on_press(move |_| sort.set(s))
+										.child(s.to_string())
+										.into()
+								})
+							)
+					)
+			)
+			
+			.child(
+				VirtualScrollView::new_controlled(
+					move |i, _| {
+						let game = &games[i];
+						UnifiedGameNode::new(game.clone()).into()
+					},
+					scrollController
+				)
+					.direction(Direction::Vertical)
+					.height(Size::flex(1.0))
+					.item_size(105.0)
+					.length(gamesLength)
+					.scroll_with_arrows(true)
+			);
+	}
+}
+
+impl AllPlatformsContent
+{
+	pub fn new() -> Self
+	{
+		return Self {};
+	}
+}
+
+#[derive(Clone, PartialEq)]
+struct UnifiedGameNode
+{
+	game: UnifiedGame,
+}
+
+impl Component for UnifiedGameNode
+{
+	fn render(&self) -> impl IntoElement
+	{
+		let game = self.game.clone();
+		
+		let iconPath = match &game.icon
+		{
+			None => None,
+			Some(location) => getImagePath(location),
+		};
+		
+		let progress = game.percentUnlocked();
+		let progressColor = platformColor(game.platform);
+		
+		let lastUnlocked = match game.formatLastUnlocked()
+		{
+			Err(_) => "Never".to_string(),
+			Ok(dt) => dt,
+		};
+		
+		let showIcon = filePathExists(&iconPath);
+		
+		return rect()
+			.direction(Direction::Horizontal)
+			.main_align(Alignment::SpaceAround)
+			.margin(Gaps::new_symmetric(5.0, 0.0))
+			.min_height(Size::px(54.0))
+			.width(Size::Fill)
+			
+			.child(
+				rect()
+					.border(Some(
+						Border::new()
+							.alignment(BorderAlignment::Center)
+							.fill(BorderColor)
+							.width(1.0)
+					))
+					.content(Content::Flex)
+					.corner_radius(CornerRadius)
+					.direction(Direction::Horizontal)
+					.min_width(Size::px(540.0))
+					.padding(Gaps::new_symmetric(10.0, 15.0))
+					.spacing(10.0)
+					.width(Size::percent(50.0))
+					
+					.maybe_child(showIcon.then(||
+						ImageViewer::new(PathBuf::from(iconPath.unwrap_or_default()))
+							.corner_radius(CornerRadius)
+							.height(Size::px(64.0))
+					))
+					
+					.child(
+						rect()
+							.direction(Direction::Vertical)
+							.height(Size::px(64.0))
+							.main_align(Alignment::Center)
+							.spacing(5.0)
+							.width(Size::flex(1.0))
+							
+							.child(
+								label()
+									.font_size(18.0)
+									.text(game.name.clone())
+							)
+							
+							.child(
+								rect()
+									.cross_align(Alignment::Center)
+									.direction(Direction::Horizontal)
+									.spacing(5.0)
+									
+									.child(platformBadge(game.platform))
+									
+									.maybe_child(game.system.clone().map(|system|
+										label()
+											.color(Color::GREY)
+											.font_size(10.0)
+											.text(system)
+									))
+							)
+					)
+					
+					.child(
+						rect()
+							.cross_align(Alignment::End)
+							.direction(Direction::Vertical)
+							.height(Size::px(64.0))
+							.main_align(Alignment::Center)
+							.spacing(5.0)
+							.width(Size::px(150.0))
+							
+							.child(
+								ProgressBar::new(progress)
+									.background(RetroAchievementsProgressColorBackground)
+									.height(8.0)
+									.progress_background(progressColor)
+									.color(progressColor)
+							)
+							
+							.child(
+								paragraph()
+									.text_align(TextAlign::Center)
+									.width(Size::percent(100.0))
+									
+									.span(
+										Span::new(format!("{} / {} ", game.achievementsUnlocked, game.achievementsTotal))
+											.font_size(10.0)
+									)
+									
+									.span(
+										Span::new(format!("({:.2}%) ", progress))
+											.font_size(10.0)
+											.color(Color::GREY)
+									)
+							)
+							
+							.child(
+								label()
+									.color(Color::GREY)
+									.font_size(10.0)
+									.text_align(TextAlign::Center)
+									.width(Size::percent(100.0))
+									.text(lastUnlocked)
+							)
+					)
+			);
+	}
+}
+
+impl UnifiedGameNode
+{
+	pub fn new(game: UnifiedGame) -> Self
+	{
+		return Self
+		{
+			game,
+		};
+	}
+}
+
+/**
+A small outlined label identifying the platform from which an item originated.
+*/
+pub fn platformBadge(platform: GamePlatforms) -> impl IntoElement
+{
+	return rect()
+		.border(Some(
+			Border::new()
+				.alignment(BorderAlignment::Center)
+				.fill(platformColor(platform))
+				.width(1.0)
+		))
+		.corner_radius(CornerRadius)
+		.padding(Gaps::new_symmetric(2.0, 5.0))
+		.child(
+			label()
+				.font_size(10.0)
+				.text(platform.as_ref().to_string())
+		);
+}
+
+pub fn platformColor(platform: GamePlatforms) -> Color
+{
+	return match platform
+	{
+		GamePlatforms::Gog => GogProgressColor,
+		GamePlatforms::Steam => SteamContrast,
+		_ => RetroAchievementsProgressColorHardcore,
+	};
+}
+
+/**
+Collect the games from every enabled platform into a single `UnifiedLibrary`.
+
+This reads each platform's user radio, so it must be called unconditionally
+from within a component's `render`, the same as any other hook.
+*/
+pub fn useUnifiedLibrary() -> UnifiedLibrary
+{
+	let appSettings = use_radio::<AppSettings, DataChannel>(DataChannel::Settings);
+	let bnetUser = use_radio::<BattleNetUser, GamePlatforms>(GamePlatforms::BattleNet);
+	let egsUser = use_radio::<EgsUser, GamePlatforms>(GamePlatforms::EpicGamesStore);
+	let gogUser = use_radio::<GogUser, GamePlatforms>(GamePlatforms::Gog);
+	let retroAchievementsUser = use_radio::<RetroAchievementsUser, GamePlatforms>(GamePlatforms::RetroAchievements);
+	let rpcs3User = use_radio::<Rpcs3User, GamePlatforms>(GamePlatforms::Rpcs3);
+	let steamUser = use_radio::<SteamUser, GamePlatforms>(GamePlatforms::Steam);
+	
+	let enabled = appSettings.read().enabledPlatforms;
+	let mut library = UnifiedLibrary::new();
+	
+	if enabled.battleNet
+	{
+		library.extend(bnetUser.read().unify());
+	}
+	
+	if enabled.epicGamesStores
+	{
+		library.extend(egsUser.read().unify());
+	}
+	
+	if enabled.gog
+	{
+		library.extend(gogUser.read().unify());
+	}
+	
+	if enabled.retroAchievements
+	{
+		library.extend(retroAchievementsUser.read().unify());
+	}
+	
+	if enabled.rpcs3
+	{
+		library.extend(rpcs3User.read().unify());
+	}
+	
+	if enabled.steam
+	{
+		library.extend(steamUser.read().unify());
+	}
+	
+	return library;
+}
@@ -1,8 +1,11 @@
 mod about;
 mod app;
+mod library;
 mod nav;
 mod profile;
 mod settings;
+mod sync;
+mod timeline;
 
 pub use app::LocalAchievementsApp;
 pub use profile::ProfileState;
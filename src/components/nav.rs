@@ -176,6 +176,28 @@ pub fn NavBar() -> impl IntoElement
 						})
 				)
 				
+				.child(
+					IconButton::new(lucide::library_big())
+						.alt("All Platforms")
+						.color(TextColor)
+						.height(Size::px(48.0))
+						.innerHeight(Size::px(32.0))
+						.innerWidth(Size::px(32.0))
+						.width(Size::px(48.0))
+						.onPress(move |_| selected.set(ActiveContent::AllPlatforms))
+				)
+				
+				.child(
+					IconButton::new(lucide::history())
+						.alt("Timeline")
+						.color(TextColor)
+						.height(Size::px(48.0))
+						.innerHeight(Size::px(32.0))
+						.innerWidth(Size::px(32.0))
+						.width(Size::px(48.0))
+						.onPress(move |_| selected.set(ActiveContent::Timeline))
+				)
+				
 				.maybe_child(bnet)
 				.maybe_child(egs)
 				.maybe_child(gog)
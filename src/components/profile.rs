@@ -1,10 +1,12 @@
 use battlenet::components::profile::BattleNetUserProfile;
-use data::constants::{BorderColor, ButtonBackgroundColor};
+use chrono::{DateTime, Local, Utc};
+use data::constants::{BorderColor, ButtonBackgroundColor,
+	Format_ChronoDateTime};
 use data::enums::DataChannel;
 use data::settings::AppSettings;
 use epicgamesstore::components::profile::EgsUserProfile;
 use freya::animation::{AnimNum, Ease, Function, use_animation};
-use freya::prelude::{Border, BorderAlignment, BorderWidth, ChildrenExt,
+use freya::prelude::{Border, BorderAlignment, BorderWidth, ChildrenExt, Color,
 	Component, ContainerExt, ContainerSizeExt, ContainerWithContentExt,
 	Direction, Gaps, IntoElement, LayerExt, Position, ScrollView, Size,
 	StyleExt, TextAlign, TextStyleExt, label, rect, use_side_effect};
@@ -64,6 +66,7 @@ impl Component for ProfileElement
 					
 					.child(profileLabelElement("Battle.Net"))
 					.child(BattleNetUserProfile::new())
+					.child(lastSyncElement(appSettings.read().sync.battleNet.lastSync))
 			)
 		};
 		
@@ -78,6 +81,7 @@ impl Component for ProfileElement
 					
 					.child(profileLabelElement("Epic Games Store"))
 					.child(EgsUserProfile::new())
+					.child(lastSyncElement(appSettings.read().sync.epicGamesStore.lastSync))
 			)
 		};
 		
@@ -92,6 +96,7 @@ impl Component for ProfileElement
 					
 					.child(profileLabelElement("GOG"))
 					.child(GogUserProfile::new())
+					.child(lastSyncElement(appSettings.read().sync.gog.lastSync))
 			)
 		};
 		
@@ -106,6 +111,7 @@ impl Component for ProfileElement
 					
 					.child(profileLabelElement("Retro Achievements"))
 					.child(RetroAchievementsUserProfile::new())
+					.child(lastSyncElement(appSettings.read().sync.retroAchievements.lastSync))
 			)
 		};
 		
@@ -120,6 +126,7 @@ impl Component for ProfileElement
 					
 					.child(profileLabelElement("RPCS3"))
 					.child(Rpcs3ProfileElement::new())
+					.child(lastSyncElement(appSettings.read().sync.rpcs3.lastSync))
 			)
 		};
 		
@@ -134,6 +141,7 @@ impl Component for ProfileElement
 					
 					.child(profileLabelElement("Steam"))
 					.child(SteamProfile::new())
+					.child(lastSyncElement(appSettings.read().sync.steam.lastSync))
 			)
 		};
 		
@@ -238,3 +246,23 @@ fn profileLabelElement(text: impl Into<String>) -> impl IntoElement
 		.width(Size::Fill)
 		.text(text.into());
 }
+
+fn lastSyncElement(lastSync: Option<DateTime<Utc>>) -> impl IntoElement
+{
+	let text = match lastSync
+	{
+		None => "Never synced".to_string(),
+		Some(timestamp) => format!(
+			"Last synced: {}",
+			timestamp.with_timezone(&Local).format(Format_ChronoDateTime)
+		),
+	};
+	
+	return label()
+		.color(Color::GREY)
+		.font_size(10.0)
+		.margin(Gaps::new(5.0, 0.0, 0.0, 0.0))
+		.text_align(TextAlign::Center)
+		.width(Size::Fill)
+		.text(text);
+}
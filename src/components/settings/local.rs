@@ -1,10 +1,38 @@
 use std::borrow::Cow;
+use battlenet::api::BattleNetSettings;
+use battlenet::data::io::{loadSettings_BattleNet, loadUserData_BattleNet};
+use battlenet::data::user::BattleNetUser;
+use chrono::Local;
+use components::input::number::NumericInput;
 use components::settings::util::separatorElement;
-use data::io::{getCacheDir, getConfigDir, getDataDir};
-use freya::prelude::{Alignment, ChildrenExt, Component, ContainerExt,
-	ContainerSizeExt, ContainerWithContentExt, Content, Direction, Gaps, Input,
-	InputValidator, IntoElement, Size, TextAlign, TextStyleExt, Writable, label,
-	rect, use_state};
+use data::constants::Format_ChronoDateTime;
+use data::enums::{DataChannel, GamePlatforms};
+use data::io::{ArchiveDirectories, ArchiveExtension, DataBackup,
+	defaultArchiveName, exportArchive, getCacheDir, getConfigDir, getDataDir,
+	importArchive, listBackups, loadAppSettings, restoreBackup, saveAppSettings};
+use data::settings::AppSettings;
+use data::storage::reimportUser;
+use epicgamesstore::data::io::loadUserData_EpicGamesStore;
+use epicgamesstore::data::user::EgsUser;
+use freya::prelude::{Alignment, Button, ChildrenExt, Component, ContainerExt,
+	ContainerSizeExt, ContainerWithContentExt, Content, Direction, Element, Gaps,
+	Input, InputValidator, IntoElement, Size, TextAlign, TextStyleExt, Writable,
+	WritableUtils, label, rect, spawn, use_side_effect, use_state};
+use freya::radio::{Radio, use_radio};
+use gog::data::io::loadUserData_Gog;
+use gog::data::user::GogUser;
+use retroachievements::data::io::{loadSettings_RetroAchievements,
+	loadUserData_RetroAchievements};
+use retroachievements::data::settings::RetroAchievementsSettings;
+use retroachievements::data::user::RetroAchievementsUser;
+use rfd::AsyncFileDialog;
+use rpcs3::data::io::{loadSettings_Rpcs3, loadUserData_Rpcs3};
+use rpcs3::data::settings::Rpcs3Settings;
+use rpcs3::data::user::Rpcs3User;
+use steam::data::io::{loadSettings_Steam, loadUserData_Steam};
+use steam::data::settings::SteamSettings;
+use steam::data::user::SteamUser;
+use tracing::{info, warn};
 
 #[derive(Clone, Default, PartialEq)]
 pub struct LocalInfo
@@ -40,6 +68,144 @@ impl Component for LocalInfo
 			Some(dir) => dir
 		});
 		
+		let mut appSettings = use_radio::<AppSettings, DataChannel>(DataChannel::Settings);
+		let backupCount = use_state(|| appSettings.read().backupCount);
+		let mut backups = use_state(|| listBackups(dataDir.read().clone()));
+		
+		let mut archiveStatus = use_state(String::default);
+		
+		let radios = PlatformRadios
+		{
+			app: appSettings.clone(),
+			battleNet: use_radio::<BattleNetUser, GamePlatforms>(GamePlatforms::BattleNet),
+			battleNetSettings: use_radio::<BattleNetSettings, GamePlatforms>(GamePlatforms::BattleNet),
+			egs: use_radio::<EgsUser, GamePlatforms>(GamePlatforms::EpicGamesStore),
+			gog: use_radio::<GogUser, GamePlatforms>(GamePlatforms::Gog),
+			retroAchievements: use_radio::<RetroAchievementsUser, GamePlatforms>(GamePlatforms::RetroAchievements),
+			retroAchievementsSettings: use_radio::<RetroAchievementsSettings, GamePlatforms>(GamePlatforms::RetroAchievements),
+			rpcs3: use_radio::<Rpcs3User, GamePlatforms>(GamePlatforms::Rpcs3),
+			rpcs3Settings: use_radio::<Rpcs3Settings, GamePlatforms>(GamePlatforms::Rpcs3),
+			steam: use_radio::<SteamUser, GamePlatforms>(GamePlatforms::Steam),
+			steamSettings: use_radio::<SteamSettings, GamePlatforms>(GamePlatforms::Steam),
+		};
+		
+		use_side_effect(move || {
+			appSettings.write().backupCount = backupCount();
+			
+			spawn(async move {
+				match saveAppSettings(&appSettings.read())
+				{
+					Err(e) => warn!("[Reliquarian] Error saving app settings: {:?}", e),
+					Ok(_) => info!("[Reliquarian] Saved app settings"),
+				}
+			});
+		});
+		
+		let backupRows = backups.read()
+			.iter()
+			.map(|backup| {
+				let backup = backup.clone();
+				let mut radios = radios.clone();
+				
+				backupRow(
+					labelWidth.clone(),
+					&backup,
+					move || {
+						let directory = dataDir.read().clone();
+						match restoreBackup(directory.clone(), &backup, backupCount() as usize)
+						{
+							Err(e) => warn!("[Reliquarian] Error restoring backup {}: {:?}", backup.path.to_string_lossy(), e),
+							Ok(_) => {
+								info!("[Reliquarian] Restored backup {}", backup.path.to_string_lossy());
+								reimportRestoredUser(&backup.fileName);
+								radios.reloadUsers();
+							},
+						}
+						
+						backups.set(listBackups(directory));
+					}
+				).into()
+			})
+			.collect::<Vec<Element>>();
+		
+		let onExport = move |_| {
+			spawn(async move {
+				let destination = match AsyncFileDialog::new()
+					.add_filter("Reliquarian Archive", &[ArchiveExtension])
+					.set_file_name(defaultArchiveName())
+					.save_file()
+					.await
+				{
+					None => return,
+					Some(handle) => handle.path().to_path_buf(),
+				};
+				
+				let result = ArchiveDirectories::local()
+					.and_then(|directories| exportArchive(&destination, &directories));
+				
+				match result
+				{
+					Err(e) => {
+						warn!("[Reliquarian] Error exporting archive: {:?}", e);
+						archiveStatus.set(format!("Export failed: {}", e));
+					},
+					
+					Ok(manifest) => {
+						info!("[Reliquarian] Exported {} files to {}", manifest.files, destination.to_string_lossy());
+						archiveStatus.set(format!("Exported {} files to {}", manifest.files, destination.to_string_lossy()));
+					},
+				}
+			});
+		};
+		
+		let importRadios = radios.clone();
+		let onImport = move |_| {
+			let mut radios = importRadios.clone();
+			spawn(async move {
+				let source = match AsyncFileDialog::new()
+					.add_filter("Reliquarian Archive", &[ArchiveExtension])
+					.pick_file()
+					.await
+				{
+					None => return,
+					Some(handle) => handle.path().to_path_buf(),
+				};
+				
+				let result = ArchiveDirectories::local()
+					.and_then(|directories| importArchive(&source, &directories));
+				
+				match result
+				{
+					Err(e) => {
+						warn!("[Reliquarian] Error importing archive: {:?}", e);
+						archiveStatus.set(format!("Import failed: {}", e));
+					},
+					
+					Ok(manifest) => {
+						info!("[Reliquarian] Imported {} files from {}", manifest.files, source.to_string_lossy());
+						radios.reloadSettings();
+						radios.reloadUsers();
+						backups.set(listBackups(dataDir.read().clone()));
+						archiveStatus.set(format!("Imported {} files. Platform credentials are not included and will need to be entered again.", manifest.files));
+					},
+				}
+			});
+		};
+		
+		let status = (!archiveStatus.read().is_empty()).then(||
+			label()
+				.text_align(TextAlign::Center)
+				.width(Size::Fill)
+				.text(archiveStatus.read().clone())
+		);
+		
+		let noBackups = backupRows.is_empty().then(||
+			label()
+				.text_align(TextAlign::Center)
+				.width(Size::Fill)
+				.text("No backups have been made yet.")
+		);
+		
 		return rect()
 			.cross_align(Alignment::Center)
 			.direction(Direction::Vertical)
@@ -59,7 +225,77 @@ impl Component for LocalInfo
 			
 			.child(directoryDisplay("Cache", labelWidth.clone(), cacheDir))
 			.child(directoryDisplay("Configuration", labelWidth.clone(), configDir))
-			.child(directoryDisplay("Data", labelWidth.clone(), dataDir));
+			.child(directoryDisplay("Data", labelWidth.clone(), dataDir))
+			
+			.child(separatorElement())
+			
+			.child(
+				label()
+					.margin(Gaps::new(0.0, 0.0, 5.0, 0.0))
+					.text_align(TextAlign::Center)
+					.width(Size::Fill)
+					.text("Backups")
+			)
+			
+			.child(
+				rect()
+					.content(Content::Flex)
+					.direction(Direction::Horizontal)
+					.main_align(Alignment::Center)
+					.spacing(10.0)
+					.width(Size::percent(75.0))
+					
+					.child(
+						label()
+							.margin(Gaps::new(7.0, 0.0, 0.0, 0.0))
+							.min_width(Size::px(102.0))
+							.text_align(TextAlign::End)
+							.width(labelWidth.clone())
+							.text("Backups to Keep")
+					)
+					
+					.child(
+						NumericInput::new(backupCount)
+							.max(100u64)
+							.placeholder("5")
+							.width(Size::flex(1.0))
+					)
+			)
+			
+			.children(backupRows)
+			.maybe_child(noBackups)
+			
+			.child(separatorElement())
+			
+			.child(
+				label()
+					.margin(Gaps::new(0.0, 0.0, 5.0, 0.0))
+					.text_align(TextAlign::Center)
+					.width(Size::Fill)
+					.text("Transfer")
+			)
+			
+			.child(
+				rect()
+					.direction(Direction::Horizontal)
+					.main_align(Alignment::Center)
+					.spacing(10.0)
+					.width(Size::Fill)
+					
+					.child(
+						Button::new()
+							.child("Export Everything")
+							.on_press(onExport)
+					)
+					
+					.child(
+						Button::new()
+							.child("Import Archive")
+							.on_press(onImport)
+					)
+			)
+			
+			.maybe_child(status);
 	}
 }
 
@@ -78,6 +314,114 @@ impl LocalInfo
 	}
 }
 
+/**
+The radios of the application's settings and every platform's settings and
+user data, so that they can be reloaded from storage after their files are
+replaced.
+*/
+#[derive(Clone)]
+struct PlatformRadios
+{
+	app: Radio<AppSettings, DataChannel>,
+	battleNet: Radio<BattleNetUser, GamePlatforms>,
+	battleNetSettings: Radio<BattleNetSettings, GamePlatforms>,
+	egs: Radio<EgsUser, GamePlatforms>,
+	gog: Radio<GogUser, GamePlatforms>,
+	retroAchievements: Radio<RetroAchievementsUser, GamePlatforms>,
+	retroAchievementsSettings: Radio<RetroAchievementsSettings, GamePlatforms>,
+	rpcs3: Radio<Rpcs3User, GamePlatforms>,
+	rpcs3Settings: Radio<Rpcs3Settings, GamePlatforms>,
+	steam: Radio<SteamUser, GamePlatforms>,
+	steamSettings: Radio<SteamSettings, GamePlatforms>,
+}
+
+impl PlatformRadios
+{
+	fn reloadSettings(&mut self)
+	{
+		**self.app.write() = loadAppSettings().unwrap_or_default();
+		**self.battleNetSettings.write() = loadSettings_BattleNet();
+		**self.retroAchievementsSettings.write() = loadSettings_RetroAchievements();
+		**self.rpcs3Settings.write() = loadSettings_Rpcs3();
+		**self.steamSettings.write() = loadSettings_Steam();
+	}
+	
+	fn reloadUsers(&mut self)
+	{
+		**self.battleNet.write() = loadUserData_BattleNet();
+		**self.egs.write() = loadUserData_EpicGamesStore();
+		**self.gog.write() = loadUserData_Gog();
+		**self.retroAchievements.write() = loadUserData_RetroAchievements();
+		**self.rpcs3.write() = loadUserData_Rpcs3();
+		**self.steam.write() = loadUserData_Steam();
+	}
+}
+
+/**
+Import a restored user data file into the storage backend selected in the
+application's settings, so that reloading the users reads the restored data.
+*/
+fn reimportRestoredUser(fileName: &str)
+{
+	let results = [
+		reimportUser::<BattleNetUser>(fileName),
+		reimportUser::<EgsUser>(fileName),
+		reimportUser::<GogUser>(fileName),
+		reimportUser::<RetroAchievementsUser>(fileName),
+		reimportUser::<Rpcs3User>(fileName),
+		reimportUser::<SteamUser>(fileName),
+	];
+	
+	for result in results
+	{
+		match result
+		{
+			Err(e) => warn!("[Reliquarian] Error importing restored file {}: {:?}", fileName, e),
+			Ok(true) => info!("[Reliquarian] Imported restored file {}", fileName),
+			Ok(false) => {},
+		}
+	}
+}
+
+fn backupRow(
+	labelWidth: impl Into<Size>,
+	backup: &DataBackup,
+	mut onRestore: impl FnMut() + 'static,
+) -> impl IntoElement
+{
+	let timestamp = backup.timestamp.with_timezone(&Local)
+		.format(Format_ChronoDateTime)
+		.to_string();
+	
+	return rect()
+		.content(Content::Flex)
+		.cross_align(Alignment::Center)
+		.direction(Direction::Horizontal)
+		.main_align(Alignment::Center)
+		.spacing(10.0)
+		.width(Size::percent(75.0))
+		
+		.child(
+			label()
+				.min_width(Size::px(102.0))
+				.text_align(TextAlign::End)
+				.width(labelWidth)
+				.text(backup.fileName.clone())
+		)
+		
+		.child(
+			label()
+				.width(Size::flex(1.0))
+				.text(timestamp)
+		)
+		
+		.child(
+			Button::new()
+				.child("Restore")
+				.on_press(move |_| onRestore())
+		);
+}
+
 fn directoryDisplay(
 	labelText: impl Into<Cow<'static, str>>,
 	labelWidth: impl Into<Size>,
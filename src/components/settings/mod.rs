@@ -1,6 +1,8 @@
 mod local;
 mod notifications;
+mod rarity;
 mod settings;
+mod sync;
 mod toggles;
 mod ui;
 
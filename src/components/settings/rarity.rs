@@ -0,0 +1,144 @@
+use components::input::number::NumericInput;
+use data::enums::{DataChannel, RarityTier};
+use data::io::saveAppSettings;
+use data::settings::{AppSettings, RarityThresholds};
+use freya::prelude::{Alignment, ChildrenExt, Component, ContainerExt,
+	ContainerSizeExt, ContainerWithContentExt, Content, Direction, FontWeight,
+	Gaps, IntoElement, Size, State, TextAlign, TextStyleExt, label, rect, spawn,
+	use_side_effect, use_state};
+use freya::radio::use_radio;
+use tracing::{info, warn};
+
+#[derive(Clone, PartialEq)]
+pub struct RaritySettings
+{
+	labelWidth: Size,
+}
+
+impl Component for RaritySettings
+{
+	fn render(&self) -> impl IntoElement
+	{
+		let mut appSettings = use_radio::<AppSettings, DataChannel>(DataChannel::Settings);
+		
+		let rare = use_state(|| appSettings.read().rarity.rare);
+		let ultraRare = use_state(|| appSettings.read().rarity.ultraRare);
+		let uncommon = use_state(|| appSettings.read().rarity.uncommon);
+		
+		use_side_effect(move || {
+			let thresholds = RarityThresholds
+			{
+				rare: rare(),
+				ultraRare: ultraRare(),
+				uncommon: uncommon(),
+			};
+			
+			// Keep the last valid thresholds until every tier covers some percentages again
+			if thresholds.isValid() && appSettings.read().rarity != thresholds
+			{
+				appSettings.write().rarity = thresholds;
+				
+				spawn(async move {
+					match saveAppSettings(&appSettings.read())
+					{
+						Err(e) => warn!("[Reliquarian] Error saving app settings: {:?}", e),
+						Ok(_) => info!("[Reliquarian] Saved app settings"),
+					}
+				});
+			}
+		});
+		
+		let valid = RarityThresholds
+		{
+			rare: rare(),
+			ultraRare: ultraRare(),
+			uncommon: uncommon(),
+		}.isValid();
+		
+		let invalid = (!valid).then(||
+			label()
+				.font_size(12.0)
+				.text_align(TextAlign::Center)
+				.width(Size::percent(75.0))
+				.text("Each threshold must be lower than the one above it. Changes will not be saved until they are.")
+		);
+		
+		let labelWidth = self.labelWidth.clone();
+		
+		return rect()
+			.cross_align(Alignment::Center)
+			.direction(Direction::Vertical)
+			.margin(Gaps::new_all(10.0))
+			.spacing(5.0)
+			.width(Size::Fill)
+			
+			.child(
+				label()
+					.font_weight(FontWeight::BOLD)
+					.margin(Gaps::new(0.0, 0.0, 5.0, 0.0))
+					.text_align(TextAlign::Center)
+					.width(Size::Fill)
+					.text("Achievement Rarity")
+			)
+			
+			.child(
+				label()
+					.font_size(12.0)
+					.text_align(TextAlign::Center)
+					.width(Size::percent(75.0))
+					.text("Achievements unlocked by fewer than this percentage of players belong to each tier.")
+			)
+			
+			.child(thresholdElement(RarityTier::Uncommon, uncommon, labelWidth.clone()))
+			.child(thresholdElement(RarityTier::Rare, rare, labelWidth.clone()))
+			.child(thresholdElement(RarityTier::UltraRare, ultraRare, labelWidth))
+			.maybe_child(invalid);
+	}
+}
+
+impl RaritySettings
+{
+	pub fn new() -> Self
+	{
+		return Self
+		{
+			labelWidth: Size::percent(20.0),
+		};
+	}
+	
+	#[allow(unused)]
+	pub fn labelWidth(mut self, width: impl Into<Size>) -> Self
+	{
+		self.labelWidth = width.into();
+		return self;
+	}
+}
+
+fn thresholdElement(tier: RarityTier, value: State<f32>, labelWidth: Size) -> impl IntoElement
+{
+	return rect()
+		.content(Content::Flex)
+		.cross_align(Alignment::Center)
+		.direction(Direction::Horizontal)
+		.main_align(Alignment::Center)
+		.spacing(10.0)
+		.width(Size::percent(75.0))
+		
+		.child(
+			label()
+				.color(tier.color())
+				.min_width(Size::px(102.0))
+				.text_align(TextAlign::End)
+				.width(labelWidth)
+				.text(tier.to_string())
+		)
+		
+		.child(
+			NumericInput::new(value)
+				.max(100f32)
+				.min(0f32)
+				.width(Size::flex(1.0))
+		)
+		
+		.child("%");
+}
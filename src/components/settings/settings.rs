@@ -11,6 +11,8 @@ use rpcs3::components::settings::Rpcs3SettingsElement;
 use steam::components::settings::SteamSettingsElement;
 use crate::components::settings::local::LocalInfo;
 use crate::components::settings::notifications::NotificationSettings;
+use crate::components::settings::rarity::RaritySettings;
+use crate::components::settings::sync::SyncSettings;
 use crate::components::settings::ui::UiSettings;
 
 #[derive(Clone, Default, PartialEq)]
@@ -70,6 +72,8 @@ impl Component for AppSettingsElement
 					.spacing(15.0)
 					.child(UiSettings::new())
 					.child(NotificationSettings::new())
+					.child(RaritySettings::new())
+					.child(SyncSettings::new())
 					.maybe_child(battleNet)
 					.maybe_child(egs)
 					.maybe_child(gog)
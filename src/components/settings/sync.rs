@@ -0,0 +1,165 @@
+use components::input::number::NumericInput;
+use data::enums::{DataChannel, GamePlatforms};
+use data::io::saveAppSettings;
+use data::settings::AppSettings;
+use freya::prelude::{Alignment, ChildrenExt, Component, ContainerExt,
+	ContainerSizeExt, ContainerWithContentExt, Content, Direction, FontWeight,
+	Gaps, IntoElement, Size, Switch, TextAlign, TextStyleExt, WritableUtils,
+	label, rect, spawn, use_side_effect, use_state};
+use freya::radio::use_radio;
+use strum::IntoEnumIterator;
+use tracing::{info, warn};
+
+#[derive(Clone, PartialEq)]
+pub struct SyncSettings
+{
+	labelWidth: Size,
+}
+
+impl Component for SyncSettings
+{
+	fn render(&self) -> impl IntoElement
+	{
+		let appSettings = use_radio::<AppSettings, DataChannel>(DataChannel::Settings);
+		
+		let enabled = appSettings.read().enabledPlatforms;
+		let labelWidth = self.labelWidth.clone();
+		
+		return rect()
+			.cross_align(Alignment::Center)
+			.direction(Direction::Vertical)
+			.margin(Gaps::new_all(10.0))
+			.spacing(5.0)
+			.width(Size::Fill)
+			
+			.child(
+				label()
+					.font_weight(FontWeight::BOLD)
+					.margin(Gaps::new(0.0, 0.0, 5.0, 0.0))
+					.text_align(TextAlign::Center)
+					.width(Size::Fill)
+					.text("Automatic Sync")
+			)
+			
+			.child(
+				label()
+					.font_size(12.0)
+					.text_align(TextAlign::Center)
+					.width(Size::percent(75.0))
+					.text("Refresh each platform every number of hours, or 0 to only refresh manually.")
+			)
+			
+			.children(
+				GamePlatforms::iter()
+					.filter(|platform| enabled.isEnabled((*platform).into()))
+					.map(|platform| PlatformSyncSettings::new(platform, labelWidth.clone()).into())
+			);
+	}
+}
+
+impl SyncSettings
+{
+	pub fn new() -> Self
+	{
+		return Self
+		{
+			labelWidth: Size::percent(20.0),
+		};
+	}
+	
+	#[allow(unused)]
+	pub fn labelWidth(mut self, width: impl Into<Size>) -> Self
+	{
+		self.labelWidth = width.into();
+		return self;
+	}
+}
+
+#[derive(Clone, PartialEq)]
+struct PlatformSyncSettings
+{
+	labelWidth: Size,
+	platform: GamePlatforms,
+}
+
+impl Component for PlatformSyncSettings
+{
+	fn render(&self) -> impl IntoElement
+	{
+		let mut appSettings = use_radio::<AppSettings, DataChannel>(DataChannel::Settings);
+		
+		let platform = self.platform;
+		let intervalHours = use_state(|| appSettings.read().sync.get(platform).intervalHours);
+		let mut onStartup = use_state(|| appSettings.read().sync.get(platform).onStartup);
+		
+		use_side_effect(move || {
+			let current = *appSettings.read().sync.get(platform);
+			
+			if current.intervalHours != intervalHours() || current.onStartup != onStartup()
+			{
+				appSettings.write().sync.getMut(platform).intervalHours = intervalHours();
+				appSettings.write().sync.getMut(platform).onStartup = onStartup();
+				
+				spawn(async move {
+					match saveAppSettings(&appSettings.read())
+					{
+						Err(e) => warn!("[Reliquarian] Error saving app settings: {:?}", e),
+						Ok(_) => info!("[Reliquarian] Saved app settings"),
+					}
+				});
+			}
+		});
+		
+		return rect()
+			.content(Content::Flex)
+			.cross_align(Alignment::Center)
+			.direction(Direction::Horizontal)
+			.main_align(Alignment::Center)
+			.spacing(10.0)
+			.width(Size::percent(75.0))
+			
+			.child(
+				label()
+					.min_width(Size::px(102.0))
+					.text_align(TextAlign::End)
+					.width(self.labelWidth.clone())
+					.text(platform.as_ref())
+			)
+			
+			.child(
+				NumericInput::new(intervalHours)
+					.max(720u64)
+					.placeholder("0")
+					.width(Size::flex(1.0))
+			)
+			
+			.child("Hours")
+			
+			.child(
+				rect()
+					.cross_align(Alignment::Center)
+					.direction(Direction::Horizontal)
+					.spacing(10.0)
+					
+					.child("On Startup")
+					
+					.child(
+						Switch::new()
+							.toggled(onStartup())
+							.on_toggle(move |_| onStartup.set(!onStartup()))
+					)
+			);
+	}
+}
+
+impl PlatformSyncSettings
+{
+	fn new(platform: GamePlatforms, labelWidth: Size) -> Self
+	{
+		return Self
+		{
+			labelWidth,
+			platform,
+		};
+	}
+}
@@ -1,11 +1,15 @@
-use data::enums::{ActiveContent, DataChannel};
+use data::enums::{ActiveContent, DataChannel, GamePlatforms};
 use data::io::saveAppSettings;
 use data::settings::{AppSettings, Language};
+use data::storage::StorageBackend;
 use freya::prelude::{Alignment, ChildrenExt, Component, ContainerExt,
 	ContainerSizeExt, ContainerWithContentExt, Content, Direction, FontWeight,
 	Gaps, IntoElement, MenuItem, Select, Size, Switch, TextAlign, TextStyleExt,
 	WritableUtils, label, rect, spawn, use_side_effect, use_state};
 use freya::radio::use_radio;
+use net::{RateLimiter, RequestEvent};
+use steam::data::operation::SteamOperation;
+use steam::data::user::SteamUser;
 use strum::IntoEnumIterator;
 use tracing::{info, warn};
 use super::toggles::PlatformToggles;
@@ -21,6 +25,9 @@ impl Component for UiSettings
 	fn render(&self) -> impl IntoElement
 	{
 		let mut appSettings = use_radio::<AppSettings, DataChannel>(DataChannel::Settings);
+		let rateLimiter = use_radio::<RateLimiter, DataChannel>(DataChannel::RateLimiter);
+		let mut requestEvent = use_radio::<RequestEvent, DataChannel>(DataChannel::RateLimiter);
+		let steamUser = use_radio::<SteamUser, GamePlatforms>(GamePlatforms::Steam);
 		
 		let mut defaultActiveContent = use_state(|| appSettings.read().defaultActivePlatform);
 		let mut displayGamesWithoutAchievements = use_state(|| appSettings.read().displayGamesWithoutAchievements);
@@ -31,8 +38,11 @@ impl Component for UiSettings
 		let enabledRpcs3 = use_state(|| appSettings.read().enabledPlatforms.rpcs3);
 		let enabledSteam = use_state(|| appSettings.read().enabledPlatforms.steam);
 		let mut language = use_state(|| appSettings.read().language.clone());
+		let mut storageBackend = use_state(|| appSettings.read().storageBackend);
 		
 		use_side_effect(move || {
+			let languageChanged = appSettings.read().language != language();
+			
 			appSettings.write().defaultActivePlatform = defaultActiveContent.read().clone();
 			appSettings.write().displayGamesWithoutAchievements = displayGamesWithoutAchievements();
 			appSettings.write().enabledPlatforms.battleNet = enabledBNet();
@@ -42,6 +52,17 @@ impl Component for UiSettings
 			appSettings.write().enabledPlatforms.rpcs3 = enabledRpcs3();
 			appSettings.write().enabledPlatforms.steam = enabledSteam();
 			appSettings.write().language = language.read().clone();
+			appSettings.write().storageBackend = storageBackend();
+			
+			// Achievement names and descriptions are stored in the language in which they were retrieved
+			if languageChanged && appSettings.read().enabledPlatforms.steam
+			{
+				spawn(async move {
+					let requests = SteamOperation::schemaRequests(&steamUser.read());
+					rateLimiter.read().pushAll(requests).await;
+					**requestEvent.write() = RequestEvent::Added;
+				});
+			}
 			
 			spawn(async move {
 				match saveAppSettings(&appSettings.read())
@@ -52,6 +73,34 @@ impl Component for UiSettings
 			});
 		});
 		
+		let storage = StorageBackend::Sqlite.isAvailable().then(||
+			rect()
+				.cross_align(Alignment::Center)
+				.direction(Direction::Horizontal)
+				.main_align(Alignment::Center)
+				.spacing(10.0)
+				.width(Size::flex(1.0))
+				
+				.child("Storage")
+				
+				.child(
+					Select::new()
+						.selected_item(storageBackend().to_string())
+						
+						.children(
+							StorageBackend::iter()
+								.filter(|backend| backend.isAvailable())
+								.map(|backend| {
+									MenuItem::new()
+										.selected(backend == storageBackend())
+										.on_press(move |_| storageBackend.set(backend))
+										.child(backend.to_string())
+										.into()
+								})
+						)
+				)
+		);
+		
 		return rect()
 			.cross_align(Alignment::Center)
 			.direction(Direction::Vertical)
@@ -161,6 +210,8 @@ impl Component for UiSettings
 									})
 							)
 					)
+					
+					.maybe_child(storage)
 			)
 			
 			.child(PlatformToggles::new(
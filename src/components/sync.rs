@@ -0,0 +1,158 @@
+use std::time::Duration;
+use chrono::Utc;
+use data::enums::{DataChannel, GamePlatforms};
+use data::io::saveAppSettings;
+use data::settings::AppSettings;
+use freya::prelude::{spawn, use_hook, use_side_effect, use_state};
+use freya::radio::use_radio;
+use net::{DataOperation, DataRequest, RateLimiter, RequestEvent};
+use retroachievements::data::settings::RetroAchievementsSettings;
+use rpcs3::components::refresh::refreshUserData;
+use rpcs3::data::settings::Rpcs3Settings;
+use rpcs3::data::user::Rpcs3User;
+use steam::data::settings::SteamSettings;
+use steam::data::user::SteamUser;
+use tokio::time::sleep;
+use tracing::{info, warn};
+use crate::refresh::refreshRequests;
+
+/// How often to check whether any platform is due for an automatic refresh.
+const CheckInterval: Duration = Duration::from_secs(60);
+
+/**
+Automatically refresh each enabled platform according to its schedule in
+`AppSettings::sync`.
+
+Scheduled refreshes are only queued while the rate limiter is idle. A
+platform's last sync time is recorded once every request queued for it has
+been processed, unless any of those requests failed. A platform which failed
+is tried again at its next check.
+
+This reads several radios, so it must be called unconditionally from within a
+component's `render`, after the radio stations have been initialized.
+*/
+pub fn useSyncSchedule()
+{
+	let mut appSettings = use_radio::<AppSettings, DataChannel>(DataChannel::Settings);
+	let rateLimiter = use_radio::<RateLimiter, DataChannel>(DataChannel::RateLimiter);
+	let retroAchievementsSettings = use_radio::<RetroAchievementsSettings, GamePlatforms>(GamePlatforms::RetroAchievements);
+	let mut requestEvent = use_radio::<RequestEvent, DataChannel>(DataChannel::RateLimiter);
+	let mut rpcs3User = use_radio::<Rpcs3User, GamePlatforms>(GamePlatforms::Rpcs3);
+	let rpcs3Settings = use_radio::<Rpcs3Settings, GamePlatforms>(GamePlatforms::Rpcs3);
+	let steamSettings = use_radio::<SteamSettings, GamePlatforms>(GamePlatforms::Steam);
+	let steamUser = use_radio::<SteamUser, GamePlatforms>(GamePlatforms::Steam);
+	
+	let mut pending = use_state(Vec::<GamePlatforms>::new);
+	
+	use_hook(|| {
+		spawn(async move {
+			let mut startup = true;
+			
+			loop
+			{
+				if *requestEvent.read() == RequestEvent::Done && pending.read().is_empty()
+				{
+					let due = appSettings.read().sync.due(
+						Utc::now(),
+						startup,
+						&appSettings.read().enabledPlatforms
+					);
+					
+					startup = false;
+					
+					// Discard failures from refreshes which were not scheduled
+					_ = rateLimiter.read().takeFailures().await;
+					
+					let mut queued = vec![];
+					let mut requests = vec![];
+					for platform in due
+					{
+						info!("[Sync] Starting scheduled refresh of {}", platform.as_ref());
+						
+						match platform
+						{
+							// RPCS3 data is read from disk, so the refresh is complete as soon as it returns
+							GamePlatforms::Rpcs3 => {
+								let user = rpcs3User.read().clone();
+								let settings = rpcs3Settings.read().clone();
+								let result = refreshUserData(user, settings).await;
+								**rpcs3User.write() = result.user;
+								
+								match result.failed
+								{
+									false => {
+										appSettings.write().sync.markSynced(platform, Utc::now());
+										saveSyncTimes(&appSettings.read());
+									},
+									
+									true => warn!("[Sync] Scheduled refresh of {} failed", platform.as_ref()),
+								}
+							},
+							
+							_ => {
+								requests.append(&mut refreshRequests(
+									platform,
+									&*retroAchievementsSettings.read(),
+									&*steamUser.read(),
+									&*steamSettings.read()
+								));
+								requests.push(DataRequest
+								{
+									operation: DataOperation::PlatformSaveToFile(platform),
+									..Default::default()
+								});
+								
+								queued.push(platform);
+							},
+						}
+					}
+					
+					if !requests.is_empty()
+					{
+						rateLimiter.read().pushAll(requests).await;
+						**requestEvent.write() = RequestEvent::Added;
+						
+						// Only track the platforms after the event has changed, so the queue is not mistaken for being drained
+						pending.write().append(&mut queued);
+					}
+				}
+				
+				sleep(CheckInterval).await;
+			}
+		});
+	});
+	
+	use_side_effect(move || {
+		let synced = pending.read().clone();
+		
+		if *requestEvent.read() == RequestEvent::Done && !synced.is_empty()
+		{
+			pending.write().clear();
+			
+			spawn(async move {
+				let failures = rateLimiter.read().takeFailures().await;
+				
+				let now = Utc::now();
+				for platform in synced
+				{
+					match failures.contains(&platform)
+					{
+						false => appSettings.write().sync.markSynced(platform, now),
+						true => warn!("[Sync] Scheduled refresh of {} failed", platform.as_ref()),
+					}
+				}
+				
+				saveSyncTimes(&appSettings.read());
+			});
+		}
+	});
+}
+
+fn saveSyncTimes(appSettings: &AppSettings)
+{
+	match saveAppSettings(appSettings)
+	{
+		Err(e) => warn!("[Sync] Error saving app settings: {:?}", e),
+		Ok(_) => info!("[Sync] Saved sync times"),
+	}
+}
@@ -0,0 +1,239 @@
+use std::path::PathBuf;
+use chrono::{Duration, Local, Utc};
+use data::constants::{BorderColor, CornerRadius};
+use data::io::{filePathExists, getImagePath};
+use data::unified::{TimelineEntry, UnlockTimeline};
+use freya::prelude::{Alignment, Border, BorderAlignment, Button, ChildrenExt,
+	Color, Component, ContainerExt, ContainerSizeExt, ContainerWithContentExt,
+	Content, Direction, FontWeight, Gaps, ImageViewer, IntoElement, MenuItem,
+	ScrollView, Select, Size, StyleExt, TextAlign, TextStyleExt, WritableUtils,
+	label, rect, use_state};
+use strum::IntoEnumIterator;
+use strum_macros::{Display, EnumIter};
+use super::library::{platformBadge, useUnifiedLibrary};
+
+const PageSize: usize = 50;
+
+/**
+How far back in time the unlock timeline should reach.
+*/
+#[derive(Clone, Copy, Debug, Default, Display, EnumIter, Eq, PartialEq)]
+enum TimelineRange
+{
+	#[default]
+	#[strum(to_string="All Time")]
+	AllTime,
+	#[strum(to_string="Last 7 Days")]
+	LastWeek,
+	#[strum(to_string="Last 30 Days")]
+	LastMonth,
+}
+
+/**
+Every unlocked achievement from every enabled platform, most recent first and
+grouped by the day on which it was unlocked.
+*/
+#[derive(Clone, PartialEq)]
+pub struct TimelineContent;
+
+impl Component for TimelineContent
+{
+	fn render(&self) -> impl IntoElement
+	{
+		let library = useUnifiedLibrary();
+		
+		let mut page = use_state(usize::default);
+		let mut range = use_state(TimelineRange::default);
+		
+		let timeline = UnlockTimeline::new(&library.games);
+		let timeline = match range()
+		{
+			TimelineRange::AllTime => timeline,
+			TimelineRange::LastMonth => timeline.since(Utc::now() - Duration::days(30)),
+			TimelineRange::LastWeek => timeline.since(Utc::now() - Duration::days(7)),
+		};
+		
+		let pageCount = timeline.pageCount(PageSize);
+		let currentPage = page().min(pageCount.saturating_sub(1));
+		let days = UnlockTimeline::groupByDay(
+			timeline.page(currentPage, PageSize),
+			&Local
+		);
+		
+		let empty = timeline.is_empty().then(||
+			label()
+				.text_align(TextAlign::Center)
+				.width(Size::percent(100.0))
+				.text("No unlocks found")
+		);
+		
+		return rect()
+			.content(Content::Flex)
+			.cross_align(Alignment::Center)
+			.direction(Direction::Vertical)
+			.margin(Gaps::new(10.0, 0.0, 5.0, 0.0))
+			.spacing(10.0)
+			.width(Size::Fill)
+			
+			.child(
+				label()
+					.font_size(32.0)
+					.font_weight(FontWeight::BOLD)
+					.text_align(TextAlign::Center)
+					.width(Size::percent(100.0))
+					.text("Timeline")
+			)
+			
+			.child(
+				rect()
+					.cross_align(Alignment::Center)
+					.direction(Direction::Horizontal)
+					.main_align(Alignment::Center)
+					.spacing(10.0)
+					.width(Size::percent(50.0))
+					
+					.child(
+						Button::new()
+							.child("Previous")
+							.on_press(move |_| page.set(currentPage.saturating_sub(1)))
+					)
+					
+					.child(
+						label()
+							.text(format!("Page {} of {}", currentPage + 1, pageCount.max(1)))
+					)
+					
+					.child(
+						Button::new()
+							.child("Next")
+							.on_press(move |_| page.set((currentPage + 1).min(pageCount.saturating_sub(1))))
+					)
+					
+					.child(
+						Select::new()
+							.selected_item(range().to_string())
+							
+							.children(
+								TimelineRange::iter().map(|r| {
+									MenuItem::new()
+										.selected(r == range())
+										.on_press(move |_| {
+											range.set(r);
+											page.set(0);
+										})
+										.child(r.to_string())
+										.into()
+								})
+							)
+					)
+			)
+			
+			.maybe_child(empty)
+			
+			.child(
+				ScrollView::new()
+					.height(Size::flex(1.0))
+					.spacing(10.0)
+					
+					.children(days.into_iter().map(|day| {
+						rect()
+							.cross_align(Alignment::Center)
+							.direction(Direction::Vertical)
+							.spacing(5.0)
+							.width(Size::Fill)
+							
+							.child(
+								label()
+									.font_size(20.0)
+									.font_weight(FontWeight::BOLD)
+									.width(Size::percent(50.0))
+									.text(day.date.format("%A, %B %d, %Y").to_string())
+							)
+							
+							.children(day.entries.into_iter().map(|entry| timelineNode(entry).into()))
+							.into()
+					}))
+			);
+	}
+}
+
+impl TimelineContent
+{
+	pub fn new() -> Self
+	{
+		return Self {};
+	}
+}
+
+fn timelineNode(entry: TimelineEntry) -> impl IntoElement
+{
+	let iconPath = match &entry.gameIcon
+	{
+		None => None,
+		Some(location) => getImagePath(location),
+	};
+	
+	let showIcon = filePathExists(&iconPath);
+	let time = entry.timestamp.with_timezone(&Local)
+		.format("%l:%M %p")
+		.to_string();
+	
+	return rect()
+		.border(Some(
+			Border::new()
+				.alignment(BorderAlignment::Center)
+				.fill(BorderColor)
+				.width(1.0)
+		))
+		.content(Content::Flex)
+		.corner_radius(CornerRadius)
+		.cross_align(Alignment::Center)
+		.direction(Direction::Horizontal)
+		.min_width(Size::px(540.0))
+		.padding(Gaps::new_symmetric(10.0, 15.0))
+		.spacing(10.0)
+		.width(Size::percent(50.0))
+		
+		.maybe_child(showIcon.then(||
+			ImageViewer::new(PathBuf::from(iconPath.unwrap_or_default()))
+				.corner_radius(CornerRadius)
+				.height(Size::px(48.0))
+		))
+		
+		.child(
+			rect()
+				.direction(Direction::Vertical)
+				.spacing(5.0)
+				.width(Size::flex(1.0))
+				
+				.child(
+					label()
+						.font_size(16.0)
+						.font_weight(FontWeight::BOLD)
+						.text(entry.achievement.name.clone())
+				)
+				
+				.child(
+					rect()
+						.cross_align(Alignment::Center)
+						.direction(Direction::Horizontal)
+						.spacing(5.0)
+						
+						.child(platformBadge(entry.platform()))
+						
+						.child(
+							label()
+								.color(Color::GREY)
+								.font_size(12.0)
+								.text(entry.gameName.clone())
+						)
+				)
+		)
+		
+		.child(
+			label()
+				.color(Color::GREY)
+				.font_size(12.0)
+				.text(time)
+		);
+}
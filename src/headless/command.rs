@@ -0,0 +1,146 @@
+use std::path::PathBuf;
+use anyhow::{Result, anyhow};
+use data::enums::GamePlatforms;
+use data::settings::AppSettings;
+use strum::IntoEnumIterator;
+
+/// The argument which starts the application without opening a window.
+pub const HeadlessFlag: &str = "--headless";
+
+pub const Usage: &str = "Usage: reliquarian --headless <command> [platform...]
+
+Commands:
+	refresh [platform...]         Retrieve the latest data from each platform and save it
+	summary [platform...]         Print the achievement totals of each platform
+	export <file> [platform...]   Write every achievement to a .csv or .json file
+	help                          Print this message
+
+Platforms:
+	battlenet, egs, gog, ra, rpcs3, steam
+
+When no platforms are given, every platform enabled in the settings is used.";
+
+/**
+A single action to perform without opening a window.
+*/
+#[derive(Clone, Debug, PartialEq)]
+pub enum HeadlessCommand
+{
+	Export(PathBuf, Vec<GamePlatforms>),
+	Help,
+	Refresh(Vec<GamePlatforms>),
+	Summary(Vec<GamePlatforms>),
+}
+
+impl HeadlessCommand
+{
+	pub fn parse(args: &[String], settings: &AppSettings) -> Result<Self>
+	{
+		let (command, rest) = match args.split_first()
+		{
+			None => return Ok(Self::Help),
+			Some(split) => split,
+		};
+		
+		return match command.to_lowercase().as_str()
+		{
+			"export" => match rest.split_first()
+			{
+				None => Err(anyhow!("The export command requires a file path")),
+				Some((path, platforms)) => Ok(Self::Export(
+					path.into(),
+					parsePlatforms(platforms, settings)?
+				)),
+			},
+			
+			"help" | "--help" | "-h" => Ok(Self::Help),
+			"refresh" => Ok(Self::Refresh(parsePlatforms(rest, settings)?)),
+			"summary" => Ok(Self::Summary(parsePlatforms(rest, settings)?)),
+			_ => Err(anyhow!("Unknown command: '{}'", command)),
+		};
+	}
+}
+
+fn parsePlatform(name: &str) -> Option<GamePlatforms>
+{
+	return match name.to_lowercase().as_str()
+	{
+		"battlenet" | "bnet" => Some(GamePlatforms::BattleNet),
+		"egs" | "epicgamesstore" => Some(GamePlatforms::EpicGamesStore),
+		"gog" => Some(GamePlatforms::Gog),
+		"ra" | "retroachievements" => Some(GamePlatforms::RetroAchievements),
+		"rpcs3" => Some(GamePlatforms::Rpcs3),
+		"steam" => Some(GamePlatforms::Steam),
+		_ => None,
+	};
+}
+
+/**
+The platforms named in `args`, in the order given, or every enabled platform
+when no names are given.
+*/
+fn parsePlatforms(args: &[String], settings: &AppSettings) -> Result<Vec<GamePlatforms>>
+{
+	if args.is_empty()
+	{
+		return Ok(GamePlatforms::iter()
+			.filter(|p| settings.enabledPlatforms.isEnabled((*p).into()))
+			.collect());
+	}
+	
+	let mut platforms = vec![];
+	for name in args
+	{
+		let platform = parsePlatform(name)
+			.ok_or(anyhow!("Unknown platform: '{}'", name))?;
+		
+		if !platforms.contains(&platform)
+		{
+			platforms.push(platform);
+		}
+	}
+	
+	return Ok(platforms);
+}
+
+#[cfg(test)]
+mod tests
+{
+	use super::*;
+	
+	fn args(values: &[&str]) -> Vec<String>
+	{
+		return values.iter()
+			.map(|v| v.to_string())
+			.collect();
+	}
+	
+	#[test]
+	fn parse()
+	{
+		let mut settings = AppSettings::default();
+		settings.enabledPlatforms.gog = true;
+		settings.enabledPlatforms.steam = true;
+		
+		assert_eq!(HeadlessCommand::parse(&[], &settings).unwrap(), HeadlessCommand::Help);
+		
+		assert_eq!(
+			HeadlessCommand::parse(&args(&["refresh"]), &settings).unwrap(),
+			HeadlessCommand::Refresh(vec![GamePlatforms::Gog, GamePlatforms::Steam])
+		);
+		
+		assert_eq!(
+			HeadlessCommand::parse(&args(&["Summary", "steam", "RA", "steam"]), &settings).unwrap(),
+			HeadlessCommand::Summary(vec![GamePlatforms::Steam, GamePlatforms::RetroAchievements])
+		);
+		
+		assert_eq!(
+			HeadlessCommand::parse(&args(&["export", "out.csv", "egs"]), &settings).unwrap(),
+			HeadlessCommand::Export("out.csv".into(), vec![GamePlatforms::EpicGamesStore])
+		);
+		
+		assert!(HeadlessCommand::parse(&args(&["export"]), &settings).is_err());
+		assert!(HeadlessCommand::parse(&args(&["refresh", "xbox"]), &settings).is_err());
+		assert!(HeadlessCommand::parse(&args(&["sync"]), &settings).is_err());
+	}
+}
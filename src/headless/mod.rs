@@ -0,0 +1,108 @@
+/*!
+Run Reliquarian without opening a window, so that refreshes can be scheduled
+with tools like cron or systemd timers on machines without a display.
+*/
+
+mod command;
+mod session;
+
+use std::process::ExitCode;
+use chrono::Utc;
+use data::export::{AchievementExport, ExportFormat};
+use data::io::{loadAppSettings, saveAppSettings};
+use tracing::warn;
+use crate::headless::command::{HeadlessCommand, Usage};
+use crate::headless::session::HeadlessSession;
+
+pub use command::HeadlessFlag;
+
+/**
+Parse and execute a single headless command.
+
+## Parameters
+- args: `&[String]` The command line arguments following `HeadlessFlag`.
+*/
+pub async fn runHeadless(args: &[String]) -> ExitCode
+{
+	attachParentConsole();
+	
+	let mut settings = loadAppSettings().unwrap_or_default();
+	
+	let command = match HeadlessCommand::parse(args, &settings)
+	{
+		Err(e) => {
+			eprintln!("{}\n\n{}", e, Usage);
+			return ExitCode::FAILURE;
+		},
+		
+		Ok(command) => command,
+	};
+	
+	let mut session = HeadlessSession::load(&settings);
+	
+	match command
+	{
+		HeadlessCommand::Export(path, platforms) => {
+			let export = AchievementExport::fromGames(&session.unify(&platforms));
+			
+			match export.write(&path, ExportFormat::fromPath(&path))
+			{
+				Err(e) => {
+					eprintln!("Export failed: {:?}", e);
+					return ExitCode::FAILURE;
+				},
+				
+				Ok(_) => println!("Exported {} achievements to {}", export.len(), path.to_string_lossy()),
+			}
+		},
+		
+		HeadlessCommand::Help => println!("{}", Usage),
+		
+		HeadlessCommand::Refresh(platforms) => {
+			let failures = session.refresh(&platforms).await;
+			
+			let now = Utc::now();
+			for platform in &platforms
+			{
+				match failures.contains(platform)
+				{
+					false => settings.sync.markSynced(*platform, now),
+					true => eprintln!("Refreshing {} failed, it will be retried by the next refresh", platform.as_ref()),
+				}
+			}
+			
+			if let Err(e) = saveAppSettings(&settings)
+			{
+				warn!("[Headless] Error saving app settings: {:?}", e);
+			}
+			
+			session.printSummary(&platforms);
+			
+			if !failures.is_empty()
+			{
+				return ExitCode::FAILURE;
+			}
+		},
+		
+		HeadlessCommand::Summary(platforms) => session.printSummary(&platforms),
+	}
+	
+	return ExitCode::SUCCESS;
+}
+
+/**
+Release builds on Windows use the GUI subsystem, so no console is attached and
+anything printed would be discarded. Attach to the console of the process which
+launched Reliquarian, if it has one.
+*/
+#[cfg(windows)]
+fn attachParentConsole()
+{
+	use windows_sys::Win32::System::Console::{ATTACH_PARENT_PROCESS, AttachConsole};
+	
+	// Fails harmlessly when a console is already attached, as in debug builds
+	unsafe { AttachConsole(ATTACH_PARENT_PROCESS) };
+}
+
+#[cfg(not(windows))]
+fn attachParentConsole() {}
@@ -0,0 +1,311 @@
+use std::collections::HashSet;
+use battlenet::api::BattleNetSettings;
+use battlenet::components::refresh::handleBattleNetOperation;
+use battlenet::data::io::{loadSettings_BattleNet, loadUserData_BattleNet};
+use battlenet::data::user::BattleNetUser;
+use data::constants::DefaultHttpRequestRate;
+use data::enums::GamePlatforms;
+use data::io::{cacheImage, imagePathExists};
+use data::settings::{AppSettings, Language};
+use data::unified::{Unifiable, UnifiedGame};
+use epicgamesstore::components::refresh::handleEgsOperation;
+use epicgamesstore::data::io::loadUserData_EpicGamesStore;
+use epicgamesstore::data::user::EgsUser;
+use gog::components::refresh::handleGogOperation;
+use gog::data::io::loadUserData_Gog;
+use gog::data::user::GogUser;
+use net::{DataOperation, DataRequest, RateLimiter};
+use reqwest::Client;
+use retroachievements::components::refresh::handleRetroAchievementsOperation;
+use retroachievements::data::io::{loadSettings_RetroAchievements,
+	loadUserData_RetroAchievements};
+use retroachievements::data::settings::RetroAchievementsSettings;
+use retroachievements::data::user::RetroAchievementsUser;
+use rpcs3::components::refresh::refreshUserData;
+use rpcs3::data::io::{loadSettings_Rpcs3, loadUserData_Rpcs3};
+use rpcs3::data::settings::Rpcs3Settings;
+use rpcs3::data::user::Rpcs3User;
+use steam::components::refresh::handleSteamOperation;
+use steam::data::io::{loadSettings_Steam, loadUserData_Steam};
+use steam::data::settings::SteamSettings;
+use steam::data::user::SteamUser;
+use tracing::{info, warn};
+use crate::refresh::refreshRequests;
+
+/**
+The user data of every platform, held in memory while requests are processed
+without a window.
+
+This is the headless counterpart to the radio stations and the rate limiter
+loop of the main application. The same operation handlers are used, so a
+refresh retrieves exactly the same data it would in the GUI.
+*/
+pub struct HeadlessSession
+{
+	battleNet: BattleNetUser,
+	battleNetSettings: BattleNetSettings,
+	egs: EgsUser,
+	gog: GogUser,
+	language: Language,
+	rateLimiter: RateLimiter,
+	retroAchievements: RetroAchievementsUser,
+	retroAchievementsSettings: RetroAchievementsSettings,
+	rpcs3: Rpcs3User,
+	rpcs3Settings: Rpcs3Settings,
+	steam: SteamUser,
+	steamSettings: SteamSettings,
+}
+
+impl HeadlessSession
+{
+	pub fn load(settings: &AppSettings) -> Self
+	{
+		return Self
+		{
+			battleNet: loadUserData_BattleNet(),
+			battleNetSettings: loadSettings_BattleNet(),
+			egs: loadUserData_EpicGamesStore(),
+			gog: loadUserData_Gog(),
+			language: settings.language,
+			rateLimiter: RateLimiter::new(DefaultHttpRequestRate),
+			retroAchievements: loadUserData_RetroAchievements(),
+			retroAchievementsSettings: loadSettings_RetroAchievements(),
+			rpcs3: loadUserData_Rpcs3(),
+			rpcs3Settings: loadSettings_Rpcs3(),
+			steam: loadUserData_Steam(),
+			steamSettings: loadSettings_Steam(),
+		};
+	}
+	
+	/**
+	Print the number of games and unlocked achievements for each of the given
+	platforms.
+	*/
+	pub fn printSummary(&self, platforms: &[GamePlatforms])
+	{
+		for platform in platforms
+		{
+			let games = self.unifyPlatform(*platform);
+			let total = games.iter()
+				.map(|g| g.achievementsTotal)
+				.sum::<u64>();
+			let unlocked = games.iter()
+				.map(|g| g.achievementsUnlocked)
+				.sum::<u64>();
+			
+			let percent = match total
+			{
+				0 => 0.0,
+				_ => unlocked as f64 / total as f64 * 100.0,
+			};
+			
+			println!(
+				"{}: {} games, {} of {} achievements unlocked ({:.2}%)",
+				platform.as_ref(),
+				games.len(),
+				unlocked,
+				total,
+				percent
+			);
+		}
+	}
+	
+	/**
+	Retrieve the latest data for each of the given platforms, wait until every
+	resulting request has been processed, then save the data.
+	
+	Returns the platforms which could not be completely refreshed or saved.
+	*/
+	pub async fn refresh(&mut self, platforms: &[GamePlatforms]) -> HashSet<GamePlatforms>
+	{
+		let mut failures = HashSet::new();
+		
+		for platform in platforms
+		{
+			println!("Refreshing {}...", platform.as_ref());
+			
+			match platform
+			{
+				GamePlatforms::Rpcs3 => {
+					let result = refreshUserData(self.rpcs3.clone(), self.rpcs3Settings.clone()).await;
+					self.rpcs3 = result.user;
+					
+					if result.failed
+					{
+						failures.insert(GamePlatforms::Rpcs3);
+					}
+				},
+				
+				_ => self.rateLimiter.pushAll(refreshRequests(
+					*platform,
+					&self.retroAchievementsSettings,
+					&self.steam,
+					&self.steamSettings
+				)).await,
+			}
+		}
+		
+		self.processRequests().await;
+		failures.extend(self.rateLimiter.takeFailures().await);
+		failures.extend(self.save(platforms));
+		
+		return failures;
+	}
+	
+	/**
+	Every game, and its achievements, of the given platforms.
+	*/
+	pub fn unify(&self, platforms: &[GamePlatforms]) -> Vec<UnifiedGame>
+	{
+		return platforms.iter()
+			.flat_map(|p| self.unifyPlatform(*p))
+			.collect();
+	}
+	
+	async fn cacheRequestedImage(&self, client: &Client, request: DataRequest, force: bool)
+	{
+		if let Some(destination) = request.destination
+		{
+			if let Some(url) = request.url
+			{
+				if force || !imagePathExists(&destination)
+				{
+					match cacheImage(client, &url, &destination).await
+					{
+						Err(e) => warn!("[Cache] Error caching image {} - {:?}", destination, e),
+						Ok(_) => info!("[Cache] Cached image: {}", destination),
+					}
+				}
+				else
+				{
+					self.rateLimiter.refundUse()
+						.await;
+				}
+			}
+		}
+	}
+	
+	async fn handleOperation(&mut self, platform: GamePlatforms, operation: DataOperation)
+	{
+		let result = match platform
+		{
+			GamePlatforms::BattleNet => handleBattleNetOperation(self.battleNet.clone(), self.battleNetSettings, operation)
+				.await
+				.map(|result| {
+					self.battleNet = result.user;
+					(result.failed, result.requests)
+				}),
+			
+			GamePlatforms::EpicGamesStore => handleEgsOperation(self.egs.clone(), operation)
+				.await
+				.map(|result| {
+					self.egs = result.user;
+					(result.failed, result.requests)
+				}),
+			
+			GamePlatforms::Gog => handleGogOperation(self.gog.clone(), operation)
+				.await
+				.map(|result| {
+					self.gog = result.user;
+					(result.failed, result.requests)
+				}),
+			
+			GamePlatforms::RetroAchievements => handleRetroAchievementsOperation(self.retroAchievements.clone(), operation)
+				.await
+				.map(|result| {
+					self.retroAchievements = result.user;
+					(result.failed, result.requests)
+				}),
+			
+			GamePlatforms::Rpcs3 => None,
+			
+			GamePlatforms::Steam => handleSteamOperation(self.steam.clone(), operation, self.language)
+				.await
+				.map(|result| {
+					self.steam = result.user;
+					(result.failed, result.requests)
+				}),
+		};
+		
+		if let Some((failed, requests)) = result
+		{
+			if failed
+			{
+				self.rateLimiter.reportFailure(platform).await;
+			}
+			
+			self.rateLimiter.pushAll(requests).await;
+		}
+	}
+	
+	async fn processRequests(&mut self)
+	{
+		let client = Client::builder()
+			.https_only(true)
+			.build()
+			.unwrap_or_default();
+		
+		while let Some(request) = self.rateLimiter.next().await
+		{
+			match request.operation.clone()
+			{
+				DataOperation::CacheImage(force) => self.cacheRequestedImage(&client, request, force).await,
+				
+				operation => if let Some(platform) = operation.platform()
+				{
+					self.handleOperation(platform, operation).await;
+				},
+			}
+		}
+	}
+	
+	/**
+	Save the user data of each of the given platforms.
+	
+	RPCS3 is skipped because its refresh already saves the data.
+	
+	Returns the platforms whose data could not be saved.
+	*/
+	fn save(&self, platforms: &[GamePlatforms]) -> HashSet<GamePlatforms>
+	{
+		let mut failures = HashSet::new();
+		
+		for platform in platforms
+		{
+			let result = match platform
+			{
+				GamePlatforms::BattleNet => battlenet::data::io::saveUserData(&self.battleNet),
+				GamePlatforms::EpicGamesStore => epicgamesstore::data::io::saveUserData(&self.egs),
+				GamePlatforms::Gog => gog::data::io::saveUserData(&self.gog),
+				GamePlatforms::RetroAchievements => retroachievements::data::io::saveUserData(&self.retroAchievements),
+				GamePlatforms::Rpcs3 => Ok(()),
+				GamePlatforms::Steam => steam::data::io::saveUserData(&self.steam),
+			};
+			
+			match result
+			{
+				Err(e) => {
+					warn!("[Headless] Error saving {} user data: {:?}", platform.as_ref(), e);
+					failures.insert(*platform);
+				},
+				
+				Ok(_) => info!("[Headless] Saved {} user data", platform.as_ref()),
+			}
+		}
+		
+		return failures;
+	}
+	
+	fn unifyPlatform(&self, platform: GamePlatforms) -> Vec<UnifiedGame>
+	{
+		return match platform
+		{
+			GamePlatforms::BattleNet => self.battleNet.unify(),
+			GamePlatforms::EpicGamesStore => self.egs.unify(),
+			GamePlatforms::Gog => self.gog.unify(),
+			GamePlatforms::RetroAchievements => self.retroAchievements.unify(),
+			GamePlatforms::Rpcs3 => self.rpcs3.unify(),
+			GamePlatforms::Steam => self.steam.unify(),
+		};
+	}
+}
@@ -4,11 +4,15 @@ and tracking your achievements across multiple platforms in one unified UI.
 */
 
 //Disable the additional command prompt window when running the application on Windows
+//Headless mode attaches to the parent console instead, see `headless::attachParentConsole`
 #![cfg_attr(not(debug_assertions), windows_subsystem = "windows")]
 
 mod components;
+mod headless;
+mod refresh;
 
 use std::path::Path;
+use std::process::ExitCode;
 use data::constants::{AppTitle, BackgroundColor, DefaultWindowSize,
 	MinimumWindowSize};
 use ::data::constants::{FileName_LogPrefix, Path_Logs};
@@ -17,8 +21,9 @@ use freya::prelude::{LaunchConfig, WindowConfig, launch};
 use tracing::Level;
 use tracing_appender::non_blocking::WorkerGuard;
 use crate::components::LocalAchievementsApp;
+use crate::headless::{HeadlessFlag, runHeadless};
 
-fn main()
+fn main() -> ExitCode
 {
 	let _guard = configureLogger();
 	
@@ -27,6 +32,15 @@ fn main()
 		.build()
 		.unwrap();
 	
+	let args = std::env::args()
+		.skip(1)
+		.collect::<Vec<_>>();
+	
+	if args.first().is_some_and(|arg| arg == HeadlessFlag)
+	{
+		return tokioBuilder.block_on(runHeadless(&args[1..]));
+	}
+	
 	let _tokioRuntime = tokioBuilder.enter();
 	
 	launch(LaunchConfig::new()
@@ -39,6 +53,8 @@ fn main()
 				.with_transparency(false)
 		)
 	);
+	
+	return ExitCode::SUCCESS;
 }
 
 fn configureLogger() -> WorkerGuard
@@ -0,0 +1,52 @@
+use battlenet::data::operation::BattleNetOperation;
+use data::enums::GamePlatforms;
+use epicgamesstore::data::operation::EgsOperation;
+use gog::data::operation::GogOperation;
+use net::DataRequest;
+use retroachievements::data::operation::RetroAchievementsOperation;
+use retroachievements::data::settings::RetroAchievementsSettings;
+use steam::data::operation::SteamOperation;
+use steam::data::settings::SteamSettings;
+use steam::data::user::SteamUser;
+
+/**
+The operations which refresh a platform's data, matching those queued by the
+refresh button of each platform's profile.
+
+A full Steam refresh also refreshes the achievements of every game which has
+already been loaded, rather than every game in the library, as there may be
+thousands. A quick refresh only refreshes the recently played games.
+
+RPCS3 data is read from the local file system instead of being requested, so
+no operations are returned for it.
+*/
+pub fn refreshRequests(
+	platform: GamePlatforms,
+	retroAchievementsSettings: &RetroAchievementsSettings,
+	steamUser: &SteamUser,
+	steamSettings: &SteamSettings
+) -> Vec<DataRequest>
+{
+	return match platform
+	{
+		GamePlatforms::BattleNet => vec![
+			BattleNetOperation::GetUserInfo.into(),
+		],
+		
+		GamePlatforms::EpicGamesStore => EgsOperation::refreshRequests(),
+		
+		GamePlatforms::Gog => vec![
+			GogOperation::RefreshSession.into(),
+			GogOperation::GetUserInfo.into(),
+			GogOperation::GetFilteredProducts(None).into(),
+		],
+		
+		GamePlatforms::RetroAchievements => RetroAchievementsOperation::refreshRequests(
+			retroAchievementsSettings.incrementalRefresh
+		),
+		
+		GamePlatforms::Rpcs3 => vec![],
+		
+		GamePlatforms::Steam => SteamOperation::refreshRequests(steamSettings, steamUser),
+	};
+}